@@ -0,0 +1,264 @@
+use std::time::Instant;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rrt_rrt_star::{maps, BiRrtStar, Bit, OccupancyGrid, PlanConfig, Planner, Point, PotentialFieldSampler, RRTStar, Sampler};
+
+// Standart haritada köşeden köşeye planlama yapılandırması
+fn standard_config() -> PlanConfig {
+    PlanConfig {
+        start: Point { x: 20.0, y: 20.0 },
+        goal: Point { x: 380.0, y: 380.0 },
+        obstacles: maps::standard(),
+        ..PlanConfig::default()
+    }
+}
+
+// Sabit tohumla 5000 iterasyonluk tam bir planlama
+fn bench_plan(c: &mut Criterion) {
+    let config = standard_config();
+    c.bench_function("plan_standard_5000", |b| {
+        b.iter(|| RRTStar::from_config(&config, 42).plan())
+    });
+}
+
+// Yeniden bağlama eşiğinin planlama süresine etkisi; her eşik için yeniden bağlama sayısı ve son
+// yol maliyetinin eşiksiz çalışmaya göre sapması ölçümden önce bir kez yazdırılır
+fn bench_rewire_threshold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rewire_threshold");
+    let baseline = RRTStar::from_config(&standard_config(), 42).plan().expect("standart harita çözülür");
+    for threshold in [0.0, 0.01] {
+        let config = PlanConfig { rewire_improvement_threshold: threshold, ..standard_config() };
+        let stats = RRTStar::from_config(&config, 42).plan().expect("standart harita çözülür");
+        let drift = (stats.best_cost - baseline.best_cost).abs() / baseline.best_cost;
+        println!("rewire_threshold {threshold}: {} rewires (baseline {}), best cost {:.3} (drift {:.4}%)", stats.rewires, baseline.rewires, stats.best_cost, drift * 100.0);
+        assert!(stats.rewires <= baseline.rewires, "threshold {threshold} increased the rewire count");
+        group.bench_with_input(BenchmarkId::from_parameter(threshold), &config, |b, config| {
+            b.iter(|| RRTStar::from_config(config, 42).plan())
+        });
+    }
+    group.finish();
+}
+
+// Çarpışma kontrolü aralığının planlama süresine etkisi (0 = adım boyutunun dörtte biri)
+fn bench_collision_resolution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collision_resolution");
+    for resolution in [0.5, 0.0, 5.0] {
+        let config = PlanConfig { collision_resolution: resolution, ..standard_config() };
+        group.bench_with_input(BenchmarkId::from_parameter(resolution), &config, |b, config| {
+            b.iter(|| RRTStar::from_config(config, 42).plan())
+        });
+    }
+    group.finish();
+}
+
+// Boş ve dar geçitli haritada tekdüze, yola yönelik ve informed örneklemeyle sabit tohumlu planlama;
+// her örneklemenin aynı iterasyon bütçesindeki son yol maliyeti, tekdüze örneklemeye göre farkıyla
+// ölçümden önce bir kez yazdırılır
+fn bench_samplers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sampler");
+    for (map, obstacles) in [("empty", Vec::new()), ("narrow_passage", maps::narrow_passage())] {
+        let uniform = PlanConfig { obstacles: obstacles.clone(), ..standard_config() };
+        let baseline = RRTStar::from_config(&uniform, 42).plan().expect("harita çözülür");
+        for (name, sampler) in [
+            ("uniform", Sampler::Uniform),
+            ("path_biased", Sampler::PathBiased { probability: 0.7, sigma: 10.0 }),
+            ("informed", Sampler::Informed),
+        ] {
+            let config = PlanConfig { obstacles: obstacles.clone(), sampler, ..standard_config() };
+            let stats = RRTStar::from_config(&config, 42).plan().expect("harita çözülür");
+            let change = (stats.best_cost - baseline.best_cost) / baseline.best_cost;
+            println!("sampler {name}/{map}: best cost {:.3} after {} iterations ({:+.2}% vs uniform)", stats.best_cost, stats.iterations, change * 100.0);
+            group.bench_with_input(BenchmarkId::new(name, map), &config, |b, config| {
+                b.iter(|| RRTStar::from_config(config, 42).plan())
+            });
+        }
+    }
+    group.finish();
+}
+
+// Labirent haritasında tekdüze ve RRT*-Smart örneklemeyle sabit tohumlu planlama; her
+// örneklemenin aynı iterasyon bütçesindeki son yol maliyeti ölçümden önce bir kez yazdırılır
+fn bench_smart(c: &mut Criterion) {
+    let mut group = c.benchmark_group("smart");
+    for (name, sampler) in [("uniform", Sampler::Uniform), ("smart", Sampler::Smart { probability: 0.3, radius: 15.0 })] {
+        let config = PlanConfig { obstacles: maps::maze(), sampler, ..standard_config() };
+        let stats = RRTStar::from_config(&config, 42).plan().expect("labirent çözülür");
+        println!("smart {name}: best cost {:.3} after {} iterations", stats.best_cost, stats.iterations);
+        group.bench_with_input(BenchmarkId::from_parameter(name), &config, |b, config| {
+            b.iter(|| RRTStar::from_config(config, 42).plan())
+        });
+    }
+    group.finish();
+}
+
+// Dar geçitli haritada aynı iterasyon sınırıyla RRT* ve BIT* (sabit tohumlu). Ölçümden önce her
+// tohum için BIT*, RRT*'ın tam planlamasının sürdüğü kadar çalıştırılır; eşit sürede son yol
+// maliyetleri yazdırılır ve BIT*'ınki RRT*'ınkini %1'den fazla aşamaz. Bu bütçede varsayılan
+// yığın boyutu küçük kalır, yığın başına 800 örnek çekilir
+fn bench_bit(c: &mut Criterion) {
+    const BATCH_SIZE: usize = 800;
+    let mut group = c.benchmark_group("bit");
+    let config = PlanConfig { obstacles: maps::narrow_passage(), ..standard_config() };
+    for seed in [42, 1, 2] {
+        let started = Instant::now();
+        let rrt_cost = RRTStar::from_config(&config, seed).plan().expect("dar geçit çözülür").best_cost;
+        let budget = started.elapsed();
+        let started = Instant::now();
+        let mut bit = Bit::from_config(&PlanConfig { max_iterations: usize::MAX, ..config.clone() }, seed);
+        bit.set_batch_size(BATCH_SIZE);
+        while !bit.is_finished() && started.elapsed() < budget {
+            bit.step();
+        }
+        println!("bit seed {seed}: best cost {:.3} vs RRT* {rrt_cost:.3} within {budget:?} ({} BIT* edges)", bit.best_cost(), bit.iterations());
+        assert!(bit.best_cost() <= rrt_cost * 1.01, "BIT* cost {} exceeds RRT* cost {rrt_cost} (seed {seed})", bit.best_cost());
+    }
+    group.bench_function("rrt_star", |b| b.iter(|| RRTStar::from_config(&config, 42).plan()));
+    group.bench_function("bit_star", |b| {
+        b.iter(|| {
+            let mut bit = Bit::from_config(&config, 42);
+            bit.set_batch_size(BATCH_SIZE);
+            while !bit.is_finished() {
+                bit.step();
+            }
+            bit.best_cost()
+        })
+    });
+    group.finish();
+}
+
+// Böcek kapanı haritasında (başlangıç kafesin içinde) tek ağaçlı RRT* ile çift yönlü RRT*'ın
+// ilk çözüme kadar geçen süresi
+fn bench_bi_rrt_star(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bi_rrt_star");
+    let config = PlanConfig { obstacles: maps::bug_trap(), start: Point { x: 120.0, y: 120.0 }, ..standard_config() };
+    group.bench_function("rrt_star", |b| {
+        b.iter(|| {
+            let mut rrt_star = RRTStar::from_config(&config, 42);
+            while !rrt_star.best_cost().is_finite() && !rrt_star.is_finished() {
+                rrt_star.step();
+            }
+            rrt_star.iterations()
+        })
+    });
+    group.bench_function("bi_rrt_star", |b| {
+        b.iter(|| {
+            let mut bi = BiRrtStar::from_config(&config, 42);
+            while !bi.best_cost().is_finite() && !bi.is_finished() {
+                bi.step();
+            }
+            bi.iterations()
+        })
+    });
+    group.finish();
+}
+
+// Boş haritada tekdüze ve Halton örneklemeyle ilk çözüme kadar geçen süre
+fn bench_first_solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("first_solution");
+    for (name, sampler) in [("uniform", Sampler::Uniform), ("halton", Sampler::Halton)] {
+        let config = PlanConfig { obstacles: Vec::new(), sampler, ..standard_config() };
+        group.bench_with_input(BenchmarkId::from_parameter(name), &config, |b, config| {
+            b.iter(|| {
+                let mut rrt_star = RRTStar::from_config(config, 42);
+                while !rrt_star.best_cost().is_finite() && !rrt_star.is_finished() {
+                    rrt_star.step();
+                }
+                rrt_star.iterations()
+            })
+        });
+    }
+    group.finish();
+}
+
+// Böcek kapanı haritasında tekdüze ve potansiyel alanıyla itilen örneklemeyle ilk çözüme kadar
+// geçen süre
+fn bench_potential_field(c: &mut Criterion) {
+    let mut group = c.benchmark_group("potential_field");
+    let field = PotentialFieldSampler { attractive_gain: 0.05, repulsive_gain: 50.0, influence: 20.0, steps: 5, max_nudge: 20.0 };
+    for (name, sampler) in [("uniform", Sampler::Uniform), ("potential_field", Sampler::PotentialField(field))] {
+        let config = PlanConfig { obstacles: maps::bug_trap(), start: Point { x: 120.0, y: 120.0 }, sampler, ..standard_config() };
+        group.bench_with_input(BenchmarkId::from_parameter(name), &config, |b, config| {
+            b.iter(|| {
+                let mut rrt_star = RRTStar::from_config(config, 42);
+                while !rrt_star.best_cost().is_finite() && !rrt_star.is_finished() {
+                    rrt_star.step();
+                }
+                rrt_star.iterations()
+            })
+        });
+    }
+    group.finish();
+}
+
+// 3 birimlik dar geçitte (2 birim çaplı bir robotun 1.5 katı) tekdüze ve köprü testli
+// örneklemeyle ilk çözüme kadar geçen süre
+fn bench_bridge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bridge");
+    for (name, sampler) in [("uniform", Sampler::Uniform), ("bridge", Sampler::Bridge { probability: 0.5, sigma: 20.0 })] {
+        let config = PlanConfig { obstacles: maps::narrow_gap(3.0), sampler, max_iterations: 30_000, ..standard_config() };
+        group.bench_with_input(BenchmarkId::from_parameter(name), &config, |b, config| {
+            b.iter(|| {
+                let mut rrt_star = RRTStar::from_config(config, 42);
+                while !rrt_star.best_cost().is_finite() && !rrt_star.is_finished() {
+                    rrt_star.step();
+                }
+                rrt_star.iterations()
+            })
+        });
+    }
+    group.finish();
+}
+
+// Standart haritanın 0.25 birimlik hücrelerle taranmış doluluk ızgarası
+fn standard_grid() -> OccupancyGrid {
+    let obstacles = maps::standard();
+    let (width, height, resolution) = (1600, 1600, 0.25);
+    let cells = (0..width * height)
+        .map(|i| {
+            let center = Point { x: ((i % width) as f32 + 0.5) * resolution, y: ((i / width) as f32 + 0.5) * resolution };
+            obstacles.iter().any(|obstacle| obstacle.contains(&center))
+        })
+        .collect();
+    OccupancyGrid::from_cells(cells, width, height, resolution, Point::default()).expect("hücre sayısı boyutlarla uyuşur")
+}
+
+// Doluluk ızgaralı haritada her kenarı hemen denetlemekle tembel denetim arasındaki fark
+// (denetim sayıları `PlanStats::collision_checks` ve `skipped_collision_checks`)
+fn bench_lazy_collision(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lazy_collision");
+    let config = PlanConfig { obstacles: Vec::new(), occupancy_grid: Some(standard_grid()), collision_resolution: 0.25, ..standard_config() };
+    for lazy in [false, true] {
+        group.bench_with_input(BenchmarkId::from_parameter(if lazy { "lazy" } else { "eager" }), &lazy, |b, &lazy| {
+            b.iter(|| {
+                let mut rrt_star = RRTStar::from_config(&config, 42);
+                rrt_star.set_lazy_collision(lazy);
+                while !rrt_star.is_finished() {
+                    rrt_star.step();
+                }
+                rrt_star.best_cost()
+            })
+        });
+    }
+    group.finish();
+}
+
+// Farklı ağaç büyüklüklerinde en yakın düğüm sorgusu
+fn bench_find_nearest(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_nearest");
+    for size in [1_000, 5_000, 10_000, 50_000] {
+        // Ağaç, kök düğüme bağlı rastgele noktalarla doğrudan doldurulur
+        let mut rrt_star = RRTStar::from_config(&standard_config(), 7);
+        while rrt_star.nodes().len() < size {
+            let point = rrt_star.random_point(0.0, 400.0, 0.0, 400.0);
+            rrt_star.add_node(point, 0);
+        }
+        let query = Point { x: 123.4, y: 321.0 };
+        group.bench_with_input(BenchmarkId::from_parameter(size), &rrt_star, |b, rrt_star| {
+            b.iter(|| rrt_star.find_nearest(black_box(&query)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_plan, bench_rewire_threshold, bench_collision_resolution, bench_samplers, bench_smart, bench_bit, bench_bi_rrt_star, bench_first_solution, bench_potential_field, bench_bridge, bench_lazy_collision, bench_find_nearest);
+criterion_main!(benches);
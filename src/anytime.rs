@@ -0,0 +1,67 @@
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+
+use crate::{Path, RRTStar, Termination};
+
+// Arka planda çalışan planlayıcının bulduğu her iyileşmiş çözüm
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution {
+    pub path: Path,       // Başlangıçtan hedefe yol
+    pub cost: f32,        // Yolun maliyeti
+    pub iteration: usize, // Çözümün bulunduğu iterasyon
+}
+
+impl RRTStar {
+    // Planlayıcıyı verilen durma koşuluyla arka plandaki bir iş parçacığında çalıştırır ve
+    // en iyi maliyet her düştüğünde yolu kanala gönderir; böylece ilk çözümle harekete
+    // başlanıp sonraki iyileştirmeler yolda alınabilir. Gönderilen maliyetler kesin olarak
+    // azalır. Planlama bittiğinde ya da alıcı bırakıldığında iş parçacığı planlayıcıyı döner
+    pub fn plan_anytime(mut self, termination: Option<Termination>) -> (JoinHandle<RRTStar>, Receiver<Solution>) {
+        let (sender, receiver) = mpsc::channel();
+        self.termination = termination;
+        let handle = thread::spawn(move || {
+            // Kök zaten hedefteyse tek noktalı yol da ilk iterasyondan önce gönderilir
+            let mut sent_cost = f32::INFINITY;
+            loop {
+                if self.best_cost < sent_cost {
+                    sent_cost = self.best_cost;
+                    let solution = Solution { path: self.best_path.clone(), cost: sent_cost, iteration: self.iterations };
+                    if sender.send(solution).is_err() {
+                        break;
+                    }
+                }
+                if self.is_finished() {
+                    break;
+                }
+                self.step();
+            }
+            self
+        });
+        (handle, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{maps, PlanConfig, Point};
+
+    #[test]
+    fn yielded_costs_strictly_decrease() {
+        let config = PlanConfig {
+            start: Point { x: 20.0, y: 20.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            obstacles: maps::standard(),
+            max_iterations: 3000,
+            ..PlanConfig::default()
+        };
+        let (handle, receiver) = RRTStar::from_config(&config, 2).plan_anytime(None);
+        let solutions: Vec<Solution> = receiver.iter().collect();
+        let rrt_star = handle.join().expect("planlama iş parçacığı paniklememeli");
+
+        assert!(solutions.len() > 1);
+        assert!(solutions.windows(2).all(|pair| pair[1].cost < pair[0].cost && pair[1].iteration >= pair[0].iteration));
+        assert!(solutions.iter().all(|solution| solution.path.first() == Some(&config.start) && solution.path.last() == Some(&config.goal)));
+        assert_eq!(solutions.last().map(|solution| solution.cost), Some(rrt_star.best_cost()));
+    }
+}
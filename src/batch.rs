@@ -0,0 +1,47 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::{PlanConfig, PlanError, PlanStats, RRTStar};
+
+// Aynı yapılandırmayı her tohum için bağımsız olarak, iş parçacıklarına dağıtarak planlar.
+// Her planlayıcının kendi düğümleri ve rastgele sayı üreteci olduğundan çalışmalar birbirinden
+// tamamen bağımsızdır; sonuçlar tohumlarla aynı sırada döner
+pub fn plan_batch(config: &PlanConfig, seeds: &[u64]) -> Vec<Result<PlanStats, PlanError>> {
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(seeds.len())
+        .max(1);
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<Result<PlanStats, PlanError>>> = vec![None; seeds.len()];
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    // Sıradaki tohumu al, bitene kadar planla
+                    let mut finished = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        if index >= seeds.len() {
+                            break;
+                        }
+                        let mut rrt_star = RRTStar::from_config(config, seeds[index]);
+                        finished.push((index, rrt_star.plan()));
+                    }
+                    finished
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            for (index, stats) in handle.join().expect("planlama iş parçacığı paniğe girdi") {
+                results[index] = Some(stats);
+            }
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|stats| stats.expect("her tohum için bir sonuç üretilir"))
+        .collect()
+}
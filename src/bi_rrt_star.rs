@@ -0,0 +1,223 @@
+use crate::{ConfigError, Path, PlanConfig, Planner, Point, RRTStar, StepOutcome, Steering};
+
+// İki ağacı buluşturan en iyi köprü
+#[derive(Clone, Copy, Debug)]
+struct Bridge {
+    start_index: usize, // Başlangıç ağacındaki uç
+    goal_index: usize,  // Hedef ağacındaki uç
+}
+
+// Çift yönlü RRT* (B-RRT*): biri başlangıçta, biri hedefte köklenen iki RRT* ağacı sırayla
+// büyütülür; her yeni düğüm diğer ağacın yakınlık kümesindeki düğümlere bağlanmaya çalışılır
+// ve toplam maliyeti en düşük köprü çözüm olarak tutulur. Ağaçlar çözüm bulunduktan sonra da
+// büyümeye ve yeniden bağlanmaya devam eder; yeniden bağlama maliyetleri yalnızca düşürdüğünden
+// köprünün maliyeti de her adımda yeniden hesaplanır. Her ağaç örnekleme, ebeveyn seçimi,
+// yeniden bağlama ve kendi hedefine bağlanma için RRT* planlayıcısını kullanır; informed
+// örneklemede iki ağaç da birleştirilen yolun elipsinden örnekler
+pub struct BiRrtStar {
+    start_tree: RRTStar,    // Başlangıçta köklenen ağaç
+    goal_tree: RRTStar,     // Hedefte köklenen ağaç
+    swapped: bool,          // Bu iterasyonda hedef ağacı mı büyütülecek
+    bridges: Vec<Bridge>,   // Şu ana kadar bulunan köprüler; yeniden bağlama hangisinin en ucuz olduğunu değiştirebilir
+    bridge: Option<Bridge>, // Şu anki en ucuz köprü
+    iterations: usize,      // Şu ana kadar çalıştırılan iterasyon sayısı
+    max_iterations: usize,  // İterasyon sınırı
+    best_cost: f32,         // Birleştirilen yolun maliyeti (yol yoksa sonsuz)
+    best_path: Path,        // Başlangıçtan hedefe birleştirilen yol
+}
+
+impl BiRrtStar {
+    // Yapılandırmadan, verilen tohumla tekrarlanabilir bir planlayıcı oluşturur
+    // Geçersiz parametrelerde paniğe girer (bkz. `try_from_config`)
+    pub fn from_config(config: &PlanConfig, seed: u64) -> Self {
+        Self::try_from_config(config, seed).unwrap_or_else(|error| panic!("invalid B-RRT* configuration: {error}"))
+    }
+
+    // Yapılandırmayı doğrulayarak tekrarlanabilir bir planlayıcı oluşturur; hedef ağacı
+    // başlangıç ve hedefi yer değiştirmiş aynı yapılandırmayla kurulur
+    pub fn try_from_config(config: &PlanConfig, seed: u64) -> Result<Self, ConfigError> {
+        // Köprüler doğru parçalarıdır; iki ağacın yönleri köprüde uyuşmayacağından eğrisel
+        // yönlendirmeler desteklenmez
+        let forward = PlanConfig { steering: Steering::Straight, ..config.clone() };
+        let start_tree = RRTStar::try_from_config(&forward, seed)?;
+        let reversed = PlanConfig { start: config.goal, goal: config.start, ..forward };
+        let goal_tree = RRTStar::try_from_config(&reversed, seed.wrapping_add(1))?;
+
+        let mut planner = BiRrtStar {
+            start_tree,
+            goal_tree,
+            swapped: false,
+            bridges: Vec::new(),
+            bridge: None,
+            iterations: 0,
+            max_iterations: config.max_iterations,
+            best_cost: f32::INFINITY,
+            best_path: Path::default(),
+        };
+        // Başlangıç hedefin kabul bölgesindeyse kök zaten bir çözümdür
+        planner.refresh();
+        Ok(planner)
+    }
+
+    // Başlangıçta köklenen ağaç
+    pub fn start_tree(&self) -> &RRTStar {
+        &self.start_tree
+    }
+
+    // Hedefte köklenen ağaç
+    pub fn goal_tree(&self) -> &RRTStar {
+        &self.goal_tree
+    }
+
+    // En iyi köprü kenarının başlangıç ve hedef ağacındaki uçları (köprü yoksa None)
+    pub fn bridge(&self) -> Option<(Point, Point)> {
+        self.bridge.map(|bridge| {
+            (self.start_tree.nodes()[bridge.start_index].point, self.goal_tree.nodes()[bridge.goal_index].point)
+        })
+    }
+
+    // Köprünün şu anki toplam maliyeti: iki ucun kök maliyetleri ve aradaki kenar
+    fn bridge_cost(&self, bridge: Bridge) -> f32 {
+        let start = &self.start_tree.nodes()[bridge.start_index];
+        let goal = &self.goal_tree.nodes()[bridge.goal_index];
+        start.cost + self.start_tree.edge_cost(&start.point, &goal.point) + goal.cost
+    }
+
+    // Yeni düğümü diğer ağacın yakınlık kümesindeki en ucuz engelsiz düğüme bağlamaya çalışır
+    // ve bulunan köprüyü saklar
+    fn try_bridge(&mut self, index: usize) {
+        let (tree, other) = if self.swapped { (&self.goal_tree, &self.start_tree) } else { (&self.start_tree, &self.goal_tree) };
+        let point = tree.nodes()[index].point;
+        let Some((other_index, _)) = other
+            .near_sorted(&point)
+            .into_iter()
+            .find(|&(other_index, _)| tree.is_edge_collision_free(&point, &other.nodes()[other_index].point))
+        else {
+            return;
+        };
+        let bridge = if self.swapped {
+            Bridge { start_index: other_index, goal_index: index }
+        } else {
+            Bridge { start_index: index, goal_index: other_index }
+        };
+        self.bridges.push(bridge);
+    }
+
+    // Köprülerden ve iki ağacın kendi hedef bağlantılarından en ucuz yolu seçer; maliyet
+    // düşmüşse yolu yeniden kurar ve 'true' döner
+    fn refresh(&mut self) -> bool {
+        self.bridge = self
+            .bridges
+            .iter()
+            .copied()
+            .min_by(|&a, &b| self.bridge_cost(a).total_cmp(&self.bridge_cost(b)));
+        let bridged = self.bridge.map_or(f32::INFINITY, |bridge| self.bridge_cost(bridge));
+        let direct = self.start_tree.best_cost();
+        let reverse = self.goal_tree.best_cost();
+        let cost = bridged.min(direct).min(reverse);
+        if cost >= self.best_cost {
+            return false;
+        }
+
+        let points = if cost == direct {
+            self.start_tree.best_path().to_vec()
+        } else if cost == reverse {
+            self.goal_tree.best_path().iter().rev().copied().collect()
+        } else {
+            let bridge = self.bridge.expect("köprü maliyeti sonlu");
+            let mut points = self.start_tree.trace_from(bridge.start_index).expect("RRT* ağacında döngü olmaz");
+            let goal_half = self.goal_tree.trace_from(bridge.goal_index).expect("RRT* ağacında döngü olmaz");
+            points.extend(goal_half.into_iter().rev());
+            points
+        };
+        self.best_cost = cost;
+        self.best_path = Path::new(points);
+        // Informed örneklemede iki ağaç da ortak yolun elipsinden örnekler
+        self.start_tree.set_shared_cost(cost);
+        self.goal_tree.set_shared_cost(cost);
+        true
+    }
+}
+
+impl Planner for BiRrtStar {
+    // Sıradaki ağaçta bir RRT* iterasyonu çalıştırır, yeni düğümü diğer ağaca köprülemeyi
+    // dener ve en iyi yolu tazeler
+    fn step(&mut self) -> StepOutcome {
+        if self.is_finished() {
+            return StepOutcome::Converged;
+        }
+        self.iterations += 1;
+
+        let tree = if self.swapped { &mut self.goal_tree } else { &mut self.start_tree };
+        let mut outcome = tree.step();
+        if let StepOutcome::Added { index, .. } = outcome {
+            self.try_bridge(index);
+        }
+        // Yeniden bağlama köprü uçlarının maliyetini düşürmüş olabilir
+        let improved = self.refresh();
+        if let StepOutcome::Added { index, .. } = outcome {
+            outcome = StepOutcome::Added { index, improved };
+        }
+        self.swapped = !self.swapped;
+        outcome
+    }
+
+    fn is_finished(&self) -> bool {
+        self.iterations >= self.max_iterations
+    }
+
+    fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    fn best_cost(&self) -> f32 {
+        self.best_cost
+    }
+
+    fn best_path(&self) -> &Path {
+        &self.best_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maps;
+
+    // Planlayıcıyı bütçe bitene kadar çalıştırır; ilk çözümün iterasyonunu ve son maliyeti döner
+    fn run(planner: &mut impl Planner) -> (usize, f32) {
+        let mut first = None;
+        while !planner.is_finished() {
+            planner.step();
+            if first.is_none() && planner.best_cost().is_finite() {
+                first = Some(planner.iterations());
+            }
+        }
+        (first.expect("bütçe içinde çözüm bulunur"), planner.best_cost())
+    }
+
+    // Böcek kapanında (başlangıç kafesin içinde) çift yönlü RRT* ilk çözümü tek ağaçlı
+    // RRT*'tan daha az iterasyonda bulur ve aynı bütçenin sonunda yolu daha pahalı değildir
+    #[test]
+    fn bi_rrt_star_escapes_bug_trap_faster() {
+        let config = PlanConfig {
+            start: Point { x: 120.0, y: 120.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            obstacles: maps::bug_trap(),
+            max_iterations: 3000,
+            ..PlanConfig::default()
+        };
+        let (mut bi_first, mut rrt_star_first) = (0, 0);
+        let (mut bi_cost, mut rrt_star_cost) = (0.0, 0.0);
+        for seed in 0..5 {
+            let (first, cost) = run(&mut BiRrtStar::from_config(&config, seed));
+            bi_first += first;
+            bi_cost += cost;
+            let (first, cost) = run(&mut RRTStar::from_config(&config, seed));
+            rrt_star_first += first;
+            rrt_star_cost += cost;
+        }
+        assert!(bi_first * 3 < rrt_star_first * 2, "{bi_first} vs {rrt_star_first}");
+        assert!(bi_cost <= rrt_star_cost * 1.01, "{bi_cost} vs {rrt_star_cost}");
+    }
+}
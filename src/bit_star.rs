@@ -0,0 +1,380 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::roadmap::QueueEntry;
+use crate::{ConfigError, Node, Path, PlanConfig, Planner, Point, RRTStar, StepOutcome, Steering};
+
+// Varsayılan olarak her yığında çekilen örnek sayısı
+const DEFAULT_BATCH_SIZE: usize = 200;
+
+// Tek bir `step` çağrısında kenar kuyruğundan en fazla çıkarılan kenar sayısı; çizimin
+// akıcı kalması için
+const EDGES_PER_STEP: usize = 32;
+
+// BIT* grafındaki bir noktanın türü
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    // Henüz ağaca bağlanmamış örnek
+    Sample,
+    // Ağaçtaki düğüm
+    Vertex,
+    // En iyi yolu iyileştiremeyeceği için budanan örnek
+    Pruned,
+}
+
+// Kenar kuyruğundaki bir aday kenar; tahmini çözüm maliyeti en düşük olan önce çıkar
+struct EdgeEntry {
+    key: f32,    // g(from) + ĉ(from, to) + ĥ(to)
+    from: usize, // Ağaçtaki düğüm
+    to: usize,   // Örnek ya da (yeniden bağlama için) ağaçtaki düğüm
+}
+
+impl PartialEq for EdgeEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EdgeEntry {}
+
+impl PartialOrd for EdgeEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EdgeEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap en büyüğü çıkardığından karşılaştırma ters çevrilir
+        other.key.total_cmp(&self.key).then(other.from.cmp(&self.from)).then(other.to.cmp(&self.to))
+    }
+}
+
+// Batch Informed Trees (BIT*): örnekler yığınlar halinde çekilir ve ağaç, kenarları tahmini
+// çözüm maliyeti sırasıyla işleyerek büyür. Kenarların çarpışma denetimi ve gerçek maliyeti
+// yalnızca kuyruktan çıktıklarında, yolu iyileştirebilecekleri hâlâ geçerliyse hesaplanır.
+// Bir çözüm bulunduktan sonra yeni yığınlar informed elipsten çekilir ve iyileştiremeyecek
+// örnekler budanır. Örnekleme, çarpışma denetimi, kenar maliyetleri, hedef koşulu ve
+// yakınlık yarıçapı için RRT* planlayıcısı kullanılır
+pub struct Bit {
+    space: RRTStar,                   // Örnekleme, çarpışma denetimi ve maliyetler için kullanılan planlayıcı
+    nodes: Vec<Node>,                 // Başlangıç (0), hedef (1), ağaç düğümleri ve örnekler
+    kinds: Vec<Kind>,                 // Noktaların türü
+    fresh: Vec<bool>,                 // Örnek bu yığında mı çekildi
+    old: Vec<bool>,                   // Düğüm önceki bir yığında zaten ağaçtaydı
+    vertex_queue: BinaryHeap<QueueEntry>, // Genişletilecek düğümler; g + ĥ en düşük önce çıkar
+    edge_queue: BinaryHeap<EdgeEntry>, // İşlenecek aday kenarlar
+    goal_vertices: Vec<usize>,        // Hedef koşulunu sağlayan ağaç düğümleri
+    radius: f32,                      // Bu yığındaki komşuluk yarıçapı
+    batch_size: usize,                // Her yığında çekilen örnek sayısı
+    batch: usize,                     // Şu anki yığının sırası (henüz yığın yoksa 0)
+    iterations: usize,                // Kuyruktan çıkarılan kenar sayısı
+    max_iterations: usize,            // Kenar sınırı
+    best_goal: Option<usize>,         // Hedefe en düşük maliyetle ulaşan düğüm
+    best_path: Path,                  // Başlangıçtan hedefe en iyi yol
+}
+
+impl Bit {
+    // Yapılandırmadan, verilen tohumla tekrarlanabilir bir planlayıcı oluşturur
+    // Geçersiz parametrelerde paniğe girer (bkz. `try_from_config`)
+    pub fn from_config(config: &PlanConfig, seed: u64) -> Self {
+        Self::try_from_config(config, seed).unwrap_or_else(|error| panic!("invalid BIT* configuration: {error}"))
+    }
+
+    // Yapılandırmayı doğrulayarak planlayıcıyı kurar; `max_iterations` kuyruktan çıkarılacak
+    // kenar sayısını sınırlar. İlk yığın ilk `step` çağrısında çekilir
+    pub fn try_from_config(config: &PlanConfig, seed: u64) -> Result<Self, ConfigError> {
+        // Kenarlar doğru parçalarıdır; yönlü durumlar desteklenmez
+        let config = PlanConfig { steering: Steering::Straight, ..config.clone() };
+        let space = RRTStar::try_from_config(&config, seed)?;
+        let mut bit = Bit {
+            space,
+            nodes: vec![Node::new(config.start, 0.0, None, 0.0), Node::new(config.goal, 0.0, None, f32::INFINITY)],
+            kinds: vec![Kind::Vertex, Kind::Sample],
+            fresh: vec![false, true],
+            old: vec![false, false],
+            vertex_queue: BinaryHeap::new(),
+            edge_queue: BinaryHeap::new(),
+            goal_vertices: Vec::new(),
+            radius: 0.0,
+            batch_size: DEFAULT_BATCH_SIZE,
+            batch: 0,
+            iterations: 0,
+            max_iterations: config.max_iterations,
+            best_goal: None,
+            best_path: Path::default(),
+        };
+        // Başlangıç hedef koşulunu sağlıyorsa kök zaten bir çözümdür
+        if bit.space.is_goal(&config.start) {
+            bit.goal_vertices.push(0);
+            bit.select_best_goal();
+        }
+        Ok(bit)
+    }
+
+    // Her yığında çekilen örnek sayısını değiştirir; sonraki yığınlardan itibaren geçerlidir
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    // Örneklerin ve çarpışma denetiminin yapıldığı planlayıcı (engeller, sınırlar vb. için)
+    pub fn space(&self) -> &RRTStar {
+        &self.space
+    }
+
+    // Ağaç düğümleri ve örnekler; ağaca bağlananların ebeveyni vardır
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    // Henüz ağaca bağlanmamış ve budanmamış örnekler
+    pub fn samples(&self) -> impl Iterator<Item = &Point> {
+        self.nodes.iter().zip(&self.kinds).filter(|(_, &kind)| kind == Kind::Sample).map(|(node, _)| &node.point)
+    }
+
+    // Şu anki yığının sırası (1'den başlar; henüz yığın çekilmediyse 0)
+    pub fn batch(&self) -> usize {
+        self.batch
+    }
+
+    // Şu anki yığındaki komşuluk yarıçapı
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    // Başlangıçtan uzaklık (ĝ): noktaya ulaşan herhangi bir yolun maliyetinin alt sınırı
+    fn cost_to_come_estimate(&self, index: usize) -> f32 {
+        self.nodes[0].point.distance(&self.nodes[index].point)
+    }
+
+    // Hedef bölgesine uzaklık (ĥ): noktadan hedefe herhangi bir yolun maliyetinin alt sınırı
+    fn cost_to_go_estimate(&self, index: usize) -> f32 {
+        let distance = self.nodes[index].point.distance(&self.space.goal());
+        if self.space.is_goal(&self.nodes[index].point) {
+            0.0
+        } else {
+            (distance - self.space.goal_threshold).max(0.0)
+        }
+    }
+
+    // En iyi yolun maliyeti (yol yoksa sonsuz)
+    fn best_cost_value(&self) -> f32 {
+        self.best_goal.map_or(f32::INFINITY, |index| self.nodes[index].cost)
+    }
+
+    // Yeni bir yığın başlatır: iyileştiremeyecek örnekleri budar, informed elipsten yeni örnekler
+    // çeker, yarıçapı günceller ve iyileştirebilecek tüm düğümleri genişletme kuyruğuna koyar
+    fn new_batch(&mut self) {
+        self.batch += 1;
+        let best_cost = self.best_cost_value();
+        for index in 0..self.nodes.len() {
+            self.fresh[index] = false;
+            match self.kinds[index] {
+                Kind::Vertex => self.old[index] = true,
+                Kind::Sample if self.cost_to_come_estimate(index) + self.cost_to_go_estimate(index) >= best_cost => {
+                    self.kinds[index] = Kind::Pruned;
+                }
+                _ => {}
+            }
+        }
+
+        let mut drawn = 0;
+        while drawn < self.batch_size {
+            let point = self.space.sample_informed(best_cost);
+            if !self.space.is_collision_free(&point) {
+                continue;
+            }
+            self.nodes.push(Node::new(point, 0.0, None, f32::INFINITY));
+            self.kinds.push(Kind::Sample);
+            self.fresh.push(true);
+            self.old.push(false);
+            drawn += 1;
+        }
+
+        let alive = self.kinds.iter().filter(|&&kind| kind != Kind::Pruned).count();
+        self.radius = self.space.search_radius_for(alive);
+        for index in 0..self.nodes.len() {
+            if self.kinds[index] == Kind::Vertex {
+                let key = self.nodes[index].cost + self.cost_to_go_estimate(index);
+                if key < best_cost {
+                    self.vertex_queue.push(QueueEntry { cost: key, node: index });
+                }
+            }
+        }
+    }
+
+    // Düğümden yarıçap içindeki örneklere (ve düğüm bu yığında yeniyse yeniden bağlama için
+    // diğer düğümlere) yolu iyileştirebilecek aday kenarları kuyruğa ekler. Önceki yığınlardan
+    // kalan düğümler için yalnızca bu yığındaki örnekler denenir; diğerleri zaten denenmiştir
+    fn expand_vertex(&mut self, v: usize) {
+        let best_cost = self.best_cost_value();
+        let g_v = self.nodes[v].cost;
+        if g_v + self.cost_to_go_estimate(v) >= best_cost {
+            return;
+        }
+        let from = self.nodes[v].point;
+        for x in 0..self.nodes.len() {
+            let distance = from.distance(&self.nodes[x].point);
+            if x == v || distance > self.radius {
+                continue;
+            }
+            let key = g_v + distance + self.cost_to_go_estimate(x);
+            let candidate = match self.kinds[x] {
+                Kind::Sample => (!self.old[v] || self.fresh[x]) && key < best_cost,
+                Kind::Vertex => {
+                    !self.old[v]
+                        && self.nodes[x].parent != Some(v)
+                        && self.nodes[v].parent != Some(x)
+                        && key < best_cost
+                        && g_v + distance < self.nodes[x].cost
+                }
+                Kind::Pruned => false,
+            };
+            if candidate {
+                self.edge_queue.push(EdgeEntry { key, from: v, to: x });
+            }
+        }
+        self.old[v] = true;
+    }
+
+    // Kuyruktan çıkan kenarı işler; ağaç değiştiyse eklenen ya da yeniden bağlanan düğüm döner
+    fn process_edge(&mut self, edge: EdgeEntry) -> Option<usize> {
+        let (v, x) = (edge.from, edge.to);
+        let best_cost = self.best_cost_value();
+        let (from, to) = (self.nodes[v].point, self.nodes[x].point);
+        let estimate = from.distance(&to);
+        // Kenar eklendiğinden beri düğümün maliyeti düşmüş olabilir; kenar artık iyileştirmiyorsa atlanır
+        if self.kinds[x] == Kind::Pruned || self.nodes[v].cost + estimate >= self.nodes[x].cost {
+            return None;
+        }
+        if self.cost_to_come_estimate(v) + estimate + self.cost_to_go_estimate(x) >= best_cost {
+            return None;
+        }
+        if !self.space.is_edge_collision_free(&from, &to) {
+            return None;
+        }
+        let cost = self.nodes[v].cost + self.space.edge_cost(&from, &to);
+        if cost >= self.nodes[x].cost || self.cost_to_come_estimate(v) + self.space.edge_cost(&from, &to) + self.cost_to_go_estimate(x) >= best_cost {
+            return None;
+        }
+
+        match self.kinds[x] {
+            Kind::Vertex => {
+                // Yeniden bağlama: eski ebeveynden kopar ve alt ağacın maliyetlerini güncelle
+                if let Some(parent) = self.nodes[x].parent {
+                    self.nodes[parent].children.retain(|&child| child != x);
+                }
+            }
+            _ => {
+                // Yeni düğüm bu yığında genişletilmek üzere kuyruğa girer
+                self.kinds[x] = Kind::Vertex;
+                self.old[x] = false;
+                if self.space.is_goal(&to) {
+                    self.goal_vertices.push(x);
+                }
+                self.vertex_queue.push(QueueEntry { cost: cost + self.cost_to_go_estimate(x), node: x });
+            }
+        }
+        self.nodes[x].parent = Some(v);
+        self.nodes[x].cost = cost;
+        self.nodes[v].children.push(x);
+        self.propagate_cost(x);
+        Some(x)
+    }
+
+    // Düğümün maliyeti değiştikten sonra alt ağacındaki maliyetleri yeniden hesaplar
+    fn propagate_cost(&mut self, index: usize) {
+        let mut stack = vec![index];
+        while let Some(current) = stack.pop() {
+            for i in 0..self.nodes[current].children.len() {
+                let child = self.nodes[current].children[i];
+                let edge = self.space.edge_cost(&self.nodes[current].point, &self.nodes[child].point);
+                self.nodes[child].cost = self.nodes[current].cost + edge;
+                stack.push(child);
+            }
+        }
+    }
+
+    // Hedef düğümleri arasından en ucuzunu seçer ve yolu yeniden izler; yol iyileştiyse 'true'
+    fn select_best_goal(&mut self) -> bool {
+        let previous = self.best_cost_value();
+        self.best_goal = self
+            .goal_vertices
+            .iter()
+            .copied()
+            .min_by(|&a, &b| self.nodes[a].cost.total_cmp(&self.nodes[b].cost).then(a.cmp(&b)));
+        let Some(index) = self.best_goal else {
+            return false;
+        };
+        let mut points = Vec::new();
+        let mut current = Some(index);
+        while let Some(node) = current {
+            points.push(self.nodes[node].point);
+            current = self.nodes[node].parent;
+        }
+        points.reverse();
+        self.best_path = Path::new(points);
+        self.best_cost_value() < previous
+    }
+}
+
+impl Planner for Bit {
+    // Kenar kuyruğundan en fazla `EDGES_PER_STEP` kenar işler; kuyruklar boşaldığında ya da
+    // kalan kenarların hiçbiri yolu iyileştiremiyorsa yeni bir yığın başlatır. Yığın başlatmak
+    // da bir iterasyon sayılır, böylece bağlanamayan haritalarda da sınıra ulaşılır
+    fn step(&mut self) -> StepOutcome {
+        if self.is_finished() {
+            return StepOutcome::Converged;
+        }
+        let mut outcome = StepOutcome::Collision;
+        for _ in 0..EDGES_PER_STEP {
+            if self.is_finished() {
+                break;
+            }
+            self.iterations += 1;
+            if self.vertex_queue.is_empty() && self.edge_queue.is_empty() {
+                self.new_batch();
+            }
+            // Kenarlardan daha umut verici düğümler önce genişletilir
+            while let Some(vertex) = self.vertex_queue.peek() {
+                if self.edge_queue.peek().is_some_and(|edge| edge.key < vertex.cost) {
+                    break;
+                }
+                let vertex = self.vertex_queue.pop().expect("kuyruk boş değil").node;
+                self.expand_vertex(vertex);
+            }
+            let Some(edge) = self.edge_queue.pop() else {
+                continue;
+            };
+            // Kuyruk tahmini maliyet sırasıyla çıktığından kalan kenarlar da iyileştiremez
+            if edge.key >= self.best_cost_value() {
+                self.edge_queue.clear();
+                self.vertex_queue.clear();
+                continue;
+            }
+            if let Some(index) = self.process_edge(edge) {
+                let improved = self.select_best_goal();
+                outcome = StepOutcome::Added { index, improved };
+            }
+        }
+        outcome
+    }
+
+    // İterasyon sınırı dolduğunda ya da yol alt sınıra (başlangıcın hedef bölgesine uzaklığı)
+    // ulaştığında biter
+    fn is_finished(&self) -> bool {
+        self.iterations >= self.max_iterations || self.best_cost_value() <= self.cost_to_go_estimate(0)
+    }
+
+    fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    fn best_cost(&self) -> f32 {
+        self.best_cost_value()
+    }
+
+    fn best_path(&self) -> &Path {
+        &self.best_path
+    }
+}
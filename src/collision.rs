@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use crate::{Obstacle, OccupancyGrid, Point, RRTStar};
+
+// Planlayıcının çarpışma denetimini dışarıdan genişletmek için arayüz: bir nokta ya da iki
+// nokta arasındaki doğru parçası engelsiz mi. Harita dışı bir dünya modeli (örneğin bir fizik
+// motoru ya da mesafe alanı) `RRTStar::set_collision_checker` ile planlayıcıya bağlanabilir
+pub trait CollisionChecker {
+    // Nokta engelsiz mi
+    fn is_free(&self, p: &Point) -> bool;
+    // [a, b] doğru parçası engelsiz mi
+    fn edge_free(&self, a: &Point, b: &Point) -> bool;
+}
+
+impl CollisionChecker for Obstacle {
+    fn is_free(&self, p: &Point) -> bool {
+        !self.contains(p)
+    }
+
+    // Parçanın engele olan kesin mesafesi sıfırsa kesişir
+    fn edge_free(&self, a: &Point, b: &Point) -> bool {
+        self.segment_distance(a, b) > 0.0
+    }
+}
+
+impl CollisionChecker for [Obstacle] {
+    fn is_free(&self, p: &Point) -> bool {
+        self.iter().all(|obstacle| obstacle.is_free(p))
+    }
+
+    fn edge_free(&self, a: &Point, b: &Point) -> bool {
+        self.iter().all(|obstacle| obstacle.edge_free(a, b))
+    }
+}
+
+impl CollisionChecker for Vec<Obstacle> {
+    fn is_free(&self, p: &Point) -> bool {
+        self.as_slice().is_free(p)
+    }
+
+    fn edge_free(&self, a: &Point, b: &Point) -> bool {
+        self.as_slice().edge_free(a, b)
+    }
+}
+
+// Paylaşılan denetleyici: aynı dünya modeli birden fazla planlayıcıya bağlanabilir (bkz. `plan_through`)
+impl<T: CollisionChecker + ?Sized> CollisionChecker for Arc<T> {
+    fn is_free(&self, p: &Point) -> bool {
+        (**self).is_free(p)
+    }
+
+    fn edge_free(&self, a: &Point, b: &Point) -> bool {
+        (**self).edge_free(a, b)
+    }
+}
+
+impl CollisionChecker for OccupancyGrid {
+    fn is_free(&self, p: &Point) -> bool {
+        !self.contains(p)
+    }
+
+    // Parçanın geçtiği tüm hücreler denetlenir
+    fn edge_free(&self, a: &Point, b: &Point) -> bool {
+        !self.segment_intersects(a, b)
+    }
+}
+
+// Planlayıcının kendisi de bir denetleyicidir: engeller, doluluk ızgarası ve bağlanmış özel
+// denetleyici birlikte kullanılır (yol kısaltma gibi planlama sonrası işlemler için)
+impl CollisionChecker for RRTStar {
+    fn is_free(&self, p: &Point) -> bool {
+        self.is_collision_free(p)
+    }
+
+    fn edge_free(&self, a: &Point, b: &Point) -> bool {
+        self.is_edge_collision_free(a, b)
+    }
+}
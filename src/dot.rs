@@ -0,0 +1,33 @@
+use std::fmt::Write;
+
+use crate::RRTStar;
+
+impl RRTStar {
+    // Ağacı GraphViz DOT biçiminde döner: her düğüm indeksi ve maliyetiyle etiketlenir, kenarlar
+    // çocuktan ebeveyne çizilir. En iyi yol üzerindeki düğümler ve kenarlar yeşil, engel
+    // içinde kalan düğümler gri gösterilir. `dot -Tpng` ile görüntülenebilir
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph rrt_star {\n    node [shape=circle, fontsize=8];\n");
+        let on_best_path = |index: usize| self.best_path_nodes.contains(&index);
+
+        // String'e yazmak hata üretmez, bu yüzden `writeln!` sonuçları göz ardı edilir
+        for (index, node) in self.nodes.iter().enumerate() {
+            let style = if on_best_path(index) {
+                ", color=green, penwidth=2"
+            } else if node.blocked {
+                ", color=gray, fontcolor=gray"
+            } else {
+                ""
+            };
+            let _ = writeln!(dot, "    n{index} [label=\"{index}\\n{:.2}\"{style}];", node.cost);
+        }
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let Some(parent) = node.parent {
+                let style = if on_best_path(index) && on_best_path(parent) { " [color=green, penwidth=2]" } else { "" };
+                let _ = writeln!(dot, "    n{index} -> n{parent}{style};");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,248 @@
+use std::f32::consts::TAU;
+
+use crate::Point;
+
+// Dubins ve Reeds-Shepp yollarını oluşturan parçaların türleri: sola dönüş, düz, sağa dönüş
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Segment {
+    Left,
+    Straight,
+    Right,
+}
+
+// Altı Dubins yol ailesi; her biri üç parçanın sırasıdır
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DubinsWord {
+    Lsl,
+    Rsr,
+    Lsr,
+    Rsl,
+    Rlr,
+    Lrl,
+}
+
+impl DubinsWord {
+    const ALL: [DubinsWord; 6] = [DubinsWord::Lsl, DubinsWord::Rsr, DubinsWord::Lsr, DubinsWord::Rsl, DubinsWord::Rlr, DubinsWord::Lrl];
+
+    fn segments(self) -> [Segment; 3] {
+        use Segment::{Left, Right, Straight};
+        match self {
+            DubinsWord::Lsl => [Left, Straight, Left],
+            DubinsWord::Rsr => [Right, Straight, Right],
+            DubinsWord::Lsr => [Left, Straight, Right],
+            DubinsWord::Rsl => [Right, Straight, Left],
+            DubinsWord::Rlr => [Right, Left, Right],
+            DubinsWord::Lrl => [Left, Right, Left],
+        }
+    }
+}
+
+// En küçük dönüş yarıçapı sabit, yalnızca ileri giden bir aracın iki konum ve yön arasındaki
+// en kısa yolu. Açılar x ekseninden y eksenine doğru ölçülür (sol dönüş açıyı artırır)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DubinsPath {
+    start: Point,       // Başlangıç noktası
+    start_heading: f32, // Başlangıç yönü (radyan)
+    turn_radius: f32,   // Dönüş yarıçapı
+    word: DubinsWord,   // Yol ailesi
+    lengths: [f32; 3],  // Parça uzunlukları (yarıçapa bölünmüş, dönüşlerde radyan)
+}
+
+// Açıyı [0, 2π) aralığına getirir
+fn wrap_angle(angle: f32) -> f32 {
+    angle.rem_euclid(TAU)
+}
+
+// Normalize edilmiş (d = mesafe / yarıçap, başlangıç ve bitiş açıları alpha, beta) problemde
+// verilen ailenin parça uzunlukları; aile bu iki konumu bağlayamıyorsa None
+fn word_lengths(word: DubinsWord, d: f32, alpha: f32, beta: f32) -> Option<[f32; 3]> {
+    let (sa, ca) = alpha.sin_cos();
+    let (sb, cb) = beta.sin_cos();
+    let c_ab = (alpha - beta).cos();
+    match word {
+        DubinsWord::Lsl => {
+            let p_squared = 2.0 + d * d - 2.0 * c_ab + 2.0 * d * (sa - sb);
+            if p_squared < 0.0 {
+                return None;
+            }
+            let angle = (cb - ca).atan2(d + sa - sb);
+            Some([wrap_angle(angle - alpha), p_squared.sqrt(), wrap_angle(beta - angle)])
+        }
+        DubinsWord::Rsr => {
+            let p_squared = 2.0 + d * d - 2.0 * c_ab + 2.0 * d * (sb - sa);
+            if p_squared < 0.0 {
+                return None;
+            }
+            let angle = (ca - cb).atan2(d - sa + sb);
+            Some([wrap_angle(alpha - angle), p_squared.sqrt(), wrap_angle(angle - beta)])
+        }
+        DubinsWord::Lsr => {
+            let p_squared = -2.0 + d * d + 2.0 * c_ab + 2.0 * d * (sa + sb);
+            if p_squared < 0.0 {
+                return None;
+            }
+            let p = p_squared.sqrt();
+            let angle = (-ca - cb).atan2(d + sa + sb) - (-2.0f32).atan2(p);
+            Some([wrap_angle(angle - alpha), p, wrap_angle(angle - beta)])
+        }
+        DubinsWord::Rsl => {
+            let p_squared = -2.0 + d * d + 2.0 * c_ab - 2.0 * d * (sa + sb);
+            if p_squared < 0.0 {
+                return None;
+            }
+            let p = p_squared.sqrt();
+            let angle = (ca + cb).atan2(d - sa - sb) - 2.0f32.atan2(p);
+            Some([wrap_angle(alpha - angle), p, wrap_angle(beta - angle)])
+        }
+        DubinsWord::Rlr => {
+            let cosine = (6.0 - d * d + 2.0 * c_ab + 2.0 * d * (sa - sb)) / 8.0;
+            if cosine.abs() > 1.0 {
+                return None;
+            }
+            let p = wrap_angle(TAU - cosine.acos());
+            let t = wrap_angle(alpha - (ca - cb).atan2(d - sa + sb) + p / 2.0);
+            Some([t, p, wrap_angle(alpha - beta - t + p)])
+        }
+        DubinsWord::Lrl => {
+            let cosine = (6.0 - d * d + 2.0 * c_ab + 2.0 * d * (sb - sa)) / 8.0;
+            if cosine.abs() > 1.0 {
+                return None;
+            }
+            let p = wrap_angle(TAU - cosine.acos());
+            let t = wrap_angle(-alpha - (ca - cb).atan2(d + sa - sb) + p / 2.0);
+            Some([t, p, wrap_angle(beta - alpha - t + p)])
+        }
+    }
+}
+
+impl DubinsPath {
+    // İki konum ve yön arasındaki en kısa Dubins yolu; yarıçap pozitif olmalıdır.
+    // Eşit uzunluklu aileler arasında `DubinsWord` sırasındaki ilki seçilir
+    pub fn shortest(start: Point, start_heading: f32, goal: Point, goal_heading: f32, turn_radius: f32) -> DubinsPath {
+        let (dx, dy) = (goal.x - start.x, goal.y - start.y);
+        let d = dx.hypot(dy) / turn_radius;
+        let theta = if d > 0.0 { dy.atan2(dx) } else { 0.0 };
+        let alpha = wrap_angle(start_heading - theta);
+        let beta = wrap_angle(goal_heading - theta);
+
+        let (word, lengths) = DubinsWord::ALL
+            .into_iter()
+            .filter_map(|word| word_lengths(word, d, alpha, beta).map(|lengths| (word, lengths)))
+            .min_by(|(_, a), (_, b)| a.iter().sum::<f32>().total_cmp(&b.iter().sum::<f32>()))
+            .expect("LSL ya da RSR ailelerinden en az biri her zaman tanımlıdır");
+        DubinsPath { start, start_heading, turn_radius, word, lengths }
+    }
+
+    // Yol ailesi
+    pub fn word(&self) -> DubinsWord {
+        self.word
+    }
+
+    // Yolun toplam uzunluğu
+    pub fn length(&self) -> f32 {
+        self.lengths.iter().sum::<f32>() * self.turn_radius
+    }
+
+    // Yolun başından `distance` uzunluk sonraki konum ve yön (yol dışına taşan mesafeler kırpılır)
+    pub fn sample(&self, distance: f32) -> (Point, f32) {
+        let mut remaining = distance.clamp(0.0, self.length()) / self.turn_radius;
+        let (mut point, mut heading) = (self.start, self.start_heading);
+        for (segment, &length) in self.word.segments().into_iter().zip(&self.lengths) {
+            let travel = remaining.min(length);
+            (point, heading) = advance(point, heading, segment, travel, self.turn_radius);
+            remaining -= travel;
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        (point, heading)
+    }
+
+    // Yolu en fazla `spacing` uzunluklu parçalarla yaklaşan noktalar dizisi (uçlar dahil)
+    pub fn polyline(&self, spacing: f32) -> Vec<Point> {
+        let length = self.length();
+        let pieces = (length / spacing).ceil().max(1.0) as usize;
+        (0..=pieces).map(|i| self.sample(length * i as f32 / pieces as f32).0).collect()
+    }
+}
+
+// Tek bir parça boyunca yarıçapa bölünmüş `travel` kadar ilerler; negatif `travel` geri gider
+pub(crate) fn advance(point: Point, heading: f32, segment: Segment, travel: f32, turn_radius: f32) -> (Point, f32) {
+    let r = turn_radius;
+    match segment {
+        Segment::Straight => {
+            let (sin, cos) = heading.sin_cos();
+            (Point { x: point.x + r * travel * cos, y: point.y + r * travel * sin }, heading)
+        }
+        Segment::Left => {
+            let end = heading + travel;
+            (Point { x: point.x + r * (end.sin() - heading.sin()), y: point.y - r * (end.cos() - heading.cos()) }, end)
+        }
+        Segment::Right => {
+            let end = heading - travel;
+            (Point { x: point.x - r * (end.sin() - heading.sin()), y: point.y + r * (end.cos() - heading.cos()) }, end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    const TOLERANCE: f32 = 1e-3;
+
+    // İki açı arasındaki en küçük fark
+    fn angle_difference(a: f32, b: f32) -> f32 {
+        let difference = wrap_angle(a - b);
+        difference.min(TAU - difference)
+    }
+
+    #[test]
+    fn straight_line_along_heading() {
+        let path = DubinsPath::shortest(Point { x: 0.0, y: 0.0 }, 0.0, Point { x: 10.0, y: 0.0 }, 0.0, 2.0);
+        assert!((path.length() - 10.0).abs() < TOLERANCE, "{}", path.length());
+    }
+
+    #[test]
+    fn u_turn_with_offset() {
+        // Yarım sol dönüşle geri dönüp 5 birim düz gidilir: π·r + 5
+        let r = 2.0;
+        let path = DubinsPath::shortest(Point { x: 0.0, y: 0.0 }, 0.0, Point { x: -5.0, y: 2.0 * r }, PI, r);
+        assert_eq!(path.word(), DubinsWord::Lsl);
+        assert!((path.length() - (PI * r + 5.0)).abs() < TOLERANCE, "{}", path.length());
+    }
+
+    #[test]
+    fn lsl_quarter_turn() {
+        // Çeyrek sol dönüş ve ardından 5 birim düz: π/2·r + 5
+        let path = DubinsPath::shortest(Point { x: 0.0, y: 0.0 }, 0.0, Point { x: 1.0, y: 6.0 }, FRAC_PI_2, 1.0);
+        assert_eq!(path.word(), DubinsWord::Lsl);
+        assert!((path.length() - (FRAC_PI_2 + 5.0)).abs() < TOLERANCE, "{}", path.length());
+    }
+
+    #[test]
+    fn rsr_quarter_turn() {
+        let path = DubinsPath::shortest(Point { x: 0.0, y: 0.0 }, 0.0, Point { x: 1.0, y: -6.0 }, -FRAC_PI_2, 1.0);
+        assert_eq!(path.word(), DubinsWord::Rsr);
+        assert!((path.length() - (FRAC_PI_2 + 5.0)).abs() < TOLERANCE, "{}", path.length());
+    }
+
+    #[test]
+    fn sample_at_length_reaches_goal() {
+        let start = Point { x: 3.0, y: -2.0 };
+        for i in 0..8 {
+            for j in 0..8 {
+                for &distance in &[0.5, 3.0, 12.0] {
+                    let angle = i as f32 * TAU / 8.0;
+                    let goal = Point { x: start.x + distance * angle.cos(), y: start.y + distance * angle.sin() };
+                    let (start_heading, goal_heading) = (i as f32 * 0.7, j as f32 * TAU / 8.0);
+                    let path = DubinsPath::shortest(start, start_heading, goal, goal_heading, 1.5);
+                    let (end, heading) = path.sample(path.length());
+                    assert!(end.distance(&goal) < TOLERANCE, "{:?}: {end:?} != {goal:?}", path.word());
+                    assert!(angle_difference(heading, goal_heading) < TOLERANCE, "{:?}: {heading} != {goal_heading}", path.word());
+                }
+            }
+        }
+    }
+}
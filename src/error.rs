@@ -0,0 +1,266 @@
+use std::fmt;
+
+use crate::{Bounds, Path, PlanStats, Point, TreeInvariantError};
+
+// Geçersiz planlayıcı parametreleri
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    // Adım boyutu pozitif ve sonlu olmalı
+    NonPositiveStepSize(f32),
+    // Hedef eşiği negatif olamaz (0 = yalnızca tam hedef)
+    NegativeGoalThreshold(f32),
+    // Sabit yakınlık yarıçapı adım boyutundan küçük olamaz
+    SearchRadiusTooSmall { search_radius: f32, step_size: f32 },
+    // Uyarlanabilir yarıçapın gamma değeri pozitif olmalı
+    NonPositiveGamma(f32),
+    // Hedef örnekleme olasılıkları [0, 1) aralığında olmalı
+    InvalidGoalBias(f32),
+    // Karışık örneklemede ağırlıklar sonlu ve negatif olmamalı, toplamları pozitif olmalı
+    InvalidSamplerWeight(f32),
+    // Potansiyel alanı parametresi geçersiz (kazançlar ve en büyük itme negatif olmamalı, etki
+    // mesafesi pozitif olmalı; hepsi sonlu)
+    InvalidPotentialField { parameter: &'static str, value: f32 },
+    // Dönüş açısı sınırı (0, π] aralığında olmalı
+    InvalidTurnAngle(f32),
+    // Dubins ve Reeds-Shepp dönüş yarıçapı pozitif ve sonlu olmalı
+    NonPositiveTurnRadius(f32),
+    // Reeds-Shepp geri gitme cezası 1'den küçük olmayan sonlu bir çarpan olmalı
+    InvalidReversePenalty(f32),
+    // Çift integratör parametresi geçersiz (sınırlar ve zaman adımı pozitif, hız ağırlığı
+    // negatif olmamalı)
+    InvalidDynamics { parameter: &'static str, value: f32 },
+    // Kinodinamik planlamada hedefe tam olarak varılamadığından hedef eşiği pozitif olmalı
+    KinodynamicGoalThreshold,
+    // Hedef yönü sonlu olmalı
+    NonFiniteGoalHeading(f32),
+    // Çarpışma kontrolü aralığı negatif olamaz (0 = varsayılan)
+    NegativeCollisionResolution(f32),
+    // Engel mesafesine göre adımda en kısa adım (0, step_size] aralığında olmalı
+    InvalidMinStep { min_step: f32, step_size: f32 },
+    // Engel mesafesine göre adımda ölçek pozitif ve sonlu olmalı
+    InvalidStepScale(f32),
+    // Çok hedefli planlayıcıya hiç hedef verilmedi
+    NoGoals,
+    // Örnekleme bölgesinin her ekseninde alt sınır üst sınırdan küçük ve sonlu olmalı
+    EmptyBounds(Bounds),
+    // Başlangıç noktası bir engelin ya da dolu bir ızgara hücresinin içinde
+    StartInCollision(Point),
+    // Hedef noktası bir engelin ya da dolu bir ızgara hücresinin içinde
+    GoalInCollision(Point),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NonPositiveStepSize(step_size) => {
+                write!(f, "step size must be positive and finite, got {step_size}")
+            }
+            ConfigError::NegativeGoalThreshold(goal_threshold) => {
+                write!(f, "goal threshold must be zero or positive, got {goal_threshold}")
+            }
+            ConfigError::SearchRadiusTooSmall { search_radius, step_size } => {
+                write!(f, "search radius {search_radius} is smaller than step size {step_size}")
+            }
+            ConfigError::NonPositiveGamma(gamma) => write!(f, "adaptive radius gamma must be positive, got {gamma}"),
+            ConfigError::InvalidGoalBias(probability) => {
+                write!(f, "goal bias must be in [0, 1), got {probability}")
+            }
+            ConfigError::InvalidSamplerWeight(weight) => {
+                write!(f, "sampler weights must be finite and non-negative with a positive sum, got {weight}")
+            }
+            ConfigError::InvalidPotentialField { parameter, value } => {
+                write!(f, "potential field {parameter} is invalid, got {value}")
+            }
+            ConfigError::InvalidTurnAngle(angle) => {
+                write!(f, "max turn angle must be in (0, pi] radians, got {angle}")
+            }
+            ConfigError::NonPositiveTurnRadius(turn_radius) => {
+                write!(f, "turn radius must be positive and finite, got {turn_radius}")
+            }
+            ConfigError::InvalidReversePenalty(reverse_penalty) => {
+                write!(f, "reverse penalty must be a finite multiplier of at least 1, got {reverse_penalty}")
+            }
+            ConfigError::InvalidDynamics { parameter, value } => {
+                write!(f, "double integrator {parameter} is invalid, got {value}")
+            }
+            ConfigError::KinodynamicGoalThreshold => {
+                write!(f, "kinodynamic steering cannot reach the goal exactly; goal threshold must be positive")
+            }
+            ConfigError::NonFiniteGoalHeading(heading) => write!(f, "goal heading must be finite, got {heading}"),
+            ConfigError::NegativeCollisionResolution(collision_resolution) => {
+                write!(f, "collision resolution must be zero or positive, got {collision_resolution}")
+            }
+            ConfigError::InvalidMinStep { min_step, step_size } => {
+                write!(f, "minimum step must be in (0, {step_size}], got {min_step}")
+            }
+            ConfigError::InvalidStepScale(scale) => write!(f, "step clearance scale must be positive and finite, got {scale}"),
+            ConfigError::NoGoals => write!(f, "at least one goal is required"),
+            ConfigError::EmptyBounds(bounds) => write!(
+                f,
+                "sampling bounds must satisfy min < max on both axes, got x in [{}, {}] and y in [{}, {}]",
+                bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y
+            ),
+            ConfigError::StartInCollision(start) => {
+                write!(f, "start ({}, {}) is inside an obstacle", start.x, start.y)
+            }
+            ConfigError::GoalInCollision(goal) => write!(f, "goal ({}, {}) is inside an obstacle", goal.x, goal.y),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// Planlamanın bir çözüm üretmeden sona erme nedenleri
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlanError {
+    // Son `stall_limit` iterasyonda hiç düğüm eklenemedi; hedef büyük olasılıkla engellerle kapalı
+    Stuck { iterations: usize, nodes: usize },
+    // İterasyon sınırı hedefe ulaşılamadan doldu; `best_partial` hedefe en yakın düğüme giden yoldur
+    NoPathFound { iterations: usize, nodes: usize, best_partial: Option<Path> },
+    // Doluluk ızgarasında hedef hücresi başlangıç hücresine boş hücrelerle bağlı değil;
+    // planlama hiç başlatılmadı
+    GoalUnreachable,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::Stuck { iterations, nodes } => write!(
+                f,
+                "planner made no progress and stopped after {iterations} iterations with {nodes} nodes; the map may be infeasible"
+            ),
+            PlanError::NoPathFound { iterations, nodes, .. } => {
+                write!(f, "no path to the goal found within {iterations} iterations ({nodes} nodes)")
+            }
+            PlanError::GoalUnreachable => write!(f, "goal is not connected to the start on the occupancy grid"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+// Doluluk ızgarası oluşturulurken verilen geçersiz boyutlar
+#[derive(Clone, Debug, PartialEq)]
+pub enum GridError {
+    // Hücre sayısı genişlik * yükseklik ile uyuşmuyor
+    SizeMismatch { expected: usize, actual: usize },
+    // Hücre boyutu pozitif ve sonlu olmalı
+    NonPositiveResolution(f32),
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::SizeMismatch { expected, actual } => {
+                write!(f, "occupancy grid needs {expected} cells but {actual} were given")
+            }
+            GridError::NonPositiveResolution(resolution) => {
+                write!(f, "grid resolution must be positive and finite, got {resolution}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+// Kütüphanenin dosya okuyan ve yazan işlemlerinin ortak hata türü; alana özgü hatalar da
+// `?` ile buna çevrilebilir, böylece çağıranlar tek bir hata türüyle (ya da `Box<dyn Error>`)
+// çalışabilir
+#[derive(Debug)]
+pub enum RrtError {
+    // Dosya okunamadı ya da yazılamadı
+    Io(std::io::Error),
+    // Dosya içeriği beklenen biçimde değil (planlayıcı durumu, engel listesi, PGM görüntüsü)
+    Parse(String),
+    // Planlayıcı parametreleri geçersiz
+    Config(ConfigError),
+    // Doluluk ızgarasının boyutları geçersiz
+    Grid(GridError),
+    // Planlama bir çözüm üretmeden sona erdi
+    Plan(PlanError),
+    // Yüklenen ya da denetlenen ağaç tutarsız
+    Corrupt(TreeInvariantError),
+}
+
+impl fmt::Display for RrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RrtError::Io(error) => write!(f, "could not access file: {error}"),
+            RrtError::Parse(reason) => write!(f, "could not parse file: {reason}"),
+            RrtError::Config(error) => write!(f, "invalid configuration: {error}"),
+            RrtError::Grid(error) => write!(f, "invalid occupancy grid: {error}"),
+            RrtError::Plan(error) => write!(f, "planning failed: {error}"),
+            RrtError::Corrupt(error) => write!(f, "planner tree is inconsistent: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for RrtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RrtError::Io(error) => Some(error),
+            RrtError::Parse(_) => None,
+            RrtError::Config(error) => Some(error),
+            RrtError::Grid(error) => Some(error),
+            RrtError::Plan(error) => Some(error),
+            RrtError::Corrupt(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for RrtError {
+    fn from(error: std::io::Error) -> Self {
+        RrtError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for RrtError {
+    fn from(error: serde_json::Error) -> Self {
+        RrtError::Parse(error.to_string())
+    }
+}
+
+impl From<ConfigError> for RrtError {
+    fn from(error: ConfigError) -> Self {
+        RrtError::Config(error)
+    }
+}
+
+impl From<GridError> for RrtError {
+    fn from(error: GridError) -> Self {
+        RrtError::Grid(error)
+    }
+}
+
+impl From<PlanError> for RrtError {
+    fn from(error: PlanError) -> Self {
+        RrtError::Plan(error)
+    }
+}
+
+impl From<TreeInvariantError> for RrtError {
+    fn from(error: TreeInvariantError) -> Self {
+        RrtError::Corrupt(error)
+    }
+}
+
+// Ara noktalar üzerinden planlamada (bkz. `plan_through`) başarısız olan bacak
+#[derive(Debug)]
+pub struct WaypointError {
+    pub leg: usize,           // Başarısız bacağın sırası (0 = başlangıçtan ilk ara noktaya)
+    pub error: RrtError,      // Bacağın yapılandırma ya da planlama hatası
+    pub legs: Vec<PlanStats>, // Başarısız bacaktan önceki bacakların özetleri
+    pub partial: Path,        // Başarısız bacağın başlangıcına kadar birleştirilen yol
+}
+
+impl fmt::Display for WaypointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "leg {} of the waypoint route failed: {}", self.leg + 1, self.error)
+    }
+}
+
+impl std::error::Error for WaypointError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
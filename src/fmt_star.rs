@@ -0,0 +1,225 @@
+use std::collections::BinaryHeap;
+
+use crate::roadmap::QueueEntry;
+use crate::{ConfigError, Node, Path, PlanConfig, Planner, RRTStar, StepOutcome, Steering};
+
+// FMT* genişlemesinde bir düğümün durumu
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Visit {
+    // Henüz ağaca bağlanmadı
+    Unvisited,
+    // Ağaçta ve genişletilmeyi bekliyor (dalga cephesi)
+    Open,
+    // Genişletildi; artık ebeveyn adayı değil
+    Closed,
+}
+
+// Sabit bir örnek bütçesiyle tek sorgulu planlama için FMT* (Fast Marching Tree): önce
+// `max_iterations` adet boş nokta örneklenir, ardından ağaç başlangıçtan maliyet sırasıyla
+// bir dalga cephesi gibi ilerler. Her adımda en düşük maliyetli açık düğümün henüz bağlanmamış
+// komşuları, kendi komşuları arasındaki en ucuz açık düğüme bağlanır; çarpışma yalnızca bu
+// seçilen kenar için (tembel olarak) denetlenir. Örnekleme, çarpışma denetimi, kenar maliyetleri
+// ve yakınlık yarıçapı için RRT* planlayıcısı kullanılır
+pub struct Fmt {
+    space: RRTStar,             // Örnekleme, çarpışma denetimi ve maliyetler için kullanılan planlayıcı
+    nodes: Vec<Node>,           // Başlangıç (0), hedef (1) ve örnekler; ağaca bağlanmayanların ebeveyni yoktur
+    neighbors: Vec<Vec<usize>>, // Bağlantı yarıçapı içindeki düğümler
+    visits: Vec<Visit>,         // Düğümlerin genişleme durumu
+    open: BinaryHeap<QueueEntry>, // Açık düğümler; en düşük maliyetli önce çıkar
+    connection_radius: f32,     // Komşuluk yarıçapı
+    iterations: usize,          // Şu ana kadar yapılan genişleme sayısı
+    best_cost: f32,             // Hedefe ulaşan yolun maliyeti (yol yoksa sonsuz)
+    best_path: Path,            // Başlangıçtan hedefe yol
+}
+
+impl Fmt {
+    // Yapılandırmadan, verilen tohumla tekrarlanabilir bir planlayıcı oluşturur
+    // Geçersiz parametrelerde paniğe girer (bkz. `try_from_config`)
+    pub fn from_config(config: &PlanConfig, seed: u64) -> Self {
+        Self::try_from_config(config, seed).unwrap_or_else(|error| panic!("invalid FMT* configuration: {error}"))
+    }
+
+    // Yapılandırmayı doğrulayarak örnekleri çeker ve komşuluk grafını kurar; örnek sayısı
+    // `max_iterations`, bağlantı yarıçapı bu kadar örnek için `search_radius` formülüdür.
+    // Genişleme (örneklerin ağaca bağlanması) `step` çağrılarıyla yapılır
+    pub fn try_from_config(config: &PlanConfig, seed: u64) -> Result<Self, ConfigError> {
+        // Kenarlar doğru parçalarıdır; yönlü durumlar desteklenmez
+        let config = PlanConfig { steering: Steering::Straight, ..config.clone() };
+        let mut space = RRTStar::try_from_config(&config, seed)?;
+
+        let mut points = vec![config.start, config.goal];
+        let bounds = space.bounds();
+        while points.len() < config.max_iterations + 2 {
+            let point = space.random_point(bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y);
+            if space.is_collision_free(&point) {
+                points.push(point);
+            }
+        }
+
+        let connection_radius = space.search_radius_for(points.len());
+        let mut neighbors = vec![Vec::new(); points.len()];
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                if points[i].distance(&points[j]) <= connection_radius {
+                    neighbors[i].push(j);
+                    neighbors[j].push(i);
+                }
+            }
+        }
+
+        let mut visits = vec![Visit::Unvisited; points.len()];
+        visits[0] = Visit::Open;
+        let mut open = BinaryHeap::new();
+        open.push(QueueEntry { cost: 0.0, node: 0 });
+        // Başlangıç dışındaki düğümlerin maliyeti ağaca bağlanana kadar sonsuzdur
+        let nodes = points
+            .into_iter()
+            .enumerate()
+            .map(|(index, point)| Node::new(point, 0.0, None, if index == 0 { 0.0 } else { f32::INFINITY }))
+            .collect();
+        Ok(Fmt {
+            space,
+            nodes,
+            neighbors,
+            visits,
+            open,
+            connection_radius,
+            iterations: 0,
+            best_cost: f32::INFINITY,
+            best_path: Path::default(),
+        })
+    }
+
+    // Örneklerin ve çarpışma denetiminin yapıldığı planlayıcı (engeller, sınırlar vb. için)
+    pub fn space(&self) -> &RRTStar {
+        &self.space
+    }
+
+    // Başlangıç (0), hedef (1) ve örnek düğümleri; ağaca bağlananların ebeveyni vardır
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    // Komşuluk grafındaki bağlantı yarıçapı
+    pub fn connection_radius(&self) -> f32 {
+        self.connection_radius
+    }
+
+    // Genişletilmeyi bekleyen düğüm sayısı
+    pub fn open_count(&self) -> usize {
+        self.open.len()
+    }
+
+    // `z` genişletilirken henüz bağlanmamış `x` için komşuları arasındaki en ucuz açık ebeveyn
+    fn cheapest_open_parent(&self, x: usize) -> Option<(usize, f32)> {
+        self.neighbors[x]
+            .iter()
+            .filter(|&&y| self.visits[y] == Visit::Open)
+            .map(|&y| (y, self.nodes[y].cost + self.space.edge_cost(&self.nodes[y].point, &self.nodes[x].point)))
+            .min_by(|(a, a_cost), (b, b_cost)| a_cost.total_cmp(b_cost).then(a.cmp(b)))
+    }
+
+    // Hedefe ulaşan düğümden başlangıca yolu izler
+    fn finish(&mut self, index: usize) {
+        let mut points = Vec::new();
+        let mut current = Some(index);
+        while let Some(node) = current {
+            points.push(self.nodes[node].point);
+            current = self.nodes[node].parent;
+        }
+        points.reverse();
+        self.best_path = Path::new(points);
+        self.best_cost = self.best_path.recompute_cost_with(|a, b| self.space.edge_cost(a, b));
+    }
+}
+
+impl Planner for Fmt {
+    // Dalga cephesindeki en düşük maliyetli düğümü genişletir; bu düğüm hedef koşulunu
+    // sağlıyorsa yol en düşük maliyetlidir ve planlama biter
+    fn step(&mut self) -> StepOutcome {
+        if self.best_cost.is_finite() {
+            return StepOutcome::Converged;
+        }
+        let Some(QueueEntry { node: z, .. }) = self.open.pop() else {
+            return StepOutcome::Collision;
+        };
+        self.iterations += 1;
+        let goal = self.space.goal();
+        if self.space.within_goal(self.nodes[z].point.distance(&goal)) {
+            self.finish(z);
+            return StepOutcome::Added { index: z, improved: true };
+        }
+
+        // Bu adımda bağlananlar, adım bitene kadar ebeveyn adayı olmaz
+        let mut connected = Vec::new();
+        for i in 0..self.neighbors[z].len() {
+            let x = self.neighbors[z][i];
+            if self.visits[x] != Visit::Unvisited {
+                continue;
+            }
+            let (parent, cost) = self.cheapest_open_parent(x).expect("genişletilen düğüm her zaman açık bir komşudur");
+            if self.space.is_edge_collision_free(&self.nodes[parent].point, &self.nodes[x].point) {
+                self.nodes[x].parent = Some(parent);
+                self.nodes[x].cost = cost;
+                self.nodes[parent].children.push(x);
+                connected.push(x);
+            }
+        }
+        for &x in &connected {
+            self.visits[x] = Visit::Open;
+            self.open.push(QueueEntry { cost: self.nodes[x].cost, node: x });
+        }
+        self.visits[z] = Visit::Closed;
+        match connected.last() {
+            Some(&index) => StepOutcome::Added { index, improved: false },
+            None => StepOutcome::Collision,
+        }
+    }
+
+    // Hedefe ulaşıldığında ya da dalga cephesi tükendiğinde (hedef bağlanamıyor) biter
+    fn is_finished(&self) -> bool {
+        self.best_cost.is_finite() || self.open.is_empty()
+    }
+
+    fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    fn best_cost(&self) -> f32 {
+        self.best_cost
+    }
+
+    fn best_path(&self) -> &Path {
+        &self.best_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{maps, Point};
+
+    // Labirentte aynı örnek sayısıyla FMT*'ın yolu RRT*'ınkiyle karşılaştırılabilir ya da daha ucuzdur
+    #[test]
+    fn fmt_matches_rrt_star_on_maze() {
+        let config = PlanConfig {
+            start: Point { x: 20.0, y: 20.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            obstacles: maps::maze(),
+            max_iterations: 3000,
+            ..PlanConfig::default()
+        };
+        for seed in 0..3 {
+            let mut fmt = Fmt::from_config(&config, seed);
+            while !fmt.is_finished() {
+                fmt.step();
+            }
+            let mut rrt_star = RRTStar::from_config(&config, seed);
+            while !rrt_star.is_finished() {
+                rrt_star.step();
+            }
+            assert!(fmt.best_cost().is_finite() && rrt_star.best_cost().is_finite());
+            assert!(fmt.best_cost() <= rrt_star.best_cost() * 1.05, "{} vs {}", fmt.best_cost(), rrt_star.best_cost());
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::Point;
+
+// Doğru parçaları üzerinde kullanılan temel geometri yardımcıları
+
+// Noktanın [a, b] doğru parçasına olan en kısa mesafesi
+pub fn point_segment_distance(point: &Point, a: &Point, b: &Point) -> f32 {
+    let length_squared = a.distance_squared(b);
+    if length_squared == 0.0 {
+        return point.distance(a);
+    }
+    let t = ((point.x - a.x) * (b.x - a.x) + (point.y - a.y) * (b.y - a.y)) / length_squared;
+    point.distance(&a.lerp(b, t.clamp(0.0, 1.0)))
+}
+
+// [a, b] ve [c, d] doğru parçalarının kesişip kesişmediği (uç noktalarda değme dahil)
+pub fn segments_intersect(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+    // Üç noktanın yönelimi: pozitif = saat yönünün tersi, negatif = saat yönü, 0 = doğrusal
+    let orientation = |p: &Point, q: &Point, r: &Point| (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x);
+    // Doğrusal r noktasının [p, q] parçasının kutusu içinde olup olmadığı
+    let on_segment = |p: &Point, q: &Point, r: &Point| {
+        r.x >= p.x.min(q.x) && r.x <= p.x.max(q.x) && r.y >= p.y.min(q.y) && r.y <= p.y.max(q.y)
+    };
+
+    let d1 = orientation(c, d, a);
+    let d2 = orientation(c, d, b);
+    let d3 = orientation(a, b, c);
+    let d4 = orientation(a, b, d);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0)) && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0)) {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(c, d, a))
+        || (d2 == 0.0 && on_segment(c, d, b))
+        || (d3 == 0.0 && on_segment(a, b, c))
+        || (d4 == 0.0 && on_segment(a, b, d))
+}
+
+// [a, b] ve [c, d] doğru parçaları arasındaki en kısa mesafe
+pub fn segment_segment_distance(a: &Point, b: &Point, c: &Point, d: &Point) -> f32 {
+    if segments_intersect(a, b, c, d) {
+        return 0.0;
+    }
+    point_segment_distance(a, c, d)
+        .min(point_segment_distance(b, c, d))
+        .min(point_segment_distance(c, a, b))
+        .min(point_segment_distance(d, a, b))
+}
+
+// Bir yolun toplam uzunluğu (ardışık noktalar arasındaki mesafelerin toplamı)
+pub fn path_length(path: &[Point]) -> f32 {
+    path.windows(2).map(|segment| segment[0].distance(&segment[1])).sum()
+}
+
+// [a, b] ve [c, d] doğru parçalarının kesişim noktası (kesişmiyorlarsa None). Doğrusal ve
+// örtüşen parçalarda bir parçanın diğerinin üzerindeki ilk uç noktası döner
+pub fn segment_intersection(a: &Point, b: &Point, c: &Point, d: &Point) -> Option<Point> {
+    if !segments_intersect(a, b, c, d) {
+        return None;
+    }
+    let (r, s) = (Point { x: b.x - a.x, y: b.y - a.y }, Point { x: d.x - c.x, y: d.y - c.y });
+    let denominator = r.x * s.y - r.y * s.x;
+    if denominator == 0.0 {
+        let on_segment = |p: &Point, q: &Point, x: &Point| point_segment_distance(x, p, q) == 0.0;
+        return [*a, *b, *c, *d]
+            .into_iter()
+            .enumerate()
+            .find(|(i, x)| if *i < 2 { on_segment(c, d, x) } else { on_segment(a, b, x) })
+            .map(|(_, x)| x);
+    }
+    let t = ((c.x - a.x) * s.y - (c.y - a.y) * s.x) / denominator;
+    Some(a.lerp(b, t.clamp(0.0, 1.0)))
+}
+
+// Yolun kendisiyle kesişip kesişmediği: ardışık olmayan her parça çifti denetlenir; ardışık
+// parçalar yalnızca yol kendi üzerine geri dönüyorsa (180 derecelik dönüş) kesişmiş sayılır
+pub fn path_self_intersects(path: &[Point]) -> bool {
+    first_self_intersection(path).is_some()
+}
+
+// Yoldaki kesişen döngüleri keser: kesişen ilk parça çifti (i, j) için aradaki noktalar atılır
+// ve yol kesişim noktasından devam eder. Yeni parçalar eski parçaların alt parçaları olduğundan
+// engelsiz bir yol engelsiz kalır ve uzunluk artmaz
+pub fn remove_loops(path: &[Point]) -> Vec<Point> {
+    let mut points = path.to_vec();
+    while let Some((i, j, crossing)) = first_self_intersection(&points) {
+        let mut snipped = points[..=i].to_vec();
+        if crossing != points[i] && crossing != points[j + 1] {
+            snipped.push(crossing);
+        }
+        snipped.extend_from_slice(&points[j + 1..]);
+        points = snipped;
+    }
+    points
+}
+
+// Yolda kesişen ilk parça çifti (i < j, parçalar i → i+1 ve j → j+1) ve kesişim noktası
+fn first_self_intersection(path: &[Point]) -> Option<(usize, usize, Point)> {
+    for i in 0..path.len().saturating_sub(1) {
+        let (a, b) = (&path[i], &path[i + 1]);
+        // Ardışık parça: ortak uç dışında ancak geri dönüşte örtüşür
+        if let Some(c) = path.get(i + 2) {
+            let (r, s) = (Point { x: b.x - a.x, y: b.y - a.y }, Point { x: c.x - b.x, y: c.y - b.y });
+            if r.x * s.y - r.y * s.x == 0.0 && r.x * s.x + r.y * s.y < 0.0 {
+                // Geri dönülen parçanın kısa olanı kadar örtüşme vardır; dönüş noktası kesilir
+                let back = if a.distance(b) <= b.distance(c) { *a } else { *c };
+                return Some((i, i + 1, back));
+            }
+        }
+        for j in i + 2..path.len() - 1 {
+            // İlk ve son parça yalnızca kapalı bir yolda ortak uçta değer
+            if i == 0 && j == path.len() - 2 && path[0] == path[j + 1] {
+                continue;
+            }
+            // Arada sıfır uzunluklu bir parça (yinelenen nokta) varsa parçalar yalnızca o noktada değer
+            if j == i + 2 && path[i + 1] == path[i + 2] {
+                continue;
+            }
+            if let Some(crossing) = segment_intersection(a, b, &path[j], &path[j + 1]) {
+                return Some((i, j, crossing));
+            }
+        }
+    }
+    None
+}
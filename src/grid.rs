@@ -0,0 +1,332 @@
+use std::collections::VecDeque;
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{GridError, Point, RrtError};
+
+// Görüntülerden ya da SLAM çıktısından gelen haritalar için doluluk ızgarası. Hücreler satır
+// satır saklanır; (sütun, satır) hücresi x ekseninde `origin.x + sütun * resolution`,
+// y ekseninde `origin.y + satır * resolution` köşesinden başlayan kareyi kaplar (satırlar y ile
+// birlikte artar, ekran koordinatlarıyla aynı). Izgaranın dışındaki noktalar boş sayılır
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OccupancyGrid {
+    cells: Vec<bool>,  // Hücrelerin dolu olup olmadığı (satır satır)
+    width: usize,      // Sütun sayısı
+    height: usize,     // Satır sayısı
+    resolution: f32,   // Bir hücrenin dünya birimi cinsinden kenar uzunluğu
+    origin: Point,     // (0, 0) hücresinin dünya koordinatlarındaki köşesi
+}
+
+impl OccupancyGrid {
+    // Satır satır verilen doluluk dizisinden bir ızgara oluşturur
+    pub fn from_cells(cells: Vec<bool>, width: usize, height: usize, resolution: f32, origin: Point) -> Result<Self, GridError> {
+        if resolution.is_nan() || resolution <= 0.0 || resolution.is_infinite() {
+            return Err(GridError::NonPositiveResolution(resolution));
+        }
+        if cells.len() != width * height {
+            return Err(GridError::SizeMismatch { expected: width * height, actual: cells.len() });
+        }
+        Ok(OccupancyGrid { cells, width, height, resolution, origin })
+    }
+
+    // PGM (P2 metin ya da P5 ikili) dosyasından bir ızgara yükler. Yaygın harita biçimindeki
+    // gibi en büyük değerin yarısından koyu pikseller dolu sayılır; görüntünün ilk satırı
+    // ızgaranın 0. satırıdır
+    pub fn from_pgm(path: &str, resolution: f32, origin: Point) -> Result<Self, RrtError> {
+        let bytes = fs::read(path)?;
+        let (width, height, max_value, pixels) = parse_pgm(&bytes)?;
+        let cells = pixels.into_iter().map(|value| value * 2 < max_value).collect();
+        Ok(Self::from_cells(cells, width, height, resolution, origin)?)
+    }
+
+    // Sütun sayısı
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    // Satır sayısı
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Bir hücrenin dünya birimi cinsinden kenar uzunluğu
+    pub fn resolution(&self) -> f32 {
+        self.resolution
+    }
+
+    // (0, 0) hücresinin dünya koordinatlarındaki köşesi
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    // Hücre dolu mu; ızgaranın dışındaki hücreler boştur
+    pub fn is_occupied(&self, column: i64, row: i64) -> bool {
+        if column < 0 || row < 0 || column >= self.width as i64 || row >= self.height as i64 {
+            return false;
+        }
+        self.cells[row as usize * self.width + column as usize]
+    }
+
+    // Noktanın bulunduğu hücre (ızgaranın dışında da olabilir)
+    pub fn cell_of(&self, point: &Point) -> (i64, i64) {
+        let (x, y) = self.to_grid(point);
+        (x.floor() as i64, y.floor() as i64)
+    }
+
+    // Hücrenin dünya koordinatlarındaki sol üst köşesi
+    pub fn cell_corner(&self, column: i64, row: i64) -> Point {
+        Point {
+            x: self.origin.x + column as f32 * self.resolution,
+            y: self.origin.y + row as f32 * self.resolution,
+        }
+    }
+
+    // Noktanın dolu bir hücrede olup olmadığı
+    pub fn contains(&self, point: &Point) -> bool {
+        let (column, row) = self.cell_of(point);
+        self.is_occupied(column, row)
+    }
+
+    // [a, b] doğru parçasının geçtiği hücreler arasında dolu olan var mı. Parça örneklenmek
+    // yerine hücre hücre izlendiğinden tek hücrelik duvarlar da atlanmaz
+    pub fn segment_intersects(&self, a: &Point, b: &Point) -> bool {
+        let (ax, ay) = self.to_grid(a);
+        let (bx, by) = self.to_grid(b);
+        let (mut column, mut row) = (ax.floor() as i64, ay.floor() as i64);
+        let (end_column, end_row) = (bx.floor() as i64, by.floor() as i64);
+        let (dx, dy) = (bx - ax, by - ay);
+
+        // Bir sonraki dikey ve yatay hücre sınırına kadar parametre t cinsinden uzaklıklar
+        let (step_column, mut next_x, delta_x) = axis_traversal(ax, dx);
+        let (step_row, mut next_y, delta_y) = axis_traversal(ay, dy);
+
+        // Yuvarlama hatalarında döngünün sonlanması için geçilecek hücre sayısı sınırlanır
+        let cells = (end_column - column).unsigned_abs() + (end_row - row).unsigned_abs();
+        for _ in 0..=cells {
+            if self.is_occupied(column, row) {
+                return true;
+            }
+            if column == end_column && row == end_row {
+                break;
+            }
+            if next_x < next_y {
+                column += step_column;
+                next_x += delta_x;
+            } else {
+                row += step_row;
+                next_y += delta_y;
+            }
+        }
+        false
+    }
+
+    // Noktanın en yakın dolu hücreye olan mesafesi (içerideyse 0, dolu hücre yoksa sonsuz).
+    // Nokta çevresindeki hücre halkaları içten dışa taranır; halka, bulunan en iyi mesafeden
+    // uzaklaşınca arama biter
+    pub fn distance(&self, point: &Point) -> f32 {
+        if self.contains(point) {
+            return 0.0;
+        }
+        let (column, row) = self.cell_of(point);
+        let farthest = [column, self.width as i64 - 1 - column, row, self.height as i64 - 1 - row]
+            .into_iter()
+            .map(i64::abs)
+            .max()
+            .unwrap_or(0);
+        let mut best = f32::INFINITY;
+        for ring in 0..=farthest {
+            // Bu halkadaki her hücre noktadan en az (halka - 1) hücre uzaktadır
+            if (ring - 1) as f32 * self.resolution >= best {
+                break;
+            }
+            for (c, r) in ring_cells(column, row, ring) {
+                if self.is_occupied(c, r) {
+                    best = best.min(self.cell_distance(point, c, r));
+                }
+            }
+        }
+        best
+    }
+
+    // Dolu hücrelerin toplam alanı
+    pub fn occupied_area(&self) -> f32 {
+        self.cells.iter().filter(|&&cell| cell).count() as f32 * self.resolution * self.resolution
+    }
+
+    // İki nokta ızgara üzerinde boş hücrelerden (4-komşuluk) birbirine bağlı mı. Bağlı değilse
+    // aradaki yol geometrik olarak da imkansızdır (yalnızca köşeden değen dolu hücrelerin
+    // arasından da geçilemez); bağlı olması ise planlayıcının yolu iterasyon sınırı içinde
+    // bulacağını garanti etmez. Noktalardan biri ızgaranın dışındaysa ızgaranın etrafından
+    // dolaşılıp dolaşılamayacağı bilinemeyeceğinden 'true' döner
+    pub fn is_connected(&self, a: &Point, b: &Point) -> bool {
+        let inside = |(column, row): (i64, i64)| {
+            column >= 0 && row >= 0 && column < self.width as i64 && row < self.height as i64
+        };
+        let (start, goal) = (self.cell_of(a), self.cell_of(b));
+        if !inside(start) || !inside(goal) {
+            return true;
+        }
+        if self.is_occupied(start.0, start.1) || self.is_occupied(goal.0, goal.1) {
+            return false;
+        }
+
+        let index = |(column, row): (i64, i64)| row as usize * self.width + column as usize;
+        let mut visited = vec![false; self.cells.len()];
+        let mut queue = VecDeque::from([start]);
+        visited[index(start)] = true;
+        while let Some((column, row)) = queue.pop_front() {
+            if (column, row) == goal {
+                return true;
+            }
+            for next in [(column + 1, row), (column - 1, row), (column, row + 1), (column, row - 1)] {
+                if inside(next) && !visited[index(next)] && !self.is_occupied(next.0, next.1) {
+                    visited[index(next)] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+
+    // Dünya koordinatlarını hücre birimine çevirir
+    fn to_grid(&self, point: &Point) -> (f32, f32) {
+        ((point.x - self.origin.x) / self.resolution, (point.y - self.origin.y) / self.resolution)
+    }
+
+    // Noktanın verilen hücrenin karesine olan mesafesi
+    fn cell_distance(&self, point: &Point, column: i64, row: i64) -> f32 {
+        let min = self.cell_corner(column, row);
+        let dx = (min.x - point.x).max(point.x - (min.x + self.resolution)).max(0.0);
+        let dy = (min.y - point.y).max(point.y - (min.y + self.resolution)).max(0.0);
+        dx.hypot(dy)
+    }
+}
+
+// Tek bir eksende hücre izleme adımı: (adım yönü, ilk sınıra kadar t, bir hücre için t)
+fn axis_traversal(start: f32, delta: f32) -> (i64, f32, f32) {
+    if delta > 0.0 {
+        (1, (start.floor() + 1.0 - start) / delta, 1.0 / delta)
+    } else if delta < 0.0 {
+        (-1, (start - start.floor()) / -delta, -1.0 / delta)
+    } else {
+        (0, f32::INFINITY, f32::INFINITY)
+    }
+}
+
+// (column, row) merkezli, Chebyshev yarıçapı `ring` olan karenin çevresindeki hücreler
+fn ring_cells(column: i64, row: i64, ring: i64) -> impl Iterator<Item = (i64, i64)> {
+    let span = -ring..=ring;
+    span.flat_map(move |offset| {
+        let edges = [
+            (column + offset, row - ring),
+            (column + offset, row + ring),
+            (column - ring, row + offset),
+            (column + ring, row + offset),
+        ];
+        // Köşeler ve ring = 0 iki kez üretilebilir; mesafe hesabı için zararsızdır
+        edges.into_iter()
+    })
+}
+
+// PGM başlığındaki bir sonraki alan; alanlar boşluklarla ayrılır, '#' ile başlayan satırlar yorumdur
+fn next_token<'a>(bytes: &'a [u8], position: &mut usize) -> Option<&'a [u8]> {
+    loop {
+        while bytes.get(*position).is_some_and(u8::is_ascii_whitespace) {
+            *position += 1;
+        }
+        if bytes.get(*position) != Some(&b'#') {
+            break;
+        }
+        while bytes.get(*position).is_some_and(|&byte| byte != b'\n') {
+            *position += 1;
+        }
+    }
+    let start = *position;
+    while bytes.get(*position).is_some_and(|byte| !byte.is_ascii_whitespace()) {
+        *position += 1;
+    }
+    (start < *position).then(|| &bytes[start..*position])
+}
+
+// PGM başlığındaki ya da P2 pikselleri arasındaki bir sonraki sayı
+fn next_number(bytes: &[u8], position: &mut usize, field: &str) -> Result<u32, RrtError> {
+    next_token(bytes, position)
+        .and_then(|token| std::str::from_utf8(token).ok()?.parse().ok())
+        .ok_or_else(|| RrtError::Parse(format!("invalid PGM image: missing or invalid {field}")))
+}
+
+// PGM başlığını ve piksellerini ayrıştırır: (genişlik, yükseklik, en büyük değer, pikseller)
+fn parse_pgm(bytes: &[u8]) -> Result<(usize, usize, u32, Vec<u32>), RrtError> {
+    let invalid = |reason: &str| RrtError::Parse(format!("invalid PGM image: {reason}"));
+    let mut position = 0;
+    let magic = next_token(bytes, &mut position).ok_or_else(|| invalid("empty file"))?;
+    if magic != b"P2" && magic != b"P5" {
+        return Err(invalid("expected a P2 or P5 header"));
+    }
+    let width = next_number(bytes, &mut position, "width")? as usize;
+    let height = next_number(bytes, &mut position, "height")? as usize;
+    let max_value = next_number(bytes, &mut position, "maximum value")?;
+    if max_value == 0 || max_value > u32::from(u16::MAX) {
+        return Err(invalid("maximum value must be in 1..=65535"));
+    }
+    let count = width * height;
+
+    let pixels = if magic == b"P2" {
+        (0..count).map(|_| next_number(bytes, &mut position, "pixel")).collect::<Result<Vec<_>, _>>()?
+    } else {
+        // Başlıktan sonra tek bir boşluk karakteri gelir, ardından ham pikseller (16 bitte büyük sonlu)
+        let data = bytes.get(position + 1..).unwrap_or_default();
+        let sample_size = if max_value < 256 { 1 } else { 2 };
+        if data.len() < count * sample_size {
+            return Err(invalid("pixel data is shorter than width * height"));
+        }
+        data.chunks_exact(sample_size)
+            .take(count)
+            .map(|sample| sample.iter().fold(0, |value, &byte| (value << 8) | u32::from(byte)))
+            .collect()
+    };
+    Ok((width, height, max_value, pixels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlanConfig, PlanError, RRTStar};
+
+    // 400x400 birimlik alanı kaplayan 40x40 hücrelik ızgara; verilen hücreler doludur
+    fn grid_with(occupied: impl Fn(i64, i64) -> bool) -> OccupancyGrid {
+        let cells = (0..40).flat_map(|row| (0..40).map(move |column| (column, row))).map(|(column, row)| occupied(column, row)).collect();
+        OccupancyGrid::from_cells(cells, 40, 40, 10.0, Point { x: 0.0, y: 0.0 }).expect("geçerli ızgara")
+    }
+
+    fn config(grid: OccupancyGrid) -> PlanConfig {
+        PlanConfig {
+            start: Point { x: 25.0, y: 25.0 },
+            goal: Point { x: 355.0, y: 355.0 },
+            occupancy_grid: Some(grid),
+            max_iterations: 200,
+            ..PlanConfig::default()
+        }
+    }
+
+    #[test]
+    fn enclosed_goal_is_unreachable() {
+        // Hedef hücresinin (35, 35) çevresi tamamen dolu bir halkayla kapatılır
+        let grid = grid_with(|column, row| (column - 35).abs().max((row - 35).abs()) == 2);
+        assert!(!grid.is_connected(&Point { x: 25.0, y: 25.0 }, &Point { x: 355.0, y: 355.0 }));
+        let mut rrt_star = RRTStar::from_config(&config(grid), 0);
+        assert!(matches!(rrt_star.plan(), Err(PlanError::GoalUnreachable)));
+        assert_eq!(rrt_star.iterations(), 0);
+    }
+
+    #[test]
+    fn goal_behind_one_cell_gap_is_reachable() {
+        // Alanı ikiye bölen duvarda tek hücrelik bir boşluk bırakılır
+        let grid = grid_with(|column, row| row == 20 && column != 7);
+        assert!(grid.is_connected(&Point { x: 25.0, y: 25.0 }, &Point { x: 355.0, y: 355.0 }));
+        let mut rrt_star = RRTStar::from_config(&config(grid), 0);
+        assert!(!matches!(rrt_star.plan(), Err(PlanError::GoalUnreachable)));
+        assert!(rrt_star.iterations() > 0);
+    }
+}
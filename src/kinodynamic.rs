@@ -0,0 +1,182 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::steering::chord_heading;
+use crate::{Point, RRTStar, StepOutcome, Steering};
+
+// Sınırlı ivmeli çift integratör: konumu ve hızı olan, her kenarda `time_step` süre boyunca
+// sabit ivmeyle hareket eden bir nokta kütle. Kenar maliyeti geçen süredir
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DoubleIntegrator {
+    pub max_acceleration: f32, // İvmenin en büyük büyüklüğü
+    pub max_speed: f32,        // Hızın en büyük büyüklüğü
+    pub time_step: f32,        // Her kenarın süresi
+    pub velocity_weight: f32,  // En yakın durum aramasında hız farkının konum farkına göre ağırlığı
+}
+
+// Vektörü verilen büyüklüğe kırpar
+fn clamp_length(vector: Point, max_length: f32) -> Point {
+    let length = vector.x.hypot(vector.y);
+    if length <= max_length {
+        return vector;
+    }
+    let scale = max_length / length;
+    Point { x: vector.x * scale, y: vector.y * scale }
+}
+
+impl DoubleIntegrator {
+    // Verilen durumdan hedef noktaya doğru bir zaman adımı ileri benzetim yapar ve varılan
+    // konumla hızı döner. Hedefe tam bir adımda varan sabit ivme en büyük ivmeye kırpılır;
+    // varış hızı en büyük hızı aşıyorsa ivme hız sınırına göre küçültülür. Hız büyüklüğü
+    // sabit ivme altında dışbükey olduğundan kenar boyunca da hız sınırı aşılmaz
+    pub fn simulate(&self, position: &Point, velocity: &Point, target: &Point) -> (Point, Point) {
+        let dt = self.time_step;
+        let desired = Point {
+            x: 2.0 * (target.x - position.x - velocity.x * dt) / (dt * dt),
+            y: 2.0 * (target.y - position.y - velocity.y * dt) / (dt * dt),
+        };
+        let acceleration = clamp_length(desired, self.max_acceleration);
+        let end_velocity = clamp_length(
+            Point { x: velocity.x + acceleration.x * dt, y: velocity.y + acceleration.y * dt },
+            self.max_speed,
+        );
+        (self.position_at(position, velocity, &end_velocity, dt), end_velocity)
+    }
+
+    // Başlangıç ve bitiş hızları verilen bir kenarda `t` süre sonraki konum
+    pub fn position_at(&self, position: &Point, velocity: &Point, end_velocity: &Point, t: f32) -> Point {
+        let acceleration = self.acceleration(velocity, end_velocity);
+        Point {
+            x: position.x + velocity.x * t + 0.5 * acceleration.x * t * t,
+            y: position.y + velocity.y * t + 0.5 * acceleration.y * t * t,
+        }
+    }
+
+    // Başlangıç ve bitiş hızları verilen bir kenardaki sabit ivme
+    pub fn acceleration(&self, velocity: &Point, end_velocity: &Point) -> Point {
+        Point { x: (end_velocity.x - velocity.x) / self.time_step, y: (end_velocity.y - velocity.y) / self.time_step }
+    }
+
+    // Ağırlıklı durum uzaklığı: konum farkı artı ağırlıklı hız farkı
+    pub fn state_distance(&self, position: &Point, velocity: &Point, other_position: &Point, other_velocity: &Point) -> f32 {
+        position.distance(other_position) + self.velocity_weight * velocity.distance(other_velocity)
+    }
+}
+
+impl RRTStar {
+    // Çift integratör yönlendirmesi kullanılıyorsa hareket modeli
+    pub fn double_integrator(&self) -> Option<DoubleIntegrator> {
+        match self.steering {
+            Steering::DoubleIntegrator(model) => Some(model),
+            _ => None,
+        }
+    }
+
+    // Kinodinamik bir iterasyon (düz kinodinamik RRT): rastgele bir konum ve hız örneklenir,
+    // ağırlıklı durum uzaklığına göre en yakın düğümden bir zaman adımı ileri benzetim yapılır
+    // ve varılan durum bu düğüme bağlanır. İki durumu tam olarak birleştiren bir yönlendirme
+    // olmadığından ebeveyn seçimi, yeniden bağlama ve hedefe tam bağlanma yapılmaz; hedef
+    // eşiğine giren düğümler hedef kümesine eklenir. Sınır dışına benzetilen durumlar, konum
+    // kırpılırsa hareket tutarsızlaşacağından politikadan bağımsız olarak reddedilir
+    pub(crate) fn step_kinodynamic(&mut self, model: DoubleIntegrator) -> StepOutcome {
+        let target = self.sample();
+        let target_velocity = self.sample_velocity(model.max_speed);
+        let Some(nearest_index) = self.find_nearest_state(&model, &target, &target_velocity) else {
+            return StepOutcome::Collision;
+        };
+        let nearest = &self.nodes[nearest_index];
+        let (position, velocity) = model.simulate(&nearest.point, &nearest.velocity, &target);
+
+        if !position.is_finite() {
+            return StepOutcome::OutOfBounds;
+        }
+        if !self.bounds.contains(&position) {
+            return StepOutcome::OutOfBounds;
+        }
+        let separation = nearest.point.distance(&position);
+        if separation == 0.0 || (!self.is_goal(&position) && separation < self.min_node_separation) {
+            return StepOutcome::TooClose;
+        }
+        if !self.trajectory_free(&model, &nearest.point, &nearest.velocity, &velocity) {
+            self.record_rejection(position);
+            return StepOutcome::Collision;
+        }
+        if !self.turn_allowed(nearest_index, &position) {
+            return StepOutcome::TurnTooSharp;
+        }
+
+        let previous_cost = self.best_cost;
+        let heading = chord_heading(&Point::default(), &velocity, self.nodes[nearest_index].heading);
+        let index = self.add_node_with_heading(position, heading, nearest_index);
+        self.nodes[index].velocity = velocity;
+        self.update_best_path();
+        StepOutcome::Added { index, improved: self.best_cost < previous_cost }
+    }
+
+    // En büyük hız yarıçaplı diskten tekdüze rastgele bir hız
+    fn sample_velocity(&mut self, max_speed: f32) -> Point {
+        let radius = max_speed * self.rng.gen::<f32>().sqrt();
+        let angle = std::f32::consts::TAU * self.rng.gen::<f32>();
+        Point { x: radius * angle.cos(), y: radius * angle.sin() }
+    }
+
+    // Ağırlıklı durum uzaklığına göre en yakın (engelli olmayan) düğüm; eşitlikte küçük indeks
+    fn find_nearest_state(&self, model: &DoubleIntegrator, position: &Point, velocity: &Point) -> Option<usize> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !node.blocked)
+            .map(|(index, node)| (index, model.state_distance(&node.point, &node.velocity, position, velocity)))
+            .min_by(|&(i, a), &(j, b)| a.total_cmp(&b).then(i.cmp(&j)))
+            .map(|(index, _)| index)
+    }
+
+    // Verilen durumdan başlayıp verilen hızla biten kenarın yörüngesi engelsiz mi
+    fn trajectory_free(&self, model: &DoubleIntegrator, position: &Point, velocity: &Point, end_velocity: &Point) -> bool {
+        self.trajectory(model, position, velocity, end_velocity)
+            .windows(2)
+            .all(|piece| self.is_edge_collision_free(&piece[0], &piece[1]))
+    }
+
+    // Kenarın yörüngesini kenar denetimi aralıklarıyla yaklaşan noktalar dizisi (uçlar dahil)
+    pub(crate) fn trajectory(&self, model: &DoubleIntegrator, position: &Point, velocity: &Point, end_velocity: &Point) -> Vec<Point> {
+        // Hız büyüklüğü kenar boyunca uçlardakinden büyük olamaz; yol uzunluğu buna göre sınırlanır
+        let speed = velocity.x.hypot(velocity.y).max(end_velocity.x.hypot(end_velocity.y));
+        let pieces = (speed * model.time_step / self.edge_check_spacing()).ceil().max(1.0) as usize;
+        (0..=pieces)
+            .map(|i| model.position_at(position, velocity, end_velocity, model.time_step * i as f32 / pieces as f32))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{maps, PlanConfig};
+
+    // Tohumlanmış bir kinodinamik planlamada hiçbir kenar ivme ya da hız sınırını aşmaz
+    #[test]
+    fn edges_respect_motion_limits() {
+        let model = DoubleIntegrator { max_acceleration: 30.0, max_speed: 40.0, time_step: 0.5, velocity_weight: 0.5 };
+        let config = PlanConfig {
+            start: Point { x: 20.0, y: 20.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            obstacles: maps::standard(),
+            max_iterations: 2000,
+            steering: Steering::DoubleIntegrator(model),
+            ..PlanConfig::default()
+        };
+        let mut rrt_star = RRTStar::from_config(&config, 9);
+        rrt_star.plan().ok();
+        assert!(rrt_star.nodes.len() > 100);
+        assert!(rrt_star.verify_tree().is_ok());
+
+        let slack = 1.0 + 1e-4;
+        for node in &rrt_star.nodes[1..] {
+            let parent = &rrt_star.nodes[node.parent.expect("kök dışındaki düğümlerin ebeveyni olmalı")];
+            let acceleration = model.acceleration(&parent.velocity, &node.velocity);
+            assert!(acceleration.x.hypot(acceleration.y) <= model.max_acceleration * slack, "{acceleration:?}");
+            assert!(node.velocity.x.hypot(node.velocity.y) <= model.max_speed * slack, "{:?}", node.velocity);
+        }
+    }
+}
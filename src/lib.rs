@@ -0,0 +1,3518 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+mod anytime;
+mod batch;
+mod bi_rrt_star;
+mod bit_star;
+mod collision;
+mod dot;
+mod dubins;
+mod error;
+mod fmt_star;
+pub mod geometry;
+mod grid;
+mod kinodynamic;
+pub mod maps;
+mod obstacle;
+mod path;
+mod planner;
+mod potential;
+mod reeds_shepp;
+mod roadmap;
+mod rrt_connect;
+mod sampling;
+mod state;
+mod steering;
+mod verify;
+mod waypoints;
+
+pub use anytime::Solution;
+pub use batch::plan_batch;
+pub use bi_rrt_star::BiRrtStar;
+pub use bit_star::Bit;
+pub use collision::CollisionChecker;
+pub use dubins::{DubinsPath, DubinsWord};
+pub use error::{ConfigError, GridError, PlanError, RrtError, WaypointError};
+pub use fmt_star::Fmt;
+pub use geometry::{path_length, path_self_intersects, remove_loops};
+pub use grid::OccupancyGrid;
+pub use kinodynamic::DoubleIntegrator;
+pub use obstacle::Obstacle;
+pub use path::Path;
+pub use planner::Planner;
+pub use potential::PotentialFieldSampler;
+pub use reeds_shepp::ReedsSheppPath;
+pub use roadmap::Roadmap;
+pub use rrt_connect::RrtConnect;
+pub use sampling::{HaltonSequence, InformedEllipse, Sampler};
+pub use steering::Steering;
+pub use verify::{TreeInvariantError, COST_EPSILON};
+pub use waypoints::{plan_through, WaypointPlan};
+
+// Kenar çarpışma kontrolünde (`collision_resolution` verilmemişse) ve kenar maliyeti
+// hesabında ara örnekler arasındaki mesafe (adım boyutunun bir oranı)
+const EDGE_CHECK_FRACTION: f32 = 0.25;
+
+// Durum uzayının boyutu (şimdilik düzlem)
+const DIMENSION: f32 = 2.0;
+
+// Uyarlanabilir yarıçapın üst sınırı (adım boyutunun katı olarak)
+const ADAPTIVE_RADIUS_STEP_FACTOR: f32 = 2.0;
+
+// Görselleştirme için saklanan, çarpışma nedeniyle reddedilmiş en son nokta sayısı
+const RECENT_REJECTIONS_CAPACITY: usize = 32;
+
+// Serbest alan oranı tahmininde kullanılan sabit tohum; tahmin planlayıcının rastgele
+// sayı üretecini ilerletmez ve aynı haritada her zaman aynı sonucu verir
+const FREE_RATIO_SEED: u64 = 0;
+
+// Yeniden bağlama komşuluk yarıçapının nasıl belirleneceği
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum RadiusMode {
+    // Sabit yarıçap
+    FixedRadius(f32),
+    // RRT* optimallik koşulundan gelen küçülen yarıçap:
+    // r = min(gamma * (ln n / n)^(1/d), ADAPTIVE_RADIUS_STEP_FACTOR * step_size)
+    Adaptive { gamma: f32 },
+}
+
+impl RadiusMode {
+    // Serbest alanın ölçüsünden (sınır alanı eksi engel alanı ya da yalnızca sınır alanı)
+    // asimptotik optimallik için gereken alt sınırın biraz üstünde bir gamma seçer.
+    // gamma* = 2 * (1 + 1/d)^(1/d) * (alan / birim top hacmi)^(1/d)
+    pub fn adaptive(free_area: f32) -> RadiusMode {
+        let unit_ball = std::f32::consts::PI;
+        let gamma_star = 2.0
+            * (1.0 + 1.0 / DIMENSION).powf(1.0 / DIMENSION)
+            * (free_area / unit_ball).powf(1.0 / DIMENSION);
+        RadiusMode::Adaptive { gamma: gamma_star * 1.1 }
+    }
+}
+
+// Bir planlama probleminin ve ayarlarının tamamı; aynı yapılandırma farklı tohumlarla
+// tekrar tekrar çalıştırılabilir (bkz. `plan_batch`)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlanConfig {
+    pub start: Point,             // Başlangıç noktası
+    pub start_heading: f32,       // Başlangıçtaki yön (radyan; yalnızca eğrisel yönlendirmelerde önemlidir)
+    pub goal: Point,              // Hedef nokta
+    pub goal_heading: Option<f32>, // Hedefte istenen yön (radyan; eğrisel yönlendirmelerde, None = serbest)
+    pub step_size: f32,           // Adım boyutu
+    pub step_mode: StepMode,      // Adım uzunluğunun seçimi (sabit ya da engel mesafesine göre)
+    pub goal_threshold: f32,      // Hedef kabul bölgesinin yarıçapı (0 = yalnızca tam hedef)
+    pub radius_mode: RadiusMode,  // Yakınlık yarıçapı seçimi
+    pub bounds: Bounds,           // Örnekleme bölgesi
+    pub obstacles: Vec<Obstacle>, // Haritadaki engeller
+    pub occupancy_grid: Option<OccupancyGrid>, // Geometrik engellere ek olarak doluluk ızgarası
+    pub max_iterations: usize,    // İterasyon sınırı
+    pub min_node_separation: f32, // En yakın düğüme bundan yakın yeni düğüm eklenmez (0 = kapalı)
+    pub collision_resolution: f32, // Kenar çarpışma kontrolündeki örnek aralığı (0 = adım boyutunun dörtte biri)
+    pub out_of_bounds: OutOfBoundsPolicy, // Sınır dışına yönlendirilen noktalara ne yapılacağı
+    pub nearest_tie_break: NearestTieBreak, // Eşit uzaklıktaki en yakın düğümler arasında seçim
+    pub stall_limit: usize,       // Art arda bu kadar iterasyonda düğüm eklenemezse planlama durur (0 = kapalı)
+    pub max_nodes: usize,         // Ağaçtaki en fazla düğüm sayısı (0 = sınırsız)
+    pub node_limit_policy: NodeLimitPolicy, // Düğüm sınırına ulaşıldığında ne yapılacağı
+    pub lazy_collision: bool,     // Kenarlar yalnızca en iyi yol adayına düştüğünde denetlenir (bkz. `set_lazy_collision`)
+    pub termination: Option<Termination>, // En iyi maliyet yeterince iyiyse erken durma koşulu
+    pub cost_regions: Vec<(Obstacle, f32)>, // Geçilebilir ama pahalı bölgeler ve maliyet çarpanları
+    pub rewire_improvement_threshold: f32, // Yeniden bağlama için gereken en küçük maliyet kazancı (0 = her iyileşme)
+    pub goal_bias: GoalBias,      // Örneğin doğrudan hedef olarak seçilme olasılığı
+    pub sampler: Sampler,         // Hedef dışındaki örneklerin nasıl üretileceği
+    pub sampler_blend: Vec<(Sampler, f32)>, // Her iterasyonda ağırlıklarına göre seçilen örnekleyiciler (boş = yalnızca `sampler`)
+    pub try_goal_every: usize,    // Çözüm bulunana kadar her k. iterasyonda hedefe doğrudan uzanılır (0 = kapalı)
+    pub max_turn_angle: Option<f32>, // Art arda iki kenar arasındaki en büyük dönüş açısı (radyan, None = sınırsız)
+    pub optimize: bool,           // false: yeniden bağlamasız düz RRT (yeni düğüm en yakın düğüme bağlanır)
+    pub any_angle: bool,          // Ebeveyn seçiminde seçilen ebeveynin görülebilen atalarına da bağlanılabilir (bkz. `set_any_angle`)
+    pub steering: Steering,       // Kenarların oluşturulma biçimi (doğrusal, Dubins ya da Reeds-Shepp)
+}
+
+// Deneyler için temel yapılandırma: görselleştirmedeki parametrelerle (adım 10, hedef eşiği
+// 10, sabit yakınlık yarıçapı 15, 400x400 bölge, 5000 iterasyon) engelsiz doğrusal RRT*; ek
+// özelliklerin hepsi kapalıdır. Başlangıç ve hedef orijindedir, her deneyde verilmelidir:
+// `PlanConfig { start, goal, ..PlanConfig::default() }`
+impl Default for PlanConfig {
+    fn default() -> Self {
+        PlanConfig {
+            start: Point::default(),
+            start_heading: 0.0,
+            goal: Point::default(),
+            goal_heading: None,
+            step_size: 10.0,
+            step_mode: StepMode::default(),
+            goal_threshold: 10.0,
+            radius_mode: RadiusMode::FixedRadius(15.0),
+            bounds: Bounds::default(),
+            obstacles: Vec::new(),
+            occupancy_grid: None,
+            max_iterations: 5000,
+            min_node_separation: 0.0,
+            collision_resolution: 0.0,
+            out_of_bounds: OutOfBoundsPolicy::default(),
+            nearest_tie_break: NearestTieBreak::default(),
+            stall_limit: 0,
+            max_nodes: 0,
+            node_limit_policy: NodeLimitPolicy::default(),
+            lazy_collision: false,
+            termination: None,
+            cost_regions: Vec::new(),
+            rewire_improvement_threshold: 0.0,
+            goal_bias: GoalBias::default(),
+            sampler: Sampler::default(),
+            sampler_blend: Vec::new(),
+            try_goal_every: 0,
+            max_turn_angle: None,
+            optimize: true,
+            any_angle: false,
+            steering: Steering::default(),
+        }
+    }
+}
+
+// Örneğin doğrudan hedef noktası olarak seçilme olasılığı. Yüksek olasılık ilk çözümü
+// hızlandırır, düşük olasılık ise sonrasında iyileştirme için keşfi artırır; azalan
+// biçimler iterasyon sayısıyla `start` değerinden `floor` değerine iner
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GoalBias {
+    // Sabit olasılık
+    Fixed(f32),
+    // `iterations` iterasyonda doğrusal olarak tabana iner
+    LinearDecay { start: f32, floor: f32, iterations: usize },
+    // Her iterasyonda tabana olan fark exp(-rate) oranında küçülür
+    ExponentialDecay { start: f32, floor: f32, rate: f32 },
+}
+
+impl GoalBias {
+    // Verilen iterasyondaki hedef örnekleme olasılığı ([0, 1] aralığına kırpılır)
+    pub fn probability(&self, iteration: usize) -> f32 {
+        let probability = match *self {
+            GoalBias::Fixed(probability) => probability,
+            GoalBias::LinearDecay { start, floor, iterations } => {
+                let remaining = 1.0 - iteration as f32 / iterations.max(1) as f32;
+                floor + (start - floor) * remaining.max(0.0)
+            }
+            GoalBias::ExponentialDecay { start, floor, rate } => floor + (start - floor) * (-rate * iteration as f32).exp(),
+        };
+        if probability.is_nan() {
+            return 0.0;
+        }
+        probability.clamp(0.0, 1.0)
+    }
+}
+
+// Yapılandırmadaki hedef örnekleme olasılıklarının [0, 1) aralığında olduğunu doğrular;
+// 1 olasılık hiç keşif yapılmaması demektir
+fn validate_goal_bias(goal_bias: GoalBias) -> Result<(), ConfigError> {
+    let probabilities = match goal_bias {
+        GoalBias::Fixed(probability) => [probability, probability],
+        GoalBias::LinearDecay { start, floor, .. } | GoalBias::ExponentialDecay { start, floor, .. } => [start, floor],
+    };
+    match probabilities.into_iter().find(|p| !(0.0..1.0).contains(p)) {
+        Some(probability) => Err(ConfigError::InvalidGoalBias(probability)),
+        None => Ok(()),
+    }
+}
+
+// Potansiyel alanı örnekleyicisinin (karışık örneklemedekiler dahil) kazançları ve en büyük
+// itme mesafesi negatif olmayan, etki mesafesi pozitif sonlu sayılar olmalı
+fn validate_sampler(sampler: Sampler, blend: &[(Sampler, f32)]) -> Result<(), ConfigError> {
+    for sampler in std::iter::once(sampler).chain(blend.iter().map(|&(sampler, _)| sampler)) {
+        let Sampler::PotentialField(field) = sampler else {
+            continue;
+        };
+        let parameters = [
+            ("attractive_gain", field.attractive_gain),
+            ("repulsive_gain", field.repulsive_gain),
+            ("max_nudge", field.max_nudge),
+        ];
+        for (parameter, value) in parameters {
+            if !(value >= 0.0 && value.is_finite()) {
+                return Err(ConfigError::InvalidPotentialField { parameter, value });
+            }
+        }
+        if !(field.influence > 0.0 && field.influence.is_finite()) {
+            return Err(ConfigError::InvalidPotentialField { parameter: "influence", value: field.influence });
+        }
+    }
+    Ok(())
+}
+
+// Dönüş açısı sınırı (0, π] aralığında olmalı; π her yönü, dolayısıyla sınırsızı ifade eder
+fn validate_turn_angle(max_turn_angle: Option<f32>) -> Result<(), ConfigError> {
+    match max_turn_angle {
+        Some(angle) if angle.is_nan() || angle <= 0.0 || angle > std::f32::consts::PI => {
+            Err(ConfigError::InvalidTurnAngle(angle))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Hedef yönü verilmişse sonlu olmalı
+fn validate_goal_heading(goal_heading: Option<f32>) -> Result<(), ConfigError> {
+    match goal_heading {
+        Some(heading) if !heading.is_finite() => Err(ConfigError::NonFiniteGoalHeading(heading)),
+        _ => Ok(()),
+    }
+}
+
+// Çarpışma kontrolü aralığı negatif olamaz (0 = varsayılan)
+fn validate_collision_resolution(collision_resolution: f32) -> Result<(), ConfigError> {
+    if collision_resolution.is_nan() || collision_resolution < 0.0 {
+        return Err(ConfigError::NegativeCollisionResolution(collision_resolution));
+    }
+    Ok(())
+}
+
+// Örnekleme bölgesi her iki eksende boş olmamalı; tekdüze örnekleme yarı açık aralıklardan çeker
+fn validate_bounds(bounds: Bounds) -> Result<(), ConfigError> {
+    let axis_valid = |min: f32, max: f32| min.is_finite() && max.is_finite() && min < max;
+    if !axis_valid(bounds.min_x, bounds.max_x) || !axis_valid(bounds.min_y, bounds.max_y) {
+        return Err(ConfigError::EmptyBounds(bounds));
+    }
+    Ok(())
+}
+
+// Engel mesafesine göre adımda en kısa adım pozitif ve adım boyutundan büyük olmamalı; ölçek
+// pozitif ve sonlu olmalı
+fn validate_step_mode(step_mode: StepMode, step_size: f32) -> Result<(), ConfigError> {
+    match step_mode {
+        StepMode::ClearanceScaled { min_step, .. } if min_step.is_nan() || min_step <= 0.0 || min_step > step_size => {
+            Err(ConfigError::InvalidMinStep { min_step, step_size })
+        }
+        StepMode::ClearanceScaled { scale, .. } if !(scale > 0.0 && scale.is_finite()) => Err(ConfigError::InvalidStepScale(scale)),
+        _ => Ok(()),
+    }
+}
+
+// Dubins ve Reeds-Shepp dönüş yarıçapı pozitif ve sonlu, geri gitme cezası 1'den küçük
+// olmayan sonlu bir çarpan olmalı. Çift integratörün sınırları ve zaman adımı pozitif ve
+// sonlu, hız ağırlığı negatif olmayan ve sonlu olmalı; durumlar hedefe tam olarak
+// getirilemediğinden hedef eşiği pozitif olmalı
+fn validate_steering(steering: Steering, goal_threshold: f32) -> Result<(), ConfigError> {
+    let (turn_radius, reverse_penalty) = match steering {
+        Steering::Straight => return Ok(()),
+        Steering::DoubleIntegrator(model) => {
+            let positive = [
+                ("max_acceleration", model.max_acceleration),
+                ("max_speed", model.max_speed),
+                ("time_step", model.time_step),
+            ];
+            for (parameter, value) in positive {
+                if value.is_nan() || value <= 0.0 || value.is_infinite() {
+                    return Err(ConfigError::InvalidDynamics { parameter, value });
+                }
+            }
+            if !(model.velocity_weight >= 0.0 && model.velocity_weight.is_finite()) {
+                return Err(ConfigError::InvalidDynamics { parameter: "velocity_weight", value: model.velocity_weight });
+            }
+            if goal_threshold <= 0.0 {
+                return Err(ConfigError::KinodynamicGoalThreshold);
+            }
+            return Ok(());
+        }
+        Steering::Dubins { turn_radius } => (turn_radius, 1.0),
+        Steering::ReedsShepp { turn_radius, reverse_penalty } => (turn_radius, reverse_penalty),
+    };
+    if turn_radius.is_nan() || turn_radius <= 0.0 || turn_radius.is_infinite() {
+        return Err(ConfigError::NonPositiveTurnRadius(turn_radius));
+    }
+    if reverse_penalty.is_nan() || reverse_penalty < 1.0 || reverse_penalty.is_infinite() {
+        return Err(ConfigError::InvalidReversePenalty(reverse_penalty));
+    }
+    Ok(())
+}
+
+impl Default for GoalBias {
+    fn default() -> Self {
+        GoalBias::Fixed(0.0)
+    }
+}
+
+// En iyi maliyeti düz çizgi alt sınırıyla karşılaştıran erken durma koşulları
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Termination {
+    // best_cost <= factor * alt sınır
+    CostWithinFactor(f32),
+    // best_cost <= alt sınır + epsilon
+    CostWithinEpsilon(f32),
+}
+
+// Yönlendirmede atılan adımın uzunluğu
+#[derive(Clone, Copy, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum StepMode {
+    // Her adım `step_size` uzunluğundadır
+    #[default]
+    Fixed,
+    // Adım, çıkılan noktanın en yakın engele olan mesafesinin `scale` katıdır ve
+    // [min_step, step_size] aralığına kırpılır: açık alanda uzun, engellerin yakınında kısa
+    // adımlar atılır. `step_size` böylece en uzun adımdır; `scale` eski kayıtlarda 1'dir
+    ClearanceScaled {
+        min_step: f32,
+        #[serde(default = "unit_step_scale")]
+        scale: f32,
+    },
+}
+
+// `StepMode::ClearanceScaled` kayıtlarında ölçek verilmemişse kullanılan değer
+fn unit_step_scale() -> f32 {
+    1.0
+}
+
+// Yönlendirilen nokta örnekleme bölgesinin dışına çıktığında uygulanacak politika
+#[derive(Clone, Copy, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum OutOfBoundsPolicy {
+    // Noktayı bölgenin kenarına çek
+    #[default]
+    Clamp,
+    // Adımı reddet
+    Reject,
+}
+
+// Ağaç `max_nodes` sınırına ulaştığında uygulanacak politika
+#[derive(Clone, Copy, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum NodeLimitPolicy {
+    // Yeni düğüm eklenmez; adımlar `StepOutcome::NodeBudgetExhausted` döner
+    #[default]
+    Stop,
+    // Yer açmak için en kötü yaprak (en yüksek `cost + heuristic`) unutulur
+    Forget,
+}
+
+// Örneğe eşit uzaklıkta birden fazla düğüm olduğunda `find_nearest`in hangisini seçeceği
+#[derive(Clone, Copy, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum NearestTieBreak {
+    // İndeksi en küçük (ağaca en önce eklenen) düğüm
+    #[default]
+    LowestIndex,
+    // Maliyeti en düşük düğüm; maliyetler de eşitse indeksi en küçük olan
+    LowestCost,
+}
+
+// Tek bir iterasyonun sonucu
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepOutcome {
+    // Yeni düğüm eklendi; en iyi yol iyileştiyse 'improved' doğrudur
+    Added { index: usize, improved: bool },
+    // Yönlendirilen nokta ya da kenar bir engelle çarpıştı
+    Collision,
+    // Yönlendirilen nokta en yakın düğüme `min_node_separation`dan daha yakın
+    TooClose,
+    // Yönlendirilen nokta örnekleme bölgesinin dışında kaldı (yalnızca `OutOfBoundsPolicy::Reject`)
+    OutOfBounds,
+    // Uygun ebeveyn adaylarının hiçbirinden yeni noktaya `max_turn_angle` içinde dönülemiyor
+    TurnTooSharp,
+    // Başlangıç zaten hedef koşulunu sağlıyor; yol maliyeti 0 olan tek noktadır
+    StartAtGoal,
+    // En iyi maliyet durma koşulunu sağlıyor; başka iterasyon yapılmadı
+    Converged,
+    // Ağaç `max_nodes` sınırında ve unutulabilecek bir yaprak yok (ya da politika `Stop`)
+    NodeBudgetExhausted,
+}
+
+// Planlayıcının genel durumu
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlanStatus {
+    // İterasyon bütçesi henüz bitmedi
+    Planning,
+    // Planlama bitti ve hedefe ulaşan bir yol var
+    SolutionFound { cost: f32 },
+    // İterasyon bütçesi tükendi ama hedefe ulaşılamadı
+    ExhaustedNoSolution,
+}
+
+impl StepOutcome {
+    // Bu iterasyonda ağaca yeni bir düğüm eklenip eklenmediği
+    pub fn added(&self) -> bool {
+        matches!(self, StepOutcome::Added { .. })
+    }
+
+    // Bu iterasyonda en iyi yolun iyileşip iyileşmediği
+    pub fn improved(&self) -> bool {
+        matches!(self, StepOutcome::Added { improved: true, .. })
+    }
+}
+
+// Bir planlama çalışmasının özeti
+#[derive(Clone, Debug)]
+pub struct PlanStats {
+    pub iterations: usize, // Çalıştırılan iterasyon sayısı
+    pub nodes: usize,      // Ağaçtaki düğüm sayısı
+    pub best_cost: f32,    // En iyi yolun maliyeti (yol yoksa sonsuz)
+    pub path: Vec<Point>,  // En iyi yol (yol yoksa boş)
+    pub path_length: f32,  // En iyi yolun uzunluğu
+    pub min_clearance: f32, // En iyi yolun engellere olan en küçük mesafesi (engel yoksa sonsuz)
+    pub rewires: usize,    // Yapılan yeniden bağlama (ebeveyn değiştirme) sayısı
+    pub collision_checks: usize, // Yapılan yerel yol çarpışma denetimi sayısı
+    pub skipped_collision_checks: usize, // Tembel kipte ertelenen kenar denetimi sayısı
+    pub self_intersects: bool, // En iyi yol kendisiyle kesişiyor mu (bkz. `path_self_intersects`)
+    pub reached_goal: usize, // Yolun ulaştığı hedefin `goals` içindeki sırası (tek hedefte 0)
+}
+
+impl PlanStats {
+    // Hedefe ulaşan bir yol bulunup bulunmadığı
+    pub fn solved(&self) -> bool {
+        self.best_cost.is_finite()
+    }
+}
+
+// Ağaç onarımının özeti (bkz. `RRTStar::repair`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RepairStats {
+    pub severed: usize,    // Ebeveyn kenarı kesilen düğüm sayısı
+    pub reattached: usize, // Geçerli ağaca yeniden bağlanan yetim alt ağaç kökü sayısı
+    pub removed: usize,    // Silinen düğüm sayısı
+}
+
+// Eksenlere hizalı örnekleme bölgesi; eksenlerin aralıkları birbirinden bağımsızdır
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bounds {
+    pub min_x: f32,
+    pub max_x: f32,
+    pub min_y: f32,
+    pub max_y: f32,
+}
+
+impl Bounds {
+    // Yeni bir örnekleme bölgesi oluşturan yardımcı fonksiyon
+    pub fn new(min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> Self {
+        Bounds { min_x, max_x, min_y, max_y }
+    }
+
+    // Bölgenin alanı
+    pub fn area(&self) -> f32 {
+        (self.max_x - self.min_x) * (self.max_y - self.min_y)
+    }
+
+    // Noktanın bölgenin içinde (kenarlar dahil) olup olmadığı
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x >= self.min_x && point.x <= self.max_x && point.y >= self.min_y && point.y <= self.max_y
+    }
+
+    // Noktayı bölgenin içine çeker
+    pub fn clamp(&self, point: &Point) -> Point {
+        Point {
+            x: point.x.clamp(self.min_x, self.max_x),
+            y: point.y.clamp(self.min_y, self.max_y),
+        }
+    }
+}
+
+impl Default for Bounds {
+    // Görselleştirme penceresiyle aynı 400x400 harita
+    fn default() -> Self {
+        Bounds::new(0.0, 400.0, 0.0, 400.0)
+    }
+}
+
+// Planlayıcı parametrelerini doğrular: adım boyutu ve hedef eşiği pozitif, sabit yakınlık
+// yarıçapı adım boyutundan küçük olmamalı, uyarlanabilir yarıçapın gamma değeri pozitif olmalı
+fn validate_parameters(step_size: f32, goal_threshold: f32, radius_mode: RadiusMode) -> Result<(), ConfigError> {
+    if step_size.is_nan() || step_size <= 0.0 || step_size.is_infinite() {
+        return Err(ConfigError::NonPositiveStepSize(step_size));
+    }
+    if goal_threshold.is_nan() || goal_threshold < 0.0 {
+        return Err(ConfigError::NegativeGoalThreshold(goal_threshold));
+    }
+    match radius_mode {
+        RadiusMode::FixedRadius(radius) if radius.is_nan() || radius < step_size => {
+            Err(ConfigError::SearchRadiusTooSmall { search_radius: radius, step_size })
+        }
+        RadiusMode::Adaptive { gamma } if gamma.is_nan() || gamma <= 0.0 => Err(ConfigError::NonPositiveGamma(gamma)),
+        _ => Ok(()),
+    }
+}
+
+// a→b kenarından b→c kenarına geçerken yapılan dönüş açısı (radyan, [0, π]); sıfır uzunluklu
+// kenarlarda yön tanımsız olduğundan 0 kabul edilir
+fn turn_angle(a: &Point, b: &Point, c: &Point) -> f32 {
+    let (x1, y1) = (b.x - a.x, b.y - a.y);
+    let (x2, y2) = (c.x - b.x, c.y - b.y);
+    if (x1 == 0.0 && y1 == 0.0) || (x2 == 0.0 && y2 == 0.0) {
+        return 0.0;
+    }
+    (x1 * y2 - y1 * x2).atan2(x1 * x2 + y1 * y2).abs()
+}
+
+// İki boyutlu bir noktayı temsil eden yapı (hızlar gibi vektörler için de kullanılır)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point {
+    // İki nokta arasındaki öklid mesafesini hesaplayan fonksiyon; `hypot` ara sonuçta taşma
+    // ve hassasiyet kaybı yaşamaz
+    pub fn distance(&self, other: &Point) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    // İki nokta arasındaki öklid mesafesinin karesi (karşılaştırmalar için karekök gerektirmez)
+    pub fn distance_squared(&self, other: &Point) -> f32 {
+        (self.x - other.x).powi(2) + (self.y - other.y).powi(2)
+    }
+
+    // Her iki koordinat da sonlu mu (NaN ya da sonsuz değil)
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    // İki nokta arasında doğrusal ara değer (t = 0 bu nokta, t = 1 diğer nokta)
+    pub fn lerp(&self, other: &Point, t: f32) -> Point {
+        Point {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+        }
+    }
+}
+
+// Düğüm yapısı: bir nokta, ebeveyn düğüm indeksi ve maliyet içerir
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Node {
+    pub point: Point,
+    #[serde(default)]
+    pub heading: f32, // Düğümdeki yön (radyan); doğrusal yönlendirmede ebeveynden gelen kenarın doğrultusu
+    #[serde(default)]
+    pub velocity: Point, // Düğümdeki hız (yalnızca çift integratör yönlendirmesinde sıfırdan farklı)
+    pub parent: Option<usize>,
+    pub cost: f32,
+    #[serde(skip)]
+    heuristic: Cell<Option<f32>>, // Hedefe olan sezgisel mesafe (ilk istendiğinde hesaplanır)
+    edge_checked: bool,           // Ebeveyne olan kenar mevcut engellere göre doğrulandı mı
+    blocked: bool,                // Düğüm sonradan eklenen ya da taşınan bir engelin içinde kaldı mı
+    children: Vec<usize>,         // Çocuk düğümlerin indeksleri (maliyet yayılımı için)
+}
+
+impl Node {
+    // Yeni bir düğüm oluşturan yardımcı fonksiyon
+    fn new(point: Point, heading: f32, parent: Option<usize>, cost: f32) -> Self {
+        Node { point, heading, velocity: Point::default(), parent, cost, heuristic: Cell::new(None), edge_checked: true, blocked: false, children: Vec::new() }
+    }
+
+    // Çocuk düğümlerin indeksleri
+    pub fn children(&self) -> &[usize] {
+        &self.children
+    }
+
+    // Ebeveyne olan kenarın mevcut engellere göre doğrulanıp doğrulanmadığı
+    pub fn edge_checked(&self) -> bool {
+        self.edge_checked
+    }
+
+    // Düğümün bir engelin içinde kalıp kalmadığı; engelli düğümlerden genişletme yapılmaz
+    pub fn blocked(&self) -> bool {
+        self.blocked
+    }
+}
+
+// RRT* algoritmasını tanımlayan yapı. Kaydedilen durumda en iyi hedef seçimi ve yol önbelleği
+// tutulmaz, yüklenirken ağaçtan yeniden hesaplanır (bkz. `save_state`)
+#[derive(Serialize, Deserialize)]
+pub struct RRTStar {
+    nodes: Vec<Node>, // Ağaçtaki düğümler; kök (düğüm 0) hiçbir zaman silinmez, liste asla boş değildir
+    goal: Point,    // Hedef nokta
+    step_size: f32, // Adım boyutu
+    #[serde(default)]
+    step_mode: StepMode, // Adım uzunluğunun seçimi
+    goal_threshold: f32, // Hedef kabul bölgesinin yarıçapı (0 = yalnızca tam hedef)
+    radius_mode: RadiusMode, // Yakınlık yarıçapı seçimi
+    #[serde(skip, default = "infinite_cost")]
+    best_cost: f32,     // En iyi maliyet
+    goal_nodes: Vec<usize>,          // Hedef koşulunu sağlayan düğümlerin indeksleri
+    #[serde(skip)]
+    best_goal_node: Option<usize>,   // Hedefe en düşük maliyetle ulaşan düğüm
+    goal_node: Option<usize>,        // Tam olarak hedef noktasında bulunan (açık) hedef düğümü
+    #[serde(default)]
+    extra_goals: Vec<(Point, Option<usize>)>, // Çok hedefli planlamada diğer hedefler ve tam hedef düğümleri
+    #[serde(skip)]
+    best_path: Path,                 // En iyi hedef düğümünden köke izlenen yolun önbelleği
+    #[serde(skip)]
+    best_path_nodes: Vec<usize>,     // Önbellekteki yolun düğüm indeksleri (kökten hedefe)
+    obstacles: Vec<Obstacle>,        // Haritadaki engeller
+    occupancy_grid: Option<OccupancyGrid>, // Geometrik engellere ek olarak doluluk ızgarası
+    cost_regions: Vec<(Obstacle, f32)>, // Maliyet çarpanlı bölgeler (çamur, kum vb.)
+    bounds: Bounds,                  // Örnekleme bölgesi
+    max_iterations: usize,           // İterasyon sınırı
+    iterations: usize,               // Şu ana kadar çalıştırılan iterasyon sayısı
+    min_node_separation: f32,        // Yeni düğümün en yakın düğüme olan en küçük mesafesi
+    #[serde(default)]
+    collision_resolution: f32,       // Kenar çarpışma kontrolündeki örnek aralığı (0 = adıma göre)
+    out_of_bounds: OutOfBoundsPolicy, // Sınır dışına yönlendirilen noktalar için politika
+    nearest_tie_break: NearestTieBreak, // Eşit uzaklıktaki en yakın düğümler arasında seçim
+    stall_limit: usize,              // Düğüm eklenemeyen ardışık iterasyon sınırı (0 = kapalı)
+    #[serde(default)]
+    max_nodes: usize,                // Ağaçtaki en fazla düğüm sayısı (0 = sınırsız)
+    #[serde(default)]
+    node_limit_policy: NodeLimitPolicy, // Düğüm sınırına ulaşıldığında ne yapılacağı
+    termination: Option<Termination>, // Erken durma koşulu
+    goal_bias: GoalBias,             // Hedef örnekleme olasılığı
+    sampler: Sampler,                // Örnekleme stratejisi
+    #[serde(default)]
+    sampler_blend: Vec<(Sampler, f32)>, // Karışık örneklemede örnekleyiciler ve toplamı 1 olan ağırlıkları (boş = kapalı)
+    try_goal_every: usize,           // Hedefe doğrudan uzanma denemelerinin aralığı (0 = kapalı)
+    rewire_improvement_threshold: f32, // Yeniden bağlama için gereken en küçük maliyet kazancı
+    max_turn_angle: Option<f32>,     // Art arda iki kenar arasındaki en büyük dönüş açısı (None = sınırsız)
+    optimize: bool,                  // Ebeveyn seçimi ve yeniden bağlama yapılır mı (false = düz RRT)
+    #[serde(default)]
+    any_angle: bool,                 // Ebeveyn seçiminde seçilen ebeveynin atalarına da görüş hattıyla bağlanılır mı
+    #[serde(default)]
+    steering: Steering,              // Kenarların oluşturulma biçimi
+    #[serde(default)]
+    goal_heading: Option<f32>,       // Hedefte istenen yön (None = serbest)
+    rewires: usize,                  // Şu ana kadar yapılan yeniden bağlama sayısı
+    #[serde(default)]
+    cost_history: Vec<(usize, f32)>, // En iyi maliyetin değiştiği iterasyonlar ve yeni maliyetler (bkz. `save_convergence_csv`)
+    #[serde(default)]
+    reroot_prune_distance: f32,      // Yeniden köklemede robotun arkasında bundan uzak düğümler silinir (0 = kapalı)
+    #[serde(default)]
+    lazy_collision: bool,            // Kenarlar eklenirken yalnızca uç noktası mı denetlenir (bkz. `set_lazy_collision`)
+    #[serde(skip, default = "infinite_cost")]
+    shared_cost: f32,                // Dışarıda (ör. B-RRT* köprüsüyle) bulunan en iyi yolun maliyeti
+    #[serde(skip)]
+    restart_seed: Option<u64>,       // `plan_with_restarts` ile çözüm bulan tohum
+    #[serde(skip)]
+    invalid_edges: Vec<usize>,       // Tembel doğrulamada ebeveyn kenarı çarpıştığı anlaşılan, silinmeyi bekleyen düğümler
+    #[serde(skip)]
+    collision_checks: Cell<usize>,   // Yapılan yerel yol çarpışma denetimi sayısı
+    #[serde(skip)]
+    skipped_collision_checks: Cell<usize>, // Tembel kipte ertelenen kenar denetimi sayısı
+    #[serde(skip)]
+    neighbor_queries: Cell<usize>,   // Yapılan yakınlık yarıçapı taraması sayısı
+    #[serde(skip, default = "HaltonSequence::unshifted")]
+    halton: HaltonSequence,          // Halton örneklemesinin dizi durumu
+    #[serde(skip)]
+    smart_beacons: (Path, Vec<Point>), // Son hesaplanan RRT*-Smart işaret noktaları ve hesaplandıkları yol
+    #[serde(skip)]
+    recent_rejections: VecDeque<Point>, // Çarpışma nedeniyle reddedilen son noktalar (eskiden yeniye)
+    #[serde(skip, default = "StdRng::from_entropy")]
+    rng: StdRng,        // Rastgele sayı üreteci
+    #[serde(skip)]
+    on_path_improved: Option<PathImprovedCallback>, // En iyi maliyet her düştüğünde çağrılır
+    #[serde(skip)]
+    collision_checker: Option<Box<dyn CollisionChecker + Send>>, // Engellere ek olarak danışılan özel denetleyici
+}
+
+// Tembel kipte bir aday yolun doğrulama sonucu
+enum Validation {
+    // Yoldaki tüm kenarlar engelsiz
+    Valid,
+    // Çarpışan bir kenar başka bir ebeveyne taşındı; maliyetler değişti
+    Repaired,
+    // Çarpışan bir kenar onarılamadı; düğüm silinmeyi bekliyor
+    Invalid,
+}
+
+// En iyi yol iyileştiğinde yeni maliyetle çağrılan geri çağırma
+type PathImprovedCallback = Box<dyn FnMut(f32) + Send>;
+
+// Kaydedilmeyen en iyi maliyetin başlangıç değeri (JSON sonsuzu temsil edemez)
+fn infinite_cost() -> f32 {
+    f32::INFINITY
+}
+
+impl RRTStar {
+    // RRT* algoritmasını başlatan fonksiyon; geçersiz parametrelerde paniğe girer
+    // (hata döndüren sürüm için bkz. `try_new`)
+    pub fn new(start: Point, goal: Point, step_size: f32, goal_threshold: f32, search_radius: f32) -> Self {
+        Self::try_new(start, goal, step_size, goal_threshold, search_radius)
+            .unwrap_or_else(|error| panic!("invalid RRT* configuration: {error}"))
+    }
+
+    // Parametreleri doğrulayarak RRT* algoritmasını başlatan fonksiyon
+    pub fn try_new(
+        start: Point,
+        goal: Point,
+        step_size: f32,
+        goal_threshold: f32,
+        search_radius: f32,
+    ) -> Result<Self, ConfigError> {
+        let radius_mode = RadiusMode::FixedRadius(search_radius);
+        validate_parameters(step_size, goal_threshold, radius_mode)?;
+        Ok(Self::build(start, goal, step_size, goal_threshold, radius_mode))
+    }
+
+    // Birden çok kabul edilebilir hedefle RRT* başlatır; yol hedeflerin herhangi birine en düşük
+    // maliyetle ulaşan yoldur. Hedef listesi boşsa ya da parametreler geçersizse paniğe girer
+    // (bkz. `try_new_multi`)
+    pub fn new_multi(start: Point, goals: Vec<Point>, step_size: f32, goal_threshold: f32, search_radius: f32) -> Self {
+        Self::try_new_multi(start, goals, step_size, goal_threshold, search_radius)
+            .unwrap_or_else(|error| panic!("invalid RRT* configuration: {error}"))
+    }
+
+    // `new_multi` ile aynı, ancak geçersiz parametrelerde hata döner
+    pub fn try_new_multi(
+        start: Point,
+        goals: Vec<Point>,
+        step_size: f32,
+        goal_threshold: f32,
+        search_radius: f32,
+    ) -> Result<Self, ConfigError> {
+        let (&goal, others) = goals.split_first().ok_or(ConfigError::NoGoals)?;
+        let mut rrt_star = Self::try_new(start, goal, step_size, goal_threshold, search_radius)?;
+        for &other in others {
+            rrt_star.add_goal(other)?;
+        }
+        Ok(rrt_star)
+    }
+
+    // Doğrulanmış parametrelerle planlayıcıyı kurar
+    fn build(start: Point, goal: Point, step_size: f32, goal_threshold: f32, radius_mode: RadiusMode) -> Self {
+        // Başlangıç düğümünü kök olarak ekler, maliyet sıfırdır
+        let root = Node::new(start, 0.0, None, 0.0);
+        let mut rrt_star = RRTStar {
+            nodes: vec![root],
+            goal,
+            step_size,
+            step_mode: StepMode::default(),
+            goal_threshold,
+            radius_mode,
+            best_cost: f32::INFINITY, // Başlangıçta en iyi yol maliyeti sonsuz
+            goal_nodes: Vec::new(),
+            best_goal_node: None,
+            goal_node: None,
+            extra_goals: Vec::new(),
+            best_path: Path::default(),
+            best_path_nodes: Vec::new(),
+            obstacles: Vec::new(),
+            occupancy_grid: None,
+            cost_regions: Vec::new(),
+            bounds: Bounds::default(),
+            max_iterations: 5000,
+            iterations: 0,
+            min_node_separation: 0.0,
+            collision_resolution: 0.0,
+            out_of_bounds: OutOfBoundsPolicy::default(),
+            nearest_tie_break: NearestTieBreak::default(),
+            stall_limit: 0,
+            max_nodes: 0,
+            node_limit_policy: NodeLimitPolicy::default(),
+            termination: None,
+            goal_bias: GoalBias::default(),
+            sampler: Sampler::default(),
+            sampler_blend: Vec::new(),
+            try_goal_every: 0,
+            rewire_improvement_threshold: 0.0,
+            max_turn_angle: None,
+            optimize: true,
+            any_angle: false,
+            steering: Steering::default(),
+            goal_heading: None,
+            rewires: 0,
+            cost_history: Vec::new(),
+            reroot_prune_distance: 0.0,
+            lazy_collision: false,
+            shared_cost: f32::INFINITY,
+            restart_seed: None,
+            invalid_edges: Vec::new(),
+            collision_checks: Cell::new(0),
+            skipped_collision_checks: Cell::new(0),
+            neighbor_queries: Cell::new(0),
+            halton: HaltonSequence::unshifted(),
+            smart_beacons: (Path::default(), Vec::new()),
+            recent_rejections: VecDeque::with_capacity(RECENT_REJECTIONS_CAPACITY),
+            rng: StdRng::from_entropy(),
+            on_path_improved: None,
+            collision_checker: None,
+        };
+        rrt_star.check_root_at_goal();
+        rrt_star
+    }
+
+    // Ağacı verilen başlangıç noktasından yeniden başlatır; ayrılmış bellek ve başlangıç yönü korunur
+    pub fn reset(&mut self, start: Point) {
+        let start_heading = self.nodes[0].heading;
+        self.nodes.clear();
+        self.nodes.push(Node::new(start, start_heading, None, 0.0));
+        self.goal_nodes.clear();
+        self.best_goal_node = None;
+        self.exact_goal_nodes_mut().for_each(|node| *node = None);
+        self.best_path.clear();
+        self.best_path_nodes.clear();
+        self.best_cost = f32::INFINITY;
+        self.iterations = 0;
+        self.rewires = 0;
+        self.cost_history.clear();
+        self.invalid_edges.clear();
+        self.collision_checks.set(0);
+        self.skipped_collision_checks.set(0);
+        self.neighbor_queries.set(0);
+        self.recent_rejections.clear();
+        self.check_root_at_goal();
+    }
+
+    // Başlangıç bir hedefle çakışıyor ya da hedef eşiğinin içindeyse kök zaten bir çözümdür
+    fn check_root_at_goal(&mut self) {
+        let start = self.nodes[0].point;
+        if self.is_goal(&start) {
+            for goal in 0..self.goal_count() {
+                if start.distance(&self.goal_point(goal)) == 0.0 {
+                    *self.exact_goal_node_mut(goal) = Some(0);
+                }
+            }
+            if !self.goal_nodes.contains(&0) {
+                self.goal_nodes.push(0);
+            }
+            self.select_best_goal();
+        }
+    }
+
+    // Yapılandırmadan, verilen tohumla tekrarlanabilir bir planlayıcı oluşturur
+    // Geçersiz parametrelerde paniğe girer (bkz. `try_from_config`)
+    pub fn from_config(config: &PlanConfig, seed: u64) -> Self {
+        Self::try_from_config(config, seed).unwrap_or_else(|error| panic!("invalid RRT* configuration: {error}"))
+    }
+
+    // Yapılandırmayı doğrulayarak tekrarlanabilir bir planlayıcı oluşturur
+    pub fn try_from_config(config: &PlanConfig, seed: u64) -> Result<Self, ConfigError> {
+        validate_parameters(config.step_size, config.goal_threshold, config.radius_mode)?;
+        validate_goal_bias(config.goal_bias)?;
+        validate_sampler(config.sampler, &config.sampler_blend)?;
+        validate_turn_angle(config.max_turn_angle)?;
+        validate_steering(config.steering, config.goal_threshold)?;
+        validate_goal_heading(config.goal_heading)?;
+        validate_collision_resolution(config.collision_resolution)?;
+        validate_step_mode(config.step_mode, config.step_size)?;
+        validate_bounds(config.bounds)?;
+        let mut rrt_star = Self::build(config.start, config.goal, config.step_size, config.goal_threshold, config.radius_mode);
+        rrt_star.step_mode = config.step_mode;
+        rrt_star.obstacles = config.obstacles.clone();
+        rrt_star.occupancy_grid = config.occupancy_grid.clone();
+        rrt_star.cost_regions = config.cost_regions.clone();
+        rrt_star.bounds = config.bounds;
+        rrt_star.max_iterations = config.max_iterations;
+        rrt_star.min_node_separation = config.min_node_separation;
+        rrt_star.collision_resolution = config.collision_resolution;
+        rrt_star.out_of_bounds = config.out_of_bounds;
+        rrt_star.nearest_tie_break = config.nearest_tie_break;
+        rrt_star.stall_limit = config.stall_limit;
+        rrt_star.max_nodes = config.max_nodes;
+        rrt_star.node_limit_policy = config.node_limit_policy;
+        rrt_star.lazy_collision = config.lazy_collision;
+        rrt_star.termination = config.termination;
+        rrt_star.goal_bias = config.goal_bias;
+        rrt_star.sampler = config.sampler;
+        rrt_star.set_sampler_blend(config.sampler_blend.clone())?;
+        rrt_star.try_goal_every = config.try_goal_every;
+        rrt_star.rewire_improvement_threshold = config.rewire_improvement_threshold;
+        rrt_star.max_turn_angle = config.max_turn_angle;
+        rrt_star.optimize = config.optimize;
+        rrt_star.any_angle = config.any_angle;
+        rrt_star.steering = config.steering;
+        rrt_star.nodes[0].heading = config.start_heading;
+        rrt_star.goal_heading = config.goal_heading;
+        // Engel içindeki bir başlangıç ya da hedefle hiçbir yol bulunamaz; iterasyonlar harcanmadan bildirilir
+        if !rrt_star.is_collision_free(&config.start) {
+            return Err(ConfigError::StartInCollision(config.start));
+        }
+        if !rrt_star.is_collision_free(&config.goal) {
+            return Err(ConfigError::GoalInCollision(config.goal));
+        }
+        rrt_star.rng = StdRng::seed_from_u64(seed);
+        rrt_star.halton = HaltonSequence::new(seed);
+        Ok(rrt_star)
+    }
+
+    // Ağaçtaki düğümler. Oluşturma, `reset` ve düğüm silme kökü her zaman korur; bu yüzden
+    // dilim en az bir eleman içerir
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    // Ağaçtaki düğüm noktalarını çevreleyen eksenlere hizalı kutunun (en küçük, en büyük)
+    // köşeleri; kök her zaman bulunduğundan yalnızca kök varsa iki köşe de köktür
+    pub fn tree_bounds(&self) -> (Point, Point) {
+        let root = self.nodes[0].point;
+        self.nodes.iter().fold((root, root), |(min, max), node| {
+            (
+                Point { x: min.x.min(node.point.x), y: min.y.min(node.point.y) },
+                Point { x: max.x.max(node.point.x), y: max.y.max(node.point.y) },
+            )
+        })
+    }
+
+    // Hedef nokta (çok hedefli planlamada birincil hedef)
+    pub fn goal(&self) -> Point {
+        self.goal
+    }
+
+    // Tüm hedef noktaları; birincil hedef ilk sıradadır
+    pub fn goals(&self) -> Vec<Point> {
+        (0..self.goal_count()).map(|goal| self.goal_point(goal)).collect()
+    }
+
+    // Hedef kümesine yeni bir kabul edilebilir hedef ekler; yol, hedeflerin herhangi birine en
+    // düşük maliyetle ulaşan yoldur ve sezgiseller en yakın hedefe olan mesafeyi kullanır.
+    // Planlamaya başlamadan önce çağrılmalıdır: ağaçtaki düğümlerden yalnızca kök yeni hedefin
+    // bölgesinde mi diye denetlenir. FMT*, BIT*, RRT-Connect ve B-RRT* yalnızca birincil
+    // hedefi kullanır. Hedef bir engelin içindeyse hata döner
+    pub fn add_goal(&mut self, goal: Point) -> Result<(), ConfigError> {
+        if !self.is_collision_free(&goal) {
+            return Err(ConfigError::GoalInCollision(goal));
+        }
+        self.extra_goals.push((goal, None));
+        for node in &self.nodes {
+            node.heuristic.set(None);
+        }
+        self.check_root_at_goal();
+        Ok(())
+    }
+
+    // Birincil hedefi taşır ve ağacı korur (hareket eden bir hedefi izlemek için): birincil
+    // hedefe ait hedef düğümleri ve en iyi yol düşürülür, eski tam hedef düğümü sıradan bir
+    // düğüm olarak kalır. Ardından ağaçtaki her engelsiz düğüm yeni hedef koşuluna göre yeniden
+    // değerlendirilir; eşiğin içindeki düğümler yeni hedefe bağlanır ya da hedef kümesine
+    // eklenir ve en iyi yol sıfırdan seçilir. Sezgiseller ve informed elips yeni hedeften
+    // hesaplanır. Yeni en iyi maliyet eskisinden yüksek olabilir. Hedef bir engelin içindeyse
+    // hiçbir şey değiştirilmeden hata döner
+    pub fn set_goal(&mut self, goal: Point) -> Result<(), ConfigError> {
+        if !self.is_collision_free(&goal) {
+            return Err(ConfigError::GoalInCollision(goal));
+        }
+        self.goal = goal;
+        self.goal_node = None;
+        for node in &self.nodes {
+            node.heuristic.set(None);
+        }
+        // Yalnızca diğer hedeflere ait hedef düğümleri geçerli kalır
+        let goal_nodes = std::mem::take(&mut self.goal_nodes);
+        self.goal_nodes = goal_nodes
+            .into_iter()
+            .filter(|&index| {
+                let point = self.nodes[index].point;
+                self.is_exact_goal_node(index) || (self.nearest_goal(&point) != 0 && self.is_goal(&point))
+            })
+            .collect();
+        // Yeni hedefe bağlanan düğümler sona eklenir ve zaten tam hedef düğümüdür
+        for index in 1..self.nodes.len() {
+            if !self.nodes[index].blocked && !self.goal_nodes.contains(&index) {
+                self.register_goal_candidate(index);
+            }
+        }
+        self.check_root_at_goal();
+        self.reselect_best_goal();
+        Ok(())
+    }
+
+    // En iyi yolun ulaştığı hedefin `goals` içindeki sırası; yol yoksa None
+    pub fn reached_goal(&self) -> Option<usize> {
+        self.best_goal_node.map(|index| self.nearest_goal(&self.nodes[index].point))
+    }
+
+    // Şu ana kadar bulunan en iyi yolun maliyeti (yol yoksa sonsuz)
+    pub fn best_cost(&self) -> f32 {
+        self.best_cost
+    }
+
+    // Yakınlık yarıçapının belirlenme biçimini değiştirir
+    pub fn set_radius_mode(&mut self, radius_mode: RadiusMode) {
+        self.radius_mode = radius_mode;
+    }
+
+    // Ağacın mevcut büyüklüğüne göre kullanılan yakınlık yarıçapı
+    pub fn search_radius(&self) -> f32 {
+        self.search_radius_for(self.nodes.len())
+    }
+
+    // `n` düğümlük bir ağaç ya da örnek kümesi için yakınlık yarıçapı
+    pub(crate) fn search_radius_for(&self, n: usize) -> f32 {
+        match self.radius_mode {
+            RadiusMode::FixedRadius(radius) => radius,
+            RadiusMode::Adaptive { gamma } => {
+                let n = n as f32;
+                let radius = gamma * (n.ln() / n).powf(1.0 / DIMENSION);
+                radius.min(ADAPTIVE_RADIUS_STEP_FACTOR * self.step_size)
+            }
+        }
+    }
+
+    // Haritadaki engeller
+    pub fn obstacles(&self) -> &[Obstacle] {
+        &self.obstacles
+    }
+
+    // Haritaya yeni bir engel ekler; içinde kalan düğümler engelli olarak işaretlenir ve
+    // `find_nearest` tarafından atlanır
+    pub fn add_obstacle(&mut self, obstacle: Obstacle) {
+        for node in &mut self.nodes {
+            node.blocked |= obstacle.contains(&node.point);
+        }
+        self.obstacles.push(obstacle);
+    }
+
+    // Doluluk ızgarası (varsa)
+    pub fn occupancy_grid(&self) -> Option<&OccupancyGrid> {
+        self.occupancy_grid.as_ref()
+    }
+
+    // Doluluk ızgarasını ayarlar ya da kaldırır; düğümlerin engelli işaretleri yenilenir.
+    // Ağacı yeni haritaya uydurmak için ardından `invalidate` çağrılmalıdır
+    pub fn set_occupancy_grid(&mut self, occupancy_grid: Option<OccupancyGrid>) {
+        self.occupancy_grid = occupancy_grid;
+        self.update_blocked();
+    }
+
+    // Doluluk ızgarası kullanılıyorsa bir hedefin başlangıca boş hücrelerle bağlı olup olmadığı;
+    // ızgara yoksa her zaman 'true' (bkz. `OccupancyGrid::is_connected`)
+    pub fn goal_reachable(&self) -> bool {
+        self.occupancy_grid.as_ref().is_none_or(|grid| {
+            (0..self.goal_count()).any(|goal| grid.is_connected(&self.nodes[0].point, &self.goal_point(goal)))
+        })
+    }
+
+    // Maliyet çarpanlı bölgeler
+    pub fn cost_regions(&self) -> &[(Obstacle, f32)] {
+        &self.cost_regions
+    }
+
+    // Geçilebilir ama pahalı bir bölge ekler; bölgeden geçen kenarların uzunluğu çarpanla
+    // ağırlıklandırılır. Düz çizgi sezgiselinin kabul edilebilir kalması için çarpan 1'den
+    // küçük olmamalıdır
+    pub fn add_cost_region(&mut self, region: Obstacle, multiplier: f32) {
+        self.cost_regions.push((region, multiplier));
+    }
+
+    // Var olan bir engeli yenisiyle değiştirir (hareketli engeller için).
+    // Ağacı yeni duruma uydurmak için ardından `invalidate` ya da tembel doğrulama için
+    // `mark_edges_unchecked` çağrılmalıdır
+    pub fn update_obstacle(&mut self, index: usize, new: Obstacle) {
+        self.obstacles[index] = new;
+        self.update_blocked();
+    }
+
+    // Engel taşındığında düğümler engelden çıkmış ya da engelin içinde kalmış olabilir
+    fn update_blocked(&mut self) {
+        for i in 0..self.nodes.len() {
+            self.nodes[i].blocked = !self.is_collision_free(&self.nodes[i].point);
+        }
+    }
+
+    // Örnekleme bölgesi
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    // Yeni düğümün en yakın düğüme olan en küçük mesafesini ayarlar (0 = kapalı)
+    pub fn set_min_node_separation(&mut self, min_node_separation: f32) {
+        self.min_node_separation = min_node_separation;
+    }
+
+    // Kenar çarpışma kontrolündeki ara örneklerin aralığını ayarlar (0 = adım boyutunun dörtte
+    // biri). Küçük aralık ince engellerin kenarlar arasından kaçırılmasını önler ama her kenar
+    // için daha çok nokta denetlendiğinden planlamayı yavaşlatır; büyük aralık hızlıdır ama
+    // aralıktan ince engellerin içinden geçen kenarlar kabul edilebilir. Doluluk ızgarası
+    // hücre hücre denetlendiğinden bu ayardan etkilenmez
+    pub fn set_collision_resolution(&mut self, collision_resolution: f32) {
+        self.collision_resolution = collision_resolution;
+    }
+
+    // Kenar çarpışma kontrolünde kullanılan örnek aralığı
+    pub fn collision_resolution(&self) -> f32 {
+        self.edge_check_spacing()
+    }
+
+    // Adım uzunluğunun seçimini değiştirir; en kısa adım (0, step_size] aralığında olmalıdır
+    pub fn set_step_mode(&mut self, step_mode: StepMode) {
+        self.step_mode = step_mode;
+    }
+
+    // Adım uzunluğunun seçimi
+    pub fn step_mode(&self) -> StepMode {
+        self.step_mode
+    }
+
+    // Örnekleme bölgesini değiştirir
+    pub fn set_bounds(&mut self, bounds: Bounds) {
+        self.bounds = bounds;
+    }
+
+    // Sınır dışına yönlendirilen noktalar için politikayı ayarlar
+    pub fn set_out_of_bounds_policy(&mut self, policy: OutOfBoundsPolicy) {
+        self.out_of_bounds = policy;
+    }
+
+    // Eşit uzaklıktaki en yakın düğümler arasında seçim kuralını ayarlar
+    pub fn set_nearest_tie_break(&mut self, tie_break: NearestTieBreak) {
+        self.nearest_tie_break = tie_break;
+    }
+
+    // Tek bir RRT* iterasyonu: örnekle, en yakın düğümden yönlen, çarpışma yoksa ekle ve
+    // yeniden bağla
+    pub fn step(&mut self) -> StepOutcome {
+        if self.best_goal_node == Some(0) {
+            return StepOutcome::StartAtGoal;
+        }
+        if self.is_converged() {
+            return StepOutcome::Converged;
+        }
+        self.iterations += 1;
+        // Sınırdaki ağaçta yeni düğüme yer açılır; hedefe bağlanan bir adım kabul bölgesindeki
+        // düğüme ek olarak hedef düğümünü de eklediğinden ağaç sınırı bir düğüm aşabilir
+        if self.max_nodes > 0 && self.nodes.len() >= self.max_nodes {
+            if self.node_limit_policy == NodeLimitPolicy::Stop {
+                return StepOutcome::NodeBudgetExhausted;
+            }
+            while self.nodes.len() >= self.max_nodes {
+                if !self.forget_worst_leaf() {
+                    return StepOutcome::NodeBudgetExhausted;
+                }
+            }
+        }
+        if let Some(model) = self.double_integrator() {
+            return self.step_kinodynamic(model);
+        }
+        // Önceki adımda tembel doğrulamada çarpıştığı anlaşılan kenarlar alt ağaçlarıyla silinir;
+        // adım ortasında silmek tutulan indeksleri geçersiz kılacağından burada yapılır
+        if !self.invalid_edges.is_empty() {
+            self.remove_invalid_edges();
+        }
+
+        // Başlangıç hedefe bir adımdan yakınsa ve aradaki doğru parçası engelsizse ilk
+        // iterasyonda hedefe doğrudan bağlanılır
+        let start = self.nodes[0].point;
+        let goal = self.goal_point(self.nearest_goal(&start));
+        let goal_heading = self.arrival_heading(0, &goal);
+        if self.nodes.len() == 1
+            && start.distance(&goal) <= self.step_size
+            && self.transition_free(&start, self.nodes[0].heading, &goal, goal_heading)
+        {
+            let index = self.add_node(goal, 0);
+            let improved = self.update_best_path();
+            return StepOutcome::Added { index, improved };
+        }
+
+        // Henüz çözüm yoksa her `try_goal_every` iterasyonda örnekleme yerine hedefe uzanılır
+        if self.try_goal_every > 0 && self.iterations.is_multiple_of(self.try_goal_every) && !self.best_cost.is_finite() {
+            let previous_cost = self.best_cost;
+            return match self.extend_toward_goal() {
+                Some(index) => StepOutcome::Added { index, improved: self.best_cost < previous_cost },
+                None => StepOutcome::Collision,
+            };
+        }
+
+        let rand_point = self.sample();
+        let Some(nearest_index) = self.find_nearest(&rand_point) else {
+            return StepOutcome::Collision;
+        };
+        let nearest_point = self.nodes[nearest_index].point;
+        let (mut new_point, new_heading) = self.steer_from(nearest_index, &rand_point);
+
+        // NaN koordinatlar (bozuk bir engel ya da dışarıdan eklenen bir noktadan) hiçbir engelin
+        // içinde sayılmaz ve kırpılamaz; ağaca girmeden reddedilir
+        if !new_point.is_finite() {
+            return StepOutcome::OutOfBounds;
+        }
+
+        // Kenara yakın düğümlerden yönlendirilen nokta bölgenin dışına taşabilir
+        if !self.bounds.contains(&new_point) {
+            match self.out_of_bounds {
+                OutOfBoundsPolicy::Clamp => new_point = self.bounds.clamp(&new_point),
+                OutOfBoundsPolicy::Reject => return StepOutcome::OutOfBounds,
+            }
+        }
+
+        // Mevcut bir düğümün üstüne ya da neredeyse üstüne düşen noktalar ağacı gereksiz büyütür;
+        // hedef koşulunu sağlayan noktalar ise hedef bağlantısını engellememek için hep kabul edilir
+        let separation = nearest_point.distance(&new_point);
+        if separation == 0.0 || (!self.is_goal(&new_point) && separation < self.min_node_separation) {
+            return StepOutcome::TooClose;
+        }
+
+        // Çarpışma kontrolünden geçerse en ucuz ebeveyne bağlayarak düğümü ekle ve yeniden bağla
+        if !self.edge_admissible(&nearest_point, self.nodes[nearest_index].heading, &new_point, new_heading) {
+            self.record_rejection(new_point);
+            return StepOutcome::Collision;
+        }
+        let previous_cost = self.best_cost;
+        // Düz RRT'de yeni düğüm doğrudan en yakın düğüme bağlanır, komşu araması yapılmaz
+        if !self.optimize {
+            if !self.turn_allowed(nearest_index, &new_point) {
+                return StepOutcome::TurnTooSharp;
+            }
+            let index = self.add_node_with_heading(new_point, new_heading, nearest_index);
+            self.defer_edge_check(index);
+            self.update_best_path();
+            return StepOutcome::Added { index, improved: self.best_cost < previous_cost };
+        }
+        // Komşular eklemeden önce bir kez bulunur ve hem ebeveyn seçimi hem yeniden bağlama için kullanılır
+        let neighbors = self.near_point(&new_point, self.search_radius());
+        let Some(parent_index) = self.choose_parent_for(&new_point, new_heading, nearest_index, &neighbors) else {
+            return StepOutcome::TurnTooSharp;
+        };
+        let index = self.add_node_with_heading(new_point, new_heading, parent_index);
+        self.defer_edge_check(index);
+        self.rewire_neighbors(index, &neighbors);
+        self.update_best_path();
+        StepOutcome::Added { index, improved: self.best_cost < previous_cost }
+    }
+
+    // Hedefe en yakın düğümden hedefe doğru adım boyutu kadar sıçramalarla ilerler; ilk
+    // çarpışmada, bölge dışına çıkıldığında ya da hedef koşulu sağlandığında durur. Hedef
+    // koşulunu sağlayan son düğüm `update_best_path` ile tam hedef noktasına bağlanır.
+    // Çok hedefli planlamada hedefler denemeden denemeye sırayla seçilir.
+    // Son eklenen düğümün indeksini döner
+    fn extend_toward_goal(&mut self) -> Option<usize> {
+        let goal = self.goal_point((self.iterations / self.try_goal_every.max(1)) % self.goal_count());
+        let mut current = self.find_nearest(&goal)?;
+        let mut last = None;
+        while !self.is_goal(&self.nodes[current].point) {
+            let from = &self.nodes[current];
+            let (next, heading) = self.steer_from(current, &goal);
+            if !next.is_finite()
+                || !self.bounds.contains(&next)
+                || !self.turn_allowed(current, &next)
+                || !self.edge_admissible(&from.point, from.heading, &next, heading)
+            {
+                break;
+            }
+            current = self.add_node_with_heading(next, heading, current);
+            self.defer_edge_check(current);
+            if self.optimize {
+                self.rewire(current);
+            }
+            self.update_best_path();
+            last = Some(current);
+        }
+        last
+    }
+
+    // Erken durma koşulunu ayarlar
+    pub fn set_termination(&mut self, termination: Option<Termination>) {
+        self.termination = termination;
+    }
+
+    // Hedefe giden herhangi bir yolun maliyeti için düz çizgi alt sınırı. En iyi yol tam
+    // hedef noktasında bitiyorsa hedefe olan mesafe, aksi halde hedef bölgesine olan mesafedir
+    pub fn cost_lower_bound(&self) -> f32 {
+        let distance = self.heuristic(0);
+        let distance = if self.best_goal_node.is_some_and(|best| self.is_exact_goal_node(best)) {
+            distance
+        } else {
+            (distance - self.goal_threshold).max(0.0)
+        };
+        distance / self.cost_scale()
+    }
+
+    // Birim maliyetle en fazla katedilebilen mesafe: maliyet uzunluksa 1, çift integratör
+    // yönlendirmesinde maliyet süre olduğundan en büyük hız. Mesafeye dayalı sezgiseller bu
+    // oranla maliyete çevrilir
+    pub(crate) fn cost_scale(&self) -> f32 {
+        self.double_integrator().map_or(1.0, |model| model.max_speed)
+    }
+
+    // En iyi maliyetin durma koşulunu sağlayıp sağlamadığı
+    pub fn is_converged(&self) -> bool {
+        if !self.best_cost.is_finite() {
+            return false;
+        }
+        match self.termination {
+            None => false,
+            Some(Termination::CostWithinFactor(factor)) => self.best_cost <= factor * self.cost_lower_bound(),
+            Some(Termination::CostWithinEpsilon(epsilon)) => self.best_cost <= self.cost_lower_bound() + epsilon,
+        }
+    }
+
+    // Düğüm eklenemeyen ardışık iterasyon sınırını ayarlar (0 = kapalı)
+    pub fn set_stall_limit(&mut self, stall_limit: usize) {
+        self.stall_limit = stall_limit;
+    }
+
+    // Ağaçtaki en fazla düğüm sayısını (0 = sınırsız) ve sınıra ulaşıldığındaki politikayı
+    // ayarlar. Uzun çalışmalarda bellek ve kare süresi sabit kalır; `Forget` politikasında ağaç
+    // sınırda tutulurken en iyi yol iyileşmeye devam eder
+    pub fn set_node_limit(&mut self, max_nodes: usize, policy: NodeLimitPolicy) {
+        self.max_nodes = max_nodes;
+        self.node_limit_policy = policy;
+    }
+
+    // Ağaçtaki en fazla düğüm sayısı (0 = sınırsız)
+    pub fn max_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    // Hedef örnekleme olasılığını ayarlar
+    pub fn set_goal_bias(&mut self, goal_bias: GoalBias) {
+        self.goal_bias = goal_bias;
+    }
+
+    // Örnekleme stratejisini ayarlar
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
+
+    // Örnekleyicileri ağırlıklarıyla karıştırır: her iterasyonda (hedef örneklemesinden sonra)
+    // biri ağırlığıyla orantılı olasılıkla seçilir, örneğin `[(Uniform, 0.7), (Goal, 0.2),
+    // (PathBiased { probability: 1.0, .. }, 0.1)]`. Ağırlıklar toplamı 1 olacak şekilde
+    // ölçeklenir; seçilen örnekleyicinin kendi olasılığı (ör. `PathBiased::probability`) yine
+    // uygulanır. Boş liste karıştırmayı kapatır ve `sampler` kullanılır. Ağırlıklardan biri
+    // negatif ya da sonsuzsa ya da toplam sıfırsa hiçbir şey değiştirilmeden hata döner
+    pub fn set_sampler_blend(&mut self, blend: Vec<(Sampler, f32)>) -> Result<(), ConfigError> {
+        if let Some(&(_, weight)) = blend.iter().find(|(_, weight)| !(weight.is_finite() && *weight >= 0.0)) {
+            return Err(ConfigError::InvalidSamplerWeight(weight));
+        }
+        let total: f32 = blend.iter().map(|(_, weight)| weight).sum();
+        if !blend.is_empty() && total <= 0.0 {
+            return Err(ConfigError::InvalidSamplerWeight(total));
+        }
+        self.sampler_blend = blend.into_iter().map(|(sampler, weight)| (sampler, weight / total)).collect();
+        Ok(())
+    }
+
+    // Karışık örneklemedeki örnekleyiciler ve ölçeklenmiş ağırlıkları (kapalıysa boş)
+    pub fn sampler_blend(&self) -> &[(Sampler, f32)] {
+        &self.sampler_blend
+    }
+
+    // Hedefe doğrudan uzanma denemelerinin aralığını ayarlar (0 = kapalı)
+    pub fn set_try_goal_every(&mut self, try_goal_every: usize) {
+        self.try_goal_every = try_goal_every;
+    }
+
+    // Yeniden bağlama için gereken en küçük maliyet kazancını ayarlar. Yoğun ağaçlarda çok
+    // küçük kazançlar için yapılan ebeveyn değişiklikleri alt ağaç güncellemeleri yüzünden
+    // pahalıdır; 0 her kesin iyileşmede yeniden bağlar
+    pub fn set_rewire_improvement_threshold(&mut self, threshold: f32) {
+        self.rewire_improvement_threshold = threshold;
+    }
+
+    // Art arda iki kenar arasındaki en büyük dönüş açısını ayarlar (radyan, None = sınırsız);
+    // yalnızca bundan sonra eklenen ve yeniden bağlanan kenarları etkiler
+    pub fn set_max_turn_angle(&mut self, max_turn_angle: Option<f32>) {
+        self.max_turn_angle = max_turn_angle;
+    }
+
+    // false ise planlayıcı düz RRT gibi çalışır: yeni düğümler en yakın düğüme bağlanır,
+    // ebeveyn seçimi ve yeniden bağlama yapılmaz
+    pub fn set_optimize(&mut self, optimize: bool) {
+        self.optimize = optimize;
+    }
+
+    // Düz RRT yerine RRT* olarak mı çalışıyor
+    pub fn optimize(&self) -> bool {
+        self.optimize
+    }
+
+    // Theta* tarzı her açılı ebeveyn seçimini açar ya da kapatır: yeni düğüm için ebeveyn
+    // seçildikten sonra ebeveynin ataları köke doğru tek tek denenir ve görüş hattı (engelsiz
+    // doğru parçası) olan en ucuz ataya bağlanılır. Yol `step_size` uzunluğundaki sıçramalar
+    // yerine engel köşelerinde kırılan az sayıda ara noktadan oluşur; her ata ayrı bir kenar
+    // denetimi gerektirdiğinden (tembel kipte de) denetim sayısı artar. Yalnızca doğrusal
+    // yönlendirmede ve RRT* kipinde etkilidir
+    pub fn set_any_angle(&mut self, any_angle: bool) {
+        self.any_angle = any_angle;
+    }
+
+    // Her açılı ebeveyn seçimi açık mı
+    pub fn any_angle(&self) -> bool {
+        self.any_angle
+    }
+
+    // Çarpışma nedeniyle reddedilen son noktalar, eskiden yeniye; planlayıcının çabasını nerede
+    // boşa harcadığını (örneğin dar geçitlerde) görmek için sınırlı bir halka arabellekte tutulur
+    pub fn recent_rejections(&self) -> &VecDeque<Point> {
+        &self.recent_rejections
+    }
+
+    // Reddedilen noktayı halka arabelleğe ekler; arabellek doluysa en eskisi düşer
+    fn record_rejection(&mut self, point: Point) {
+        if self.recent_rejections.len() == RECENT_REJECTIONS_CAPACITY {
+            self.recent_rejections.pop_front();
+        }
+        self.recent_rejections.push_back(point);
+    }
+
+    // Şu ana kadar yapılan yeniden bağlama sayısı
+    pub fn rewires(&self) -> usize {
+        self.rewires
+    }
+
+    // En iyi maliyetin her seçildiği iterasyon ve yeni maliyet, eskiden yeniye; maliyet iki
+    // kayıt arasında sabittir. Engel değişikliğiyle yol kaybedilip daha pahalı bir yol
+    // bulunursa maliyet artabilir
+    pub fn cost_history(&self) -> &[(usize, f32)] {
+        &self.cost_history
+    }
+
+    // İterasyon sınırını ayarlar
+    pub fn set_max_iterations(&mut self, max_iterations: usize) {
+        self.max_iterations = max_iterations;
+    }
+
+    // Şu ana kadar çalıştırılan iterasyon sayısı
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    // İterasyon bütçesi bittiyse, başlangıç zaten hedefteyse ya da durma koşulu sağlandıysa
+    // planlama bitmiştir
+    pub fn is_finished(&self) -> bool {
+        self.iterations >= self.max_iterations || self.best_goal_node == Some(0) || self.is_converged()
+    }
+
+    // Planlayıcının genel durumu
+    pub fn status(&self) -> PlanStatus {
+        if !self.is_finished() {
+            PlanStatus::Planning
+        } else if self.best_cost.is_finite() {
+            PlanStatus::SolutionFound { cost: self.best_cost }
+        } else {
+            PlanStatus::ExhaustedNoSolution
+        }
+    }
+
+    // Planlama bitene kadar iterasyon çalıştırır ve sonucu özetler. Son `stall_limit`
+    // iterasyonda hiç düğüm eklenemediyse (tüm örnekler reddedildiyse) harita muhtemelen
+    // geçilemezdir: çözüm yoksa `PlanError::Stuck` döner, çözüm varsa planlama erken biter.
+    // İterasyon sınırı çözümsüz dolarsa hedefe en yakın kısmi yolla `PlanError::NoPathFound` döner.
+    // Doluluk ızgarasında hedef başlangıca bağlı değilse hiç iterasyon yapmadan
+    // `PlanError::GoalUnreachable` döner
+    pub fn plan(&mut self) -> Result<PlanStats, PlanError> {
+        // Izgarada hedef başlangıçtan ayrılmışsa iterasyon bütçesi boşa harcanmaz
+        if !self.goal_reachable() {
+            return Err(PlanError::GoalUnreachable);
+        }
+        let mut since_last_node = 0;
+        while !self.is_finished() {
+            match self.step() {
+                StepOutcome::Added { .. } => since_last_node = 0,
+                // Düğüm sınırında ağaç artık değişmez
+                StepOutcome::NodeBudgetExhausted => break,
+                _ => since_last_node += 1,
+            }
+
+            if self.stall_limit > 0 && since_last_node >= self.stall_limit {
+                if !self.best_cost.is_finite() {
+                    return Err(PlanError::Stuck { iterations: self.iterations, nodes: self.nodes.len() });
+                }
+                break;
+            }
+        }
+        if !self.best_cost.is_finite() {
+            return Err(PlanError::NoPathFound {
+                iterations: self.iterations,
+                nodes: self.nodes.len(),
+                best_partial: self.closest_partial_path(),
+            });
+        }
+        let path = self.best_path.to_vec();
+        // Uzunluk ve engel mesafesi eğrisel yollarda yayların kendisi üzerinden ölçülür
+        let traced = self.best_path_polyline();
+        Ok(PlanStats {
+            iterations: self.iterations,
+            nodes: self.nodes.len(),
+            best_cost: self.best_cost,
+            path_length: path_length(&traced),
+            min_clearance: self.min_clearance(&traced),
+            rewires: self.rewires,
+            collision_checks: self.collision_checks.get(),
+            skipped_collision_checks: self.skipped_collision_checks.get(),
+            self_intersects: path_self_intersects(&traced),
+            reached_goal: self.reached_goal().expect("çözüm varsa en iyi hedef düğümü vardır"),
+            path,
+        })
+    }
+
+    // Yeniden başlatmalı planlama: her tohumla ağaç `reset` ile sıfırlanıp yeniden planlanır ve
+    // ilk başarılı çalışmanın yolu döner. Zor haritalarda tek bir çalışmanın başarısız olduğu
+    // durumlarda başarı oranını artırır; i'nci denemenin iterasyon bütçesi `per_run_iters * i`
+    // olarak artar. Başarılı tohum `restart_seed` ile okunur; hiçbir tohum başarılı olmazsa
+    // ya da hedef ızgarada ulaşılamazsa None döner. İterasyon sınırı sonunda eski değerine döner
+    pub fn plan_with_restarts(&mut self, seeds: &[u64], per_run_iters: usize) -> Option<Vec<Point>> {
+        let start = self.nodes[0].point;
+        let max_iterations = self.max_iterations;
+        self.restart_seed = None;
+        let mut path = None;
+        for (attempt, &seed) in seeds.iter().enumerate() {
+            self.set_seed(seed);
+            self.reset(start);
+            self.max_iterations = per_run_iters.saturating_mul(attempt + 1);
+            match self.plan() {
+                Ok(stats) => {
+                    self.restart_seed = Some(seed);
+                    path = Some(stats.path);
+                    break;
+                }
+                Err(PlanError::GoalUnreachable) => break,
+                Err(_) => {}
+            }
+        }
+        self.max_iterations = max_iterations;
+        path
+    }
+
+    // `plan_with_restarts` çağrısında çözüm bulan tohum (çağrılmadıysa ya da başarısızsa None)
+    pub fn restart_seed(&self) -> Option<u64> {
+        self.restart_seed
+    }
+
+    // Rastgele bir nokta oluşturan fonksiyon
+    pub fn random_point(&mut self, min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> Point {
+        let x = self.rng.gen_range(min_x..max_x);
+        let y = self.rng.gen_range(min_y..max_y);
+        Point { x, y }
+    }
+
+    // Verilen bir noktaya en yakın düğümün indeksini bulur; ağaç boşsa None döner.
+    // Karekök almamak için kare mesafeler karşılaştırılır, NaN mesafeler en uzak sayılır;
+    // eşit uzaklıktaki düğümler arasında `NearestTieBreak` kuralı uygulanır. Sonuç, düğümlerin
+    // taranma sırasına bağlı değildir. Engelli düğümler atlanır; hepsi engelliyse None döner
+    pub fn find_nearest(&self, point: &Point) -> Option<usize> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !node.blocked)
+            .map(|(index, node)| {
+                let key = node.point.distance_squared(point);
+                (index, if key.is_nan() { f32::INFINITY } else { key })
+            })
+            .min_by(|&(i, a), &(j, b)| {
+                let tie = match self.nearest_tie_break {
+                    NearestTieBreak::LowestIndex => std::cmp::Ordering::Equal,
+                    NearestTieBreak::LowestCost => self.nodes[i].cost.total_cmp(&self.nodes[j].cost),
+                };
+                a.total_cmp(&b).then(tie).then(i.cmp(&j))
+            })
+            .map(|(index, _)| index)
+    }
+
+    // Bir noktadan diğerine belirli bir açıyla en fazla adım boyutu kadar ilerleyen fonksiyon.
+    // Hedef nokta adım boyutundan yakınsa (sıfır mesafe dahil) doğrudan hedefin kendisi döner
+    pub fn steer(&self, from: &Point, to: &Point) -> Point {
+        let step = self.step_length(from);
+        if from.distance(to) <= step {
+            return *to;
+        }
+        let angle = (to.y - from.y).atan2(to.x - from.x);
+        Point {
+            x: from.x + step * angle.cos(),
+            y: from.y + step * angle.sin(),
+        }
+    }
+
+    // Verilen noktadan atılacak adımın uzunluğu (bkz. `StepMode`)
+    pub fn step_length(&self, from: &Point) -> f32 {
+        match self.step_mode {
+            StepMode::Fixed => self.step_size,
+            StepMode::ClearanceScaled { min_step, scale } => (scale * self.clearance(from)).clamp(min_step, self.step_size),
+        }
+    }
+
+    // Noktanın hiçbir engelin içinde olmadığını kontrol eder
+    pub fn is_collision_free(&self, point: &Point) -> bool {
+        self.map_free(point) && self.collision_checker.as_ref().is_none_or(|checker| checker.is_free(point))
+    }
+
+    // Nokta geometrik engellerin ve doluluk ızgarasının dışında mı (özel denetleyici hariç)
+    fn map_free(&self, point: &Point) -> bool {
+        self.obstacles.iter().all(|obstacle| !obstacle.contains(point))
+            && self.occupancy_grid.as_ref().is_none_or(|grid| !grid.contains(point))
+    }
+
+    // Sınırlar içinden `samples` tekdüze örnek çekerek haritanın engelsiz kısmının oranını
+    // (0..=1) Monte Carlo yöntemiyle tahmin eder; haritanın zorluğunu planlama süreleriyle
+    // birlikte raporlamak için. Örnek sayısı 0 ise 0 döner
+    pub fn estimate_free_ratio(&self, samples: usize) -> f32 {
+        if samples == 0 {
+            return 0.0;
+        }
+        let mut rng = StdRng::seed_from_u64(FREE_RATIO_SEED);
+        let Bounds { min_x, max_x, min_y, max_y } = self.bounds;
+        let free = (0..samples)
+            .filter(|_| {
+                let point = Point { x: rng.gen_range(min_x..=max_x), y: rng.gen_range(min_y..=max_y) };
+                self.is_collision_free(&point)
+            })
+            .count();
+        free as f32 / samples as f32
+    }
+
+    // Noktanın en yakın engele olan mesafesi (engel yoksa sonsuz)
+    pub fn clearance(&self, point: &Point) -> f32 {
+        let grid_clearance = self.occupancy_grid.as_ref().map_or(f32::INFINITY, |grid| grid.distance(point));
+        self.obstacles
+            .iter()
+            .map(|obstacle| obstacle.distance(point))
+            .fold(grid_clearance, f32::min)
+    }
+
+    // Yolun herhangi bir parçasının herhangi bir engele olan en küçük mesafesi; güvenlik
+    // payını doğrulamak ve yumuşatılmış/ham yolları karşılaştırmak için (engel yoksa sonsuz).
+    // Doluluk ızgarasına olan mesafe parça üzerinde hücre boyutu aralıklarla örneklenir
+    pub fn min_clearance(&self, path: &[Point]) -> f32 {
+        let segment_clearance = |a: &Point, b: &Point| {
+            let grid_clearance = self.occupancy_grid.as_ref().map_or(f32::INFINITY, |grid| {
+                let samples = (a.distance(b) / grid.resolution()).ceil().max(1.0) as usize;
+                (0..=samples)
+                    .map(|i| grid.distance(&a.lerp(b, i as f32 / samples as f32)))
+                    .fold(f32::INFINITY, f32::min)
+            });
+            self.obstacles
+                .iter()
+                .map(|obstacle| obstacle.segment_distance(a, b))
+                .fold(grid_clearance, f32::min)
+        };
+        match path {
+            [] => f32::INFINITY,
+            [point] => self.clearance(point),
+            _ => path
+                .windows(2)
+                .map(|segment| segment_clearance(&segment[0], &segment[1]))
+                .fold(f32::INFINITY, f32::min),
+        }
+    }
+
+    // İki nokta arasındaki doğru parçasını eşit aralıklarla örnekleyerek çarpışma kontrolü yapar;
+    // doluluk ızgarasında ise parçanın geçtiği tüm hücreler denetlenir. Özel denetleyici
+    // bağlanmışsa parça ayrıca onun `edge_free` yöntemine sorulur
+    pub fn is_edge_collision_free(&self, from: &Point, to: &Point) -> bool {
+        if self.occupancy_grid.as_ref().is_some_and(|grid| grid.segment_intersects(from, to)) {
+            return false;
+        }
+        if self.collision_checker.as_ref().is_some_and(|checker| !checker.edge_free(from, to)) {
+            return false;
+        }
+        let checks = (from.distance(to) / self.edge_check_spacing()).ceil().max(1.0) as usize;
+        (0..=checks).all(|i| self.map_free(&from.lerp(to, i as f32 / checks as f32)))
+    }
+
+    // İki nokta arasındaki kenarın maliyeti: uzunluk, geçtiği maliyet bölgelerinin çarpanıyla
+    // ağırlıklandırılır. Kenar adım boyutunun dörtte biri aralıklarla örneklenir ve her parçaya
+    // orta noktasını içeren bölgelerin en büyük çarpanı uygulanır
+    pub fn edge_cost(&self, from: &Point, to: &Point) -> f32 {
+        let length = from.distance(to);
+        if self.cost_regions.is_empty() {
+            return length;
+        }
+        let pieces = (length / self.cost_sample_spacing()).ceil().max(1.0) as usize;
+        let piece_length = length / pieces as f32;
+        (0..pieces)
+            .map(|i| piece_length * self.cost_multiplier(&from.lerp(to, (i as f32 + 0.5) / pieces as f32)))
+            .sum()
+    }
+
+    // Noktayı içeren maliyet bölgelerinin en büyük çarpanı (bölge yoksa 1)
+    fn cost_multiplier(&self, point: &Point) -> f32 {
+        self.cost_regions
+            .iter()
+            .filter(|(region, _)| region.contains(point))
+            .map(|&(_, multiplier)| multiplier)
+            .fold(1.0, f32::max)
+    }
+
+    // Kenar çarpışma kontrolünde ara örnekler arasındaki mesafe
+    fn edge_check_spacing(&self) -> f32 {
+        if self.collision_resolution > 0.0 {
+            self.collision_resolution
+        } else {
+            self.step_size * EDGE_CHECK_FRACTION
+        }
+    }
+
+    // Kenar maliyeti hesabında ara örnekler arasındaki mesafe; saklanan maliyetlerin çarpışma
+    // kontrolü aralığı değiştirildiğinde de geçerli kalması için yalnızca adım boyutuna bağlıdır
+    fn cost_sample_spacing(&self) -> f32 {
+        self.step_size * EDGE_CHECK_FRACTION
+    }
+
+    // Yeni nokta için, verilen komşular (bkz. `near_point`) arasından noktaya engelsiz ve en düşük
+    // maliyetle ulaşan ebeveyni seçer; en yakın düğümün kenarı önceden doğrulanmış olmalıdır.
+    // Eşit maliyetli adaylar arasında, sonucun komşu tarama sırasına bağlı kalmaması için
+    // indeksi küçük olan düğüm seçilir. `max_turn_angle` ayarlıysa gelen kenarıyla yeni kenar
+    // arasındaki dönüş sınırı aşan adaylar elenir; hiçbir aday kalmazsa None döner. Noktaya
+    // en yakın düğümden gelindiğindeki yönle varılır (bkz. `steer_from`)
+    pub fn choose_parent(&self, point: &Point, nearest_index: usize, neighbors: &[usize]) -> Option<usize> {
+        let heading = self.arrival_heading(nearest_index, point);
+        self.choose_parent_for(point, heading, nearest_index, neighbors)
+    }
+
+    // `choose_parent` ile aynı, ancak noktaya varılan yön verilmiştir
+    // Adaylar en ucuzdan başlayarak denenir ve engelsiz bağlanabilen ilkinde durulur; böylece
+    // çarpışma denetimi yalnızca gerektiği kadar yapılır. En yakın düğüm için kenar zaten
+    // denetlenmiştir
+    fn choose_parent_for(&self, point: &Point, heading: f32, nearest_index: usize, neighbors: &[usize]) -> Option<usize> {
+        let candidates = std::iter::once(nearest_index).chain(neighbors.iter().copied());
+        let (parent, cost) = self
+            .sorted_by_cost(point, |_| heading, candidates)
+            .into_iter()
+            .find(|&(index, _)| {
+                let node = &self.nodes[index];
+                self.turn_allowed(index, point)
+                    && (index == nearest_index || self.edge_admissible(&node.point, node.heading, point, heading))
+            })?;
+        if !self.any_angle || self.steering != Steering::Straight {
+            return Some(parent);
+        }
+        Some(self.visible_ancestor(parent, cost, point, heading))
+    }
+
+    // Her açılı ebeveyn seçimi: ebeveynin atalarından noktaya görüş hattı kesilmeden
+    // ulaşılanların en ucuzu (daha ucuzu yoksa ebeveynin kendisi). Atalar köke doğru denenir
+    // ve görüş hattı kesilen ya da dönüş açısını aşan ilk atada durulur; tembel kipte de
+    // kenarlar tam denetlenir
+    fn visible_ancestor(&self, parent: usize, parent_cost: f32, point: &Point, heading: f32) -> usize {
+        let (mut best, mut best_cost) = (parent, parent_cost);
+        let mut current = parent;
+        while let Some(ancestor) = self.nodes[current].parent {
+            let node = &self.nodes[ancestor];
+            if !self.turn_allowed(ancestor, point) || !self.transition_free(&node.point, node.heading, point, heading) {
+                break;
+            }
+            let cost = node.cost + self.transition_cost(&node.point, node.heading, point, heading);
+            if cost < best_cost {
+                (best, best_cost) = (ancestor, cost);
+            }
+            current = ancestor;
+        }
+        best
+    }
+
+    // Noktanın yakınlık yarıçapı içindeki düğümleri, noktaya o düğümden bağlanıldığında
+    // oluşacak maliyetle (`cost + edge`) birlikte artan maliyet sırasıyla döndürür; eşit
+    // maliyetlerde küçük indeks önce gelir. Noktaya her düğümden kiriş doğrultusunda varıldığı
+    // varsayılır; çarpışma ve dönüş açısı denetlenmez
+    pub fn near_sorted(&self, point: &Point) -> Vec<(usize, f32)> {
+        let neighbors = self.near_point(point, self.search_radius());
+        self.sorted_by_cost(point, |index| self.arrival_heading(index, point), neighbors.into_iter())
+    }
+
+    // Adayları, noktaya verilen yönle varıldığında oluşacak maliyete göre sıralar; yinelenen
+    // adaylar bir kez yer alır
+    fn sorted_by_cost(
+        &self,
+        point: &Point,
+        heading: impl Fn(usize) -> f32,
+        candidates: impl Iterator<Item = usize>,
+    ) -> Vec<(usize, f32)> {
+        let mut sorted: Vec<(usize, f32)> = candidates
+            .map(|index| {
+                let node = &self.nodes[index];
+                (index, node.cost + self.transition_cost(&node.point, node.heading, point, heading(index)))
+            })
+            .collect();
+        sorted.sort_by(|(a, a_cost), (b, b_cost)| a_cost.total_cmp(b_cost).then(a.cmp(b)));
+        sorted.dedup_by_key(|&mut (index, _)| index);
+        sorted
+    }
+
+    // Verilen noktaya bu ebeveynden bağlanırken ebeveynin gelen kenarıyla yapılan dönüş açısı
+    // `max_turn_angle` içinde mi; kökün gelen kenarı olmadığından kökten her yöne dönülebilir
+    fn turn_allowed(&self, parent_index: usize, point: &Point) -> bool {
+        let (Some(max_turn_angle), Some(grandparent)) = (self.max_turn_angle, self.nodes[parent_index].parent) else {
+            return true;
+        };
+        turn_angle(&self.nodes[grandparent].point, &self.nodes[parent_index].point, point) <= max_turn_angle
+    }
+
+    // Yeni bir düğüm ekler ve bu düğüm için maliyet hesaplar; düğüme ebeveynden kiriş
+    // doğrultusunda varılır
+    pub fn add_node(&mut self, point: Point, parent_index: usize) -> usize {
+        let heading = self.arrival_heading(parent_index, &point);
+        self.add_node_with_heading(point, heading, parent_index)
+    }
+
+    // `add_node` ile aynı, ancak düğümdeki yön verilmiştir
+    fn add_node_with_heading(&mut self, point: Point, heading: f32, parent_index: usize) -> usize {
+        let parent = &self.nodes[parent_index];
+        let cost = parent.cost + self.transition_cost(&parent.point, parent.heading, &point, heading);
+        let new_node = Node::new(point, heading, Some(parent_index), cost);
+        self.nodes.push(new_node);
+        let index = self.nodes.len() - 1;
+        self.nodes[parent_index].children.push(index);
+        index
+    }
+
+    // Düğümü yeni ebeveynine taşır ve maliyet değişimini tüm alt ağacına yayar
+    fn set_parent(&mut self, index: usize, new_parent: usize, cost: f32) {
+        if let Some(old_parent) = self.nodes[index].parent {
+            self.nodes[old_parent].children.retain(|&child| child != index);
+        }
+        self.nodes[new_parent].children.push(index);
+        self.nodes[index].parent = Some(new_parent);
+        self.nodes[index].cost = cost;
+        // Eğrisel yönlendirmelerde yön durumun parçasıdır; doğrusalda yeni kenarın doğrultusudur
+        if self.steering == Steering::Straight {
+            self.nodes[index].heading = self.arrival_heading(new_parent, &self.nodes[index].point);
+        }
+        self.nodes[index].edge_checked = true;
+        self.propagate_cost(index);
+    }
+
+    // Komşu yeni düğüme bağlandığında hem yeni düğümdeki hem de komşudan çocuklarına geçişteki
+    // dönüşler `max_turn_angle` içinde kalıyor mu
+    fn rewire_turns_allowed(&self, new_node_index: usize, neighbor_index: usize) -> bool {
+        let Some(max_turn_angle) = self.max_turn_angle else {
+            return true;
+        };
+        let new_point = &self.nodes[new_node_index].point;
+        let neighbor = &self.nodes[neighbor_index];
+        self.turn_allowed(new_node_index, &neighbor.point)
+            && neighbor
+                .children
+                .iter()
+                .all(|&child| turn_angle(new_point, &neighbor.point, &self.nodes[child].point) <= max_turn_angle)
+    }
+
+    // 'ancestor' düğümünün 'index' düğümünün (kendisi dahil) atası olup olmadığı
+    fn is_ancestor(&self, ancestor: usize, index: usize) -> bool {
+        let mut current = Some(index);
+        for _ in 0..=self.nodes.len() {
+            match current {
+                Some(node) if node == ancestor => return true,
+                Some(node) => current = self.nodes[node].parent,
+                None => return false,
+            }
+        }
+        // Döngülü bir zincirde güvenli tarafta kal
+        true
+    }
+
+    // Düğümün maliyeti değiştiğinde torunlarının maliyetlerini yeniden hesaplar
+    fn propagate_cost(&mut self, index: usize) {
+        let mut stack = vec![index];
+        while let Some(current) = stack.pop() {
+            for i in 0..self.nodes[current].children.len() {
+                let child = self.nodes[current].children[i];
+                let edge = self.node_edge_cost(current, child);
+                self.nodes[child].cost = self.nodes[current].cost + edge;
+                stack.push(child);
+            }
+        }
+    }
+
+    // Yeni eklenen düğümün yakınında olan düğümlerin indekslerini döndürür
+    #[deprecated(note = "use `near_point` with the candidate point before inserting it")]
+    pub fn near(&self, new_node_index: usize) -> Vec<usize> {
+        self.near_except(new_node_index)
+    }
+
+    // Düğümün kendisi hariç yakınlık yarıçapındaki düğümler
+    fn near_except(&self, index: usize) -> Vec<usize> {
+        let mut neighbors = self.near_point(&self.nodes[index].point, self.search_radius());
+        neighbors.retain(|&i| i != index);
+        neighbors
+    }
+
+    // Noktaya `radius` mesafesinden yakın düğümlerin indeksleri (artan sırada). Aday nokta
+    // ağaca eklenmeden önce çağrılabilir; ebeveyn seçimi ve yeniden bağlama aynı kümeyi kullanır
+    pub fn near_point(&self, point: &Point, radius: f32) -> Vec<usize> {
+        self.neighbor_queries.set(self.neighbor_queries.get() + 1);
+        let radius_squared = radius * radius;
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.point.distance_squared(point) < radius_squared)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    // Daha kısa maliyetli yollar bulunursa düğümleri yeniden bağlar; maliyet düşüşü
+    // yeniden bağlanan düğümün alt ağacına yayılır ve en iyi hedef düğümü tazelenir
+    pub fn rewire(&mut self, new_node_index: usize) {
+        let neighbors = self.near_except(new_node_index);
+        self.rewire_neighbors(new_node_index, &neighbors);
+    }
+
+    // `rewire` ile aynı, ancak komşular önceden hesaplanmıştır (yeni düğümü içermemelidir)
+    fn rewire_neighbors(&mut self, new_node_index: usize, neighbors: &[usize]) {
+        let new_point = self.nodes[new_node_index].point;
+        let new_heading = self.nodes[new_node_index].heading;
+        let new_node_cost = self.nodes[new_node_index].cost;
+        let mut rewired = false;
+
+        for &neighbor_index in neighbors {
+            let neighbor = &self.nodes[neighbor_index];
+            let new_cost = new_node_cost + self.transition_cost(&new_point, new_heading, &neighbor.point, neighbor.heading);
+
+            // Eğer yeni maliyet mevcut maliyetten eşik kadardan fazla düşükse ve kenar engelsizse,
+            // düğümü yeniden bağla. Komşu yeni düğümün bir atasıysa bağlamak ebeveyn döngüsü
+            // oluşturacağından atlanır.
+            // Eşit maliyette mevcut ebeveyn korunur: yeni düğümün indeksi her zaman en büyüktür,
+            // bu da `choose_parent`teki küçük indeks tercihiyle tutarlıdır
+            if neighbor.cost - new_cost > self.rewire_improvement_threshold
+                && !self.is_ancestor(neighbor_index, new_node_index)
+                && self.rewire_turns_allowed(new_node_index, neighbor_index)
+                && self.edge_admissible(&new_point, new_heading, &neighbor.point, neighbor.heading)
+            {
+                self.set_parent(neighbor_index, new_node_index, new_cost);
+                self.defer_edge_check(neighbor_index);
+                self.rewires += 1;
+                rewired = true;
+            }
+        }
+
+        if rewired {
+            self.select_best_goal();
+        }
+
+        #[cfg(debug_assertions)]
+        if let Err(error) = self.verify_tree() {
+            panic!("tree invariant violated after rewire: {error}");
+        }
+    }
+
+    // Ebeveynine olan kenarı artık bir engelle çarpışan düğümleri ve bunların alt ağaçlarını
+    // siler; kök düğüm her zaman korunur. Silinen düğüm sayısını döner
+    pub fn invalidate(&mut self) -> usize {
+        let removed = self.mark_subtrees(|tree, index| {
+            let parent = tree.nodes[index].parent.expect("kök dışındaki düğümlerin ebeveyni vardır");
+            !tree.node_edge_free(parent, index)
+        });
+        let count = removed.iter().filter(|&&r| r).count();
+        if count > 0 {
+            self.remove_nodes(&removed);
+        }
+        count
+    }
+
+    // Engeller değiştikten sonra ağacı sıfırlamadan onarır (DRRT tarzı): ebeveyn kenarı artık
+    // çarpışan ya da kendisi engelin içinde kalan düğümlerin kenarları kesilir ve alt ağaçları
+    // yetim olarak işaretlenir. Her yetim alt ağacın kökü, yakınlık yarıçapındaki geçerli ağaç
+    // düğümlerinden engelsiz bağlanabilen en ucuzuna taşınır; bir bağlantı yeni adaylar
+    // açabileceğinden bağlanamayan kökler ilerleme kalmayana kadar yeniden denenir. Engelin
+    // içinde kalan düğümler silinir ve çocukları yetim kök olur; bağlanamayan alt ağaçlar en
+    // sonda silinir. Örnekleme ardından `step` ile normal şekilde sürer. Çift integratör
+    // yönlendirmesinde kenarlar yeniden kurulamadığından `invalidate` gibi davranır
+    pub fn repair(&mut self) -> RepairStats {
+        self.update_blocked();
+        // Tembel doğrulamanın bekleyen kenarları da aşağıda denetlenir
+        self.invalid_edges.clear();
+        let cut: Vec<bool> = (0..self.nodes.len()).map(|index| self.edge_severed(index)).collect();
+        let mut orphaned = self.mark_subtrees(|_, index| cut[index]);
+        let mut removed = vec![false; self.nodes.len()];
+        let mut roots: Vec<usize> = (0..self.nodes.len()).filter(|&index| cut[index]).collect();
+        let mut stats = RepairStats { severed: roots.len(), ..RepairStats::default() };
+
+        let mut progress = self.double_integrator().is_none();
+        while progress {
+            progress = false;
+            for root in std::mem::take(&mut roots) {
+                if !orphaned[root] || removed[root] {
+                    continue;
+                }
+                if self.nodes[root].blocked {
+                    removed[root] = true;
+                    roots.extend_from_slice(&self.nodes[root].children);
+                    progress = true;
+                    continue;
+                }
+                match self.cheapest_free_parent(root, |candidate| !orphaned[candidate] && !removed[candidate]) {
+                    Some((parent, cost)) => {
+                        self.set_parent(root, parent, cost);
+                        // Alt ağaç, kendi kenarı kesilmiş düğümlere kadar geçerli ağaca döner
+                        let mut stack = vec![root];
+                        while let Some(index) = stack.pop() {
+                            orphaned[index] = false;
+                            stack.extend(self.nodes[index].children.iter().filter(|&&child| !cut[child]));
+                        }
+                        stats.reattached += 1;
+                        progress = true;
+                    }
+                    None => roots.push(root),
+                }
+            }
+        }
+
+        let doomed: Vec<bool> = orphaned.iter().zip(&removed).map(|(&orphan, &removed)| orphan || removed).collect();
+        stats.removed = doomed.iter().filter(|&&d| d).count();
+        if stats.removed > 0 {
+            self.remove_nodes(&doomed);
+        } else if stats.reattached > 0 {
+            // Yeniden bağlanan dallarda maliyetler artmış olabilir
+            self.reselect_best_goal();
+        }
+
+        #[cfg(debug_assertions)]
+        if let Err(error) = self.verify_tree() {
+            panic!("tree invariant violated after repair: {error}");
+        }
+        stats
+    }
+
+    // Çocuğu olmayan düğümler arasından `cost + heuristic` değeri en yüksek olanı siler; kök ve
+    // en iyi hedef düğümü hiçbir zaman silinmez. En iyi yol üzerindeki diğer düğümlerin yolda
+    // bir çocuğu olduğundan yol korunur. Silinecek yaprak yoksa 'false' döner
+    fn forget_worst_leaf(&mut self) -> bool {
+        let scale = self.cost_scale();
+        let worst = (1..self.nodes.len())
+            .filter(|&index| self.nodes[index].children.is_empty() && self.best_goal_node != Some(index))
+            .map(|index| (index, self.nodes[index].cost + self.heuristic(index) / scale))
+            .max_by(|(a, a_cost), (b, b_cost)| a_cost.total_cmp(b_cost).then(b.cmp(a)));
+        match worst {
+            Some((index, _)) => {
+                self.remove_leaf(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Yaprak düğümü ağaçtan çıkarır: boşalan indekse son düğüm taşınır ve ona olan başvurular
+    // güncellenir. `remove_nodes`ın aksine yalnızca taşınan düğümün komşuluğuna dokunur ve en
+    // iyi yolu yeniden seçmez
+    fn remove_leaf(&mut self, index: usize) {
+        let parent = self.nodes[index].parent.expect("kök silinemez");
+        debug_assert!(self.nodes[index].children.is_empty(), "yalnızca yapraklar silinebilir");
+        self.nodes[parent].children.retain(|&child| child != index);
+        self.goal_nodes.retain(|&goal_node| goal_node != index);
+        self.invalid_edges.retain(|&invalid| invalid != index);
+        self.exact_goal_nodes_mut().filter(|node| **node == Some(index)).for_each(|node| *node = None);
+
+        let last = self.nodes.len() - 1;
+        self.nodes.swap_remove(index);
+        if index == last {
+            return;
+        }
+        let moved = |i: &mut usize| {
+            if *i == last {
+                *i = index;
+            }
+        };
+        if let Some(parent) = self.nodes[index].parent {
+            self.nodes[parent].children.iter_mut().for_each(moved);
+        }
+        for i in 0..self.nodes[index].children.len() {
+            let child = self.nodes[index].children[i];
+            self.nodes[child].parent = Some(index);
+        }
+        self.goal_nodes.iter_mut().for_each(moved);
+        self.invalid_edges.iter_mut().for_each(moved);
+        self.best_path_nodes.iter_mut().for_each(moved);
+        self.exact_goal_nodes_mut().flatten().for_each(moved);
+        if let Some(best_goal_node) = self.best_goal_node.as_mut() {
+            moved(best_goal_node);
+        }
+    }
+
+    // Kök dışındaki düğüm engelin içinde mi ya da ebeveyn kenarı çarpışıyor mu
+    fn edge_severed(&self, index: usize) -> bool {
+        match self.nodes[index].parent {
+            Some(parent) => self.nodes[index].blocked || !self.node_edge_free(parent, index),
+            None => false,
+        }
+    }
+
+    // Dal ve sınır budaması: `cost + heuristic` değeri en iyi yolun sınırını aşan düğümler
+    // daha iyi bir yola asla katkı veremez ve yalnızca en yakın/komşu aramalarını yavaşlatır;
+    // bu düğümler alt ağaçlarıyla birlikte silinir. Kenar maliyetleri düz çizgi mesafesinden
+    // küçük olmadığından alt ağaçtaki düğümler de sınırı aşar, en iyi yol üzerindeki düğümler
+    // ise hiçbir zaman aşmaz. Sınır, yol hedefte bitmiyorsa kalan mesafeyi de içerir; maliyet
+    // süreyse mesafe en büyük hıza bölünür (bkz. `cost_scale`).
+    // Silinen düğüm sayısını döner; yol yoksa hiçbir şey yapmaz
+    pub fn prune(&mut self) -> usize {
+        let Some(best_goal_node) = self.best_goal_node else {
+            return 0;
+        };
+        let scale = self.cost_scale();
+        let bound = self.best_cost + self.heuristic(best_goal_node) / scale;
+        // Artımlı maliyetlerdeki yuvarlama farkları yol üzerindeki düğümleri silmesin
+        let tolerance = COST_EPSILON * bound.max(1.0);
+        let removed = self.mark_subtrees(|tree, index| tree.nodes[index].cost + tree.heuristic(index) / scale > bound + tolerance);
+        let count = removed.iter().filter(|&&r| r).count();
+        if count > 0 {
+            self.remove_nodes(&removed);
+        }
+        count
+    }
+
+    // Yeniden köklemede robotun arkasındaki düğümlerin silinme mesafesini ayarlar (0 = kapalı)
+    pub fn set_reroot_prune_distance(&mut self, distance: f32) {
+        self.reroot_prune_distance = distance;
+    }
+
+    // Ağacı robotun şu anki konumunda yeniden kökler (ilerleyen ufuklu yürütme için): konuma
+    // en yakın engelsiz düğüm maliyeti 0 olan yeni kök olur, eski kökten ona uzanan zincirdeki
+    // ebeveyn bağları ters çevrilir ve maliyetler kökten dışa doğru yeniden hesaplanır. Kök
+    // her zaman 0 indeksinde tutulduğundan yeni kökle eski kökün indeksleri yer değiştirir.
+    // Ardından `set_reroot_prune_distance` ile verilen mesafeden uzak ve robotun arkasında
+    // (hedefe göre ters yönde) kalan düğümler alt ağaçlarıyla silinir ve en iyi yol sıfırdan
+    // seçilir. Ters çevrilen kenarlar aynı doğru parçaları olduğundan engelsiz kalır; dönüş
+    // açısı sınırı yeniden denetlenmez. Eğrisel ve kinodinamik kenarlar tersine çevrilemediğinden
+    // yalnızca doğrusal yönlendirmede çalışır, diğerlerinde hiçbir şey yapmaz.
+    // Silinen düğüm sayısını döner
+    pub fn reroot_at(&mut self, pose: Point) -> usize {
+        if self.steering != Steering::Straight {
+            return 0;
+        }
+        let Some(new_root) = self.find_nearest(&pose) else {
+            return 0;
+        };
+
+        // Yeni kökten eski köke zincir; her kenarın yönü ve denetim durumu ebeveyne geçer
+        let mut chain = vec![new_root];
+        while let Some(parent) = self.nodes[chain[chain.len() - 1]].parent {
+            chain.push(parent);
+        }
+        let checked: Vec<bool> = chain.iter().map(|&index| self.nodes[index].edge_checked).collect();
+        for (k, pair) in chain.windows(2).enumerate() {
+            let (child, parent) = (pair[0], pair[1]);
+            self.nodes[parent].children.retain(|&c| c != child);
+            self.nodes[child].children.push(parent);
+            self.nodes[parent].parent = Some(child);
+            self.nodes[parent].edge_checked = checked[k];
+            self.nodes[parent].heading = self.arrival_heading(child, &self.nodes[parent].point);
+        }
+        self.nodes[new_root].parent = None;
+        self.nodes[new_root].edge_checked = true;
+        self.swap_nodes(0, new_root);
+        self.nodes[0].cost = 0.0;
+        self.propagate_cost(0);
+
+        let goal = self.goal;
+        let distance = self.reroot_prune_distance;
+        let removed = self.mark_subtrees(|tree, index| {
+            let point = tree.nodes[index].point;
+            let behind = (point.x - pose.x) * (goal.x - pose.x) + (point.y - pose.y) * (goal.y - pose.y) < 0.0;
+            distance > 0.0 && behind && point.distance(&pose) > distance
+        });
+        let count = removed.iter().filter(|&&r| r).count();
+        if count > 0 {
+            self.remove_nodes(&removed);
+        } else {
+            self.reselect_best_goal();
+        }
+        #[cfg(debug_assertions)]
+        if let Err(error) = self.verify_tree() {
+            panic!("tree invariant violated after reroot: {error}");
+        }
+        count
+    }
+
+    // Önceki bir planlamadan kalan yolu (ör. haritadaki küçük bir değişiklikten sonra yeniden
+    // planlarken) ağaca birbirine bağlı bir düğüm zinciri olarak ekler; planlayıcı böylece
+    // hemen iyileştirebileceği bir çözümle başlar. Zincir kökten başlar (köke çakışan noktalar
+    // atlanır) ve adım boyutundan uzun parçalar adım boyutu aralıklarla bölünür. Her kenar
+    // eklenmeden önce denetlenir; ilk çarpışan kenarda, sınır dışı bir noktada, izin verilmeyen
+    // bir dönüşte ya da düğüm sınırında durulur ve o ana kadar eklenen kısım korunur. Zincir
+    // eklenirken yeniden bağlanmaz, sonraki iterasyonlar onu diğer düğümler gibi iyileştirir.
+    // Yolun kenarları doğru parçaları olduğundan yalnızca doğrusal yönlendirmede çalışır,
+    // diğerlerinde hiçbir şey yapmaz. Eklenen düğüm sayısını döner
+    pub fn seed_from_path(&mut self, path: &[Point]) -> usize {
+        if self.steering != Steering::Straight {
+            return 0;
+        }
+        let mut current = 0;
+        let mut added = 0;
+        'waypoints: for waypoint in path {
+            let from = self.nodes[current].point;
+            let distance = from.distance(waypoint);
+            if distance == 0.0 {
+                continue;
+            }
+            let pieces = (distance / self.step_size).ceil().max(1.0) as usize;
+            for piece in 1..=pieces {
+                let next = from.lerp(waypoint, piece as f32 / pieces as f32);
+                let heading = self.arrival_heading(current, &next);
+                let node = &self.nodes[current];
+                if !next.is_finite()
+                    || !self.bounds.contains(&next)
+                    || (self.max_nodes > 0 && self.nodes.len() >= self.max_nodes)
+                    || !self.turn_allowed(current, &next)
+                    || !self.transition_free(&node.point, node.heading, &next, heading)
+                {
+                    break 'waypoints;
+                }
+                current = self.add_node_with_heading(next, heading, current);
+                self.register_goal_candidate(current);
+                added += 1;
+            }
+        }
+        if added > 0 {
+            self.select_best_goal();
+        }
+        #[cfg(debug_assertions)]
+        if let Err(error) = self.verify_tree() {
+            panic!("tree invariant violated after seeding from path: {error}");
+        }
+        added
+    }
+
+    // İki düğümün indekslerini yer değiştirir; tüm ebeveyn, çocuk ve hedef referansları güncellenir
+    fn swap_nodes(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        self.nodes.swap(a, b);
+        let swapped = |i: &mut usize| {
+            if *i == a {
+                *i = b;
+            } else if *i == b {
+                *i = a;
+            }
+        };
+        for node in &mut self.nodes {
+            node.parent.iter_mut().for_each(swapped);
+            node.children.iter_mut().for_each(swapped);
+        }
+        self.goal_nodes.iter_mut().for_each(swapped);
+        self.invalid_edges.iter_mut().for_each(swapped);
+        self.best_path_nodes.iter_mut().for_each(swapped);
+        self.exact_goal_nodes_mut().flatten().for_each(swapped);
+        self.best_goal_node.iter_mut().for_each(swapped);
+    }
+
+    // Tüm kenarları "kontrol edilmedi" olarak işaretler. Engeller sık hareket ettiğinde
+    // her karede `invalidate` çağırmak yerine kenarlar yalnızca en iyi yol izlenirken
+    // (`trace_checked_path`) yeniden doğrulanır
+    pub fn mark_edges_unchecked(&mut self) {
+        for node in self.nodes.iter_mut().skip(1) {
+            node.edge_checked = false;
+        }
+    }
+
+    // Kendisi ya da bir atası `cut` koşulunu sağlayan (kök dışı) düğümleri işaretler;
+    // sonuç alt ağaçlar açısından kapalıdır ve doğrudan `remove_nodes`e verilebilir
+    fn mark_subtrees(&self, cut: impl Fn(&Self, usize) -> bool) -> Vec<bool> {
+        // Her düğüm için: None = henüz bilinmiyor, Some(true) = işaretli
+        let mut marked: Vec<Option<bool>> = vec![None; self.nodes.len()];
+        marked[0] = Some(false);
+
+        for index in 0..self.nodes.len() {
+            // Durumu bilinen bir ataya ulaşana kadar yukarı çık, yol üzerindeki düğümler aynı kaderi paylaşır
+            let mut chain = Vec::new();
+            let mut current = index;
+            let state = loop {
+                if let Some(state) = marked[current] {
+                    break state;
+                }
+                if self.nodes[current].parent.is_none() {
+                    break false;
+                }
+                if cut(self, current) {
+                    marked[current] = Some(true);
+                    break true;
+                }
+                chain.push(current);
+                current = self.nodes[current].parent.unwrap();
+            };
+            for node_index in chain {
+                marked[node_index] = Some(state);
+            }
+        }
+
+        marked.into_iter().map(|state| state.unwrap_or(false)).collect()
+    }
+
+    // İşaretli düğümleri ağaçtan çıkarır ve kalan indeksleri sıkıştırır. İşaretli kümenin
+    // alt ağaçlar açısından kapalı olması (silinen düğümün çocuklarının da silinmesi) gerekir
+    fn remove_nodes(&mut self, removed: &[bool]) {
+        let mut new_index = vec![None; self.nodes.len()];
+        let mut next = 0;
+        for (index, &is_removed) in removed.iter().enumerate() {
+            if !is_removed {
+                new_index[index] = Some(next);
+                next += 1;
+            }
+        }
+
+        let mut index = 0;
+        self.nodes.retain(|_| {
+            let keep = !removed[index];
+            index += 1;
+            keep
+        });
+        for node in &mut self.nodes {
+            node.parent = node.parent.map(|parent| new_index[parent].expect("silinen düğümün çocuğu da silinmeli"));
+            node.children = node.children.iter().filter_map(|&child| new_index[child]).collect();
+        }
+
+        self.goal_nodes = self.goal_nodes.iter().filter_map(|&goal_node| new_index[goal_node]).collect();
+        // Silinmeyi bekleyen geçersiz kenarlar da yeni indekslere taşınır
+        self.invalid_edges = self.invalid_edges.iter().filter_map(|&invalid| new_index[invalid]).collect();
+        self.exact_goal_nodes_mut().for_each(|node| *node = node.and_then(|goal_node| new_index[goal_node]));
+
+        // En iyi yol silinmiş olabilir
+        self.reselect_best_goal();
+    }
+
+    // En iyi yolu düşürür ve hedef düğümleri arasından sıfırdan seçer; maliyetler artmış
+    // olabileceğinden eski en iyi maliyet sınır olarak kullanılmaz
+    fn reselect_best_goal(&mut self) {
+        self.best_cost = f32::INFINITY;
+        self.best_goal_node = None;
+        self.best_path.clear();
+        self.best_path_nodes.clear();
+        self.select_best_goal();
+    }
+
+    // Düğümden en yakın hedefe olan düz çizgi mesafesi (kabul edilebilir sezgisel).
+    // Budama (cost + heuristic > best_cost) ve öncelikli genişletme için kullanılır;
+    // değer ilk istendiğinde hesaplanıp düğümde saklanır
+    pub fn heuristic(&self, index: usize) -> f32 {
+        let node = &self.nodes[index];
+        if let Some(h) = node.heuristic.get() {
+            return h;
+        }
+        let h = self.goal_distance(&node.point);
+        node.heuristic.set(Some(h));
+        h
+    }
+
+    // Noktanın hedef koşulunu sağlayıp sağlamadığı: bir hedefin eşiğinin içinde ya da tam hedefte
+    pub fn is_goal(&self, point: &Point) -> bool {
+        self.within_goal(self.goal_distance(point))
+    }
+
+    // Noktaya en yakın hedefin `goals` içindeki sırası (eşitlikte küçük sıra)
+    pub fn nearest_goal(&self, point: &Point) -> usize {
+        (0..self.goal_count())
+            .min_by(|&a, &b| point.distance(&self.goal_point(a)).total_cmp(&point.distance(&self.goal_point(b))))
+            .expect("en az bir hedef vardır")
+    }
+
+    // Noktadan en yakın hedefe olan mesafe
+    fn goal_distance(&self, point: &Point) -> f32 {
+        point.distance(&self.goal_point(self.nearest_goal(point)))
+    }
+
+    // Hedef sayısı (tek hedefli planlamada 1)
+    fn goal_count(&self) -> usize {
+        1 + self.extra_goals.len()
+    }
+
+    // Sıradaki hedef noktası (0 = birincil hedef)
+    fn goal_point(&self, goal: usize) -> Point {
+        if goal == 0 {
+            self.goal
+        } else {
+            self.extra_goals[goal - 1].0
+        }
+    }
+
+    // Hedefin tam hedef noktasında bulunan düğümü
+    fn exact_goal_node(&self, goal: usize) -> Option<usize> {
+        if goal == 0 {
+            self.goal_node
+        } else {
+            self.extra_goals[goal - 1].1
+        }
+    }
+
+    // `exact_goal_node` ile aynı, ancak değiştirilebilir
+    fn exact_goal_node_mut(&mut self, goal: usize) -> &mut Option<usize> {
+        if goal == 0 {
+            &mut self.goal_node
+        } else {
+            &mut self.extra_goals[goal - 1].1
+        }
+    }
+
+    // Tüm hedeflerin tam hedef düğümleri (indeksler güncellenirken kullanılır)
+    fn exact_goal_nodes_mut(&mut self) -> impl Iterator<Item = &mut Option<usize>> {
+        std::iter::once(&mut self.goal_node).chain(self.extra_goals.iter_mut().map(|(_, node)| node))
+    }
+
+    // Düğüm herhangi bir hedefin tam hedef düğümü mü
+    fn is_exact_goal_node(&self, index: usize) -> bool {
+        (0..self.goal_count()).any(|goal| self.exact_goal_node(goal) == Some(index))
+    }
+
+    // Hedefe olan mesafenin hedef koşulunu sağlayıp sağlamadığı
+    fn within_goal(&self, distance: f32) -> bool {
+        distance < self.goal_threshold || distance == 0.0
+    }
+
+    // Son eklenen düğüm hedef koşulunu sağlıyorsa en yakın hedefe bağlar ya da hedef kümesine ekler,
+    // ardından kümedeki en düşük maliyetli düğümü en iyi hedef düğümü olarak seçer. Yeniden
+    // bağlama sonrası maliyeti düşen hedef düğümleri de böylece yakalanır; maliyet
+    // iyileşmişse 'true' döner
+    pub fn update_best_path(&mut self) -> bool {
+        self.register_goal_candidate(self.nodes.len() - 1);
+        self.select_best_goal()
+    }
+
+    // Düğüm hedef koşulunu sağlıyorsa en yakın hedefe bağlar ya da hedef kümesine ekler;
+    // kümede ya da bir hedefin tam hedef düğümü olan düğümler atlanır
+    fn register_goal_candidate(&mut self, index: usize) {
+        let already_known = self.goal_nodes.last() == Some(&index) || self.is_exact_goal_node(index);
+        let distance = self.heuristic(index);
+        let goal = self.nearest_goal(&self.nodes[index].point);
+        let goal_point = self.goal_point(goal);
+
+        if !already_known && distance == 0.0 && self.exact_goal_node(goal).is_none() && self.heading_at_goal(index) {
+            // Düğüm tam hedefte: açık hedef düğümü budur
+            *self.exact_goal_node_mut(goal) = Some(index);
+            self.goal_nodes.push(index);
+        } else if !already_known && self.within_goal(distance) {
+            // Hedefe engelsiz bağlanabiliyorsa yol tam hedefte biter, aksi halde (hedef yönü
+            // istenmiyorsa) bu düğümde
+            if self.turn_allowed(index, &goal_point) && self.goal_link_free(index, goal) {
+                self.connect_goal(index, goal);
+            } else if self.required_goal_heading().is_none() {
+                self.goal_nodes.push(index);
+            }
+        } else if !already_known
+            && self.goal_threshold == 0.0
+            && distance <= self.step_size
+            && self.turn_allowed(index, &goal_point)
+            && self.goal_link_free(index, goal)
+        {
+            // Eşik 0 iken yönlendirilen noktalar hedefe tam olarak denk gelmez; bir adımdan
+            // yakın ve hedefe engelsiz bağlanabilen düğümler hedefe tam olarak bağlanır
+            self.connect_goal(index, goal);
+        }
+    }
+
+    // Sıradaki hedefin noktasını, verilen düğümün çocuğu olarak o hedefin tek ortak hedef
+    // düğümüyle ağaca bağlar. Hedef düğümü zaten varsa ebeveyni yalnızca daha ucuzsa bu düğüme taşınır;
+    // son hedef bacağının maliyeti de yol maliyetine dahildir
+    fn connect_goal(&mut self, connector: usize, goal: usize) {
+        let from = &self.nodes[connector];
+        let goal_point = self.goal_point(goal);
+        let heading = self.goal_heading(connector, goal);
+        let cost = from.cost + self.transition_cost(&from.point, from.heading, &goal_point, heading);
+        match self.exact_goal_node(goal) {
+            None => {
+                let index = self.add_node_with_heading(goal_point, heading, connector);
+                self.defer_edge_check(index);
+                *self.exact_goal_node_mut(goal) = Some(index);
+                self.goal_nodes.push(index);
+            }
+            Some(goal_index) => {
+                // Düz RRT'de hedef ilk bağlandığı ebeveyninde kalır
+                if self.optimize && cost < self.nodes[goal_index].cost {
+                    self.set_parent(goal_index, connector, cost);
+                    self.defer_edge_check(goal_index);
+                }
+            }
+        }
+    }
+
+    // Düğümden hedefe bağlanırken hedefteki yön: eğrisel yönlendirmelerde istenen hedef yönü
+    // ya da var olan hedef düğümünün yönü korunur, aksi halde hedefe kiriş doğrultusunda varılır
+    fn goal_heading(&self, connector: usize, goal: usize) -> f32 {
+        if let Some(heading) = self.required_goal_heading() {
+            return heading;
+        }
+        match self.exact_goal_node(goal) {
+            Some(goal_index) if self.steering != Steering::Straight => self.nodes[goal_index].heading,
+            _ => self.arrival_heading(connector, &self.goal_point(goal)),
+        }
+    }
+
+    // Hedefte uyulması gereken yön; doğrusal yönlendirmede yön serbesttir
+    fn required_goal_heading(&self) -> Option<f32> {
+        self.goal_heading.filter(|_| self.steering != Steering::Straight)
+    }
+
+    // Düğümün yönü istenen hedef yönüyle uyuşuyor mu (istenen yön yoksa her zaman)
+    fn heading_at_goal(&self, index: usize) -> bool {
+        self.required_goal_heading().is_none_or(|heading| {
+            let difference = (self.nodes[index].heading - heading).rem_euclid(std::f32::consts::TAU);
+            difference.min(std::f32::consts::TAU - difference) <= COST_EPSILON
+        })
+    }
+
+    // Düğümden hedefe olan bağlantı engelsiz mi; çift integratör yönlendirmesinde iki durumu
+    // birleştiren bir yerel yol olmadığından hedefe hiçbir zaman tam bağlanılmaz
+    fn goal_link_free(&self, connector: usize, goal: usize) -> bool {
+        if self.double_integrator().is_some() {
+            return false;
+        }
+        let from = &self.nodes[connector];
+        self.edge_admissible(&from.point, from.heading, &self.goal_point(goal), self.goal_heading(connector, goal))
+    }
+
+    // Hedef kümesindeki en düşük maliyetli düğümü seçer (eşitlikte küçük indeks); maliyet
+    // iyileşmişse yolu tazeler, `on_path_improved` geri çağırmasını tetikler ve 'true' döner.
+    // Maliyet düşüşü yalnızca hedef düğümünün atalarının yeniden bağlanmasından gelse de
+    // `rewire` buraya ulaştığından iyileşme kaçırılmaz
+    // Tembel kipte aday yol önce doğrulanır (bkz. `validate_edges_to`); onarılan kenarlar
+    // maliyetleri değiştirdiğinden seçim yinelenir, onarılamayan bir kenarın altında kalan
+    // adaylar ise hedef kümesinden çıkarılır
+    fn select_best_goal(&mut self) -> bool {
+        let cheapest = loop {
+            let cheapest = self
+                .goal_nodes
+                .iter()
+                .copied()
+                .min_by(|&a, &b| self.nodes[a].cost.total_cmp(&self.nodes[b].cost).then(a.cmp(&b)));
+            match cheapest {
+                Some(index) if self.lazy_collision => match self.validate_edges_to(index) {
+                    Validation::Valid => break cheapest,
+                    Validation::Repaired => {}
+                    Validation::Invalid => self.drop_goal_node(index),
+                },
+                _ => break cheapest,
+            }
+        };
+
+        if let Some(index) = cheapest {
+            if self.nodes[index].cost < self.best_cost {
+                self.best_cost = self.nodes[index].cost;
+                self.best_goal_node = Some(index);
+                // Yüklenen bir ağaçta en iyi maliyet yeniden seçilir; kayıt zaten geçmiştedir
+                if self.cost_history.last().map(|&(_, cost)| cost) != Some(self.best_cost) {
+                    self.cost_history.push((self.iterations, self.best_cost));
+                }
+                self.refresh_best_path();
+                if let Some(callback) = self.on_path_improved.as_mut() {
+                    callback(self.best_cost);
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    // Düğümden köke giden yoldaki denetlenmemiş kenarları doğrular. Çarpışan ilk kenarın
+    // düğümü, alt ağacı dışındaki komşularından engelsiz bağlanabilen en ucuzuna taşınır; böyle
+    // bir komşu yoksa alt ağacıyla silinmek üzere kaydedilir. Onarılan ya da geçersiz kalan
+    // yol en iyi yolsa yol düşürülür
+    fn validate_edges_to(&mut self, index: usize) -> Validation {
+        let mut current = index;
+        while let Some(parent) = self.nodes[current].parent {
+            if !self.nodes[current].edge_checked {
+                if self.invalid_edges.contains(&current) {
+                    return Validation::Invalid;
+                }
+                if !self.node_edge_free(parent, current) {
+                    let best_affected = self.best_goal_node.is_some_and(|best| self.is_ancestor(current, best));
+                    let repaired = self.reattach(current);
+                    if !repaired {
+                        self.invalid_edges.push(current);
+                    }
+                    if best_affected {
+                        self.best_cost = f32::INFINITY;
+                        self.best_goal_node = None;
+                        self.best_path.clear();
+                        self.best_path_nodes.clear();
+                    }
+                    return if repaired { Validation::Repaired } else { Validation::Invalid };
+                }
+                self.nodes[current].edge_checked = true;
+            }
+            current = parent;
+        }
+        Validation::Valid
+    }
+
+    // Ebeveyn kenarı çarpışan düğümü, alt ağacında olmayan komşuları arasından engelsiz
+    // bağlanabilen en ucuzuna taşır; maliyet değişimi alt ağaca yayılır
+    fn reattach(&mut self, index: usize) -> bool {
+        match self.cheapest_free_parent(index, |candidate| !self.invalid_edges.contains(&candidate)) {
+            Some((parent, cost)) => {
+                self.set_parent(index, parent, cost);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Düğümün alt ağacında olmayan ve `allowed` koşulunu sağlayan komşuları arasından
+    // engelsiz bağlanabilen en ucuzu ve bağlanınca oluşacak maliyet
+    fn cheapest_free_parent(&self, index: usize, allowed: impl Fn(usize) -> bool) -> Option<(usize, f32)> {
+        let point = self.nodes[index].point;
+        let heading = self.nodes[index].heading;
+        let arrival = |parent: usize| match self.steering {
+            Steering::Straight => self.arrival_heading(parent, &point),
+            _ => heading,
+        };
+        let candidates = self.near_except(index).into_iter().filter(|&candidate| !self.is_ancestor(index, candidate));
+        self.sorted_by_cost(&point, arrival, candidates).into_iter().find(|&(candidate, _)| {
+            let node = &self.nodes[candidate];
+            allowed(candidate)
+                && self.rewire_turns_allowed(candidate, index)
+                && self.transition_free(&node.point, node.heading, &point, arrival(candidate))
+        })
+    }
+
+    // Yolu geçersiz çıkan hedef düğümünü hedef kümesinden çıkarır; en iyi hedef düğümüyse
+    // yol düşürülür ve en iyi hedef sıfırdan seçilir
+    fn drop_goal_node(&mut self, index: usize) {
+        self.goal_nodes.retain(|&goal_node| goal_node != index);
+        self.exact_goal_nodes_mut().filter(|node| **node == Some(index)).for_each(|node| *node = None);
+        if self.best_goal_node == Some(index) {
+            self.best_cost = f32::INFINITY;
+            self.best_goal_node = None;
+            self.best_path.clear();
+            self.best_path_nodes.clear();
+        }
+    }
+
+    // Tembel doğrulamada çarpıştığı anlaşılan kenarların düğümlerini alt ağaçlarıyla siler
+    fn remove_invalid_edges(&mut self) {
+        let invalid = std::mem::take(&mut self.invalid_edges);
+        let removed = self.mark_subtrees(|_, index| invalid.contains(&index));
+        self.remove_nodes(&removed);
+    }
+
+    // Tembel çarpışma denetimini açar ya da kapatır. Açıkken yeni kenarlar (ebeveyn seçimi,
+    // yeniden bağlama ve hedef bağlantısı) yalnızca uç noktaları denetlenerek eklenir ve
+    // "kontrol edilmedi" olarak işaretlenir; kenarın tamamı yalnızca en iyi yol adayının
+    // üzerine düştüğünde denetlenir. Çarpışan kenarlar alt ağaçlarıyla silinir ve arama
+    // sürer. Pahalı çarpışma denetimli haritalarda (büyük ızgaralar, çok sayıda engel) çoğu
+    // kenar hiçbir zaman bir yolun parçası olmadığından denetim süresi kısalır. Çift
+    // integratör yönlendirmesinde etkisizdir
+    pub fn set_lazy_collision(&mut self, lazy_collision: bool) {
+        self.lazy_collision = lazy_collision;
+    }
+
+    // Tembel çarpışma denetimi açık mı
+    pub fn lazy_collision(&self) -> bool {
+        self.lazy_collision
+    }
+
+    // Şu ana kadar yapılan yerel yol çarpışma denetimi sayısı (tembel doğrulamalar dahil)
+    pub fn collision_checks(&self) -> usize {
+        self.collision_checks.get()
+    }
+
+    // Tembel kipte yalnızca uç noktası denetlenerek geçilen kenar sayısı
+    pub fn skipped_collision_checks(&self) -> usize {
+        self.skipped_collision_checks.get()
+    }
+
+    // Şu ana kadar yapılan yakınlık yarıçapı taraması sayısı (bkz. `near_point`)
+    pub fn neighbor_queries(&self) -> usize {
+        self.neighbor_queries.get()
+    }
+
+    // Ağaca eklenecek bir kenarın kabul edilip edilmeyeceği: tembel kipte yalnızca varış
+    // noktası denetlenir, aksi halde yerel yolun tamamı
+    fn edge_admissible(&self, from: &Point, from_heading: f32, to: &Point, to_heading: f32) -> bool {
+        if self.lazy_collision {
+            self.skipped_collision_checks.set(self.skipped_collision_checks.get() + 1);
+            return self.is_collision_free(to);
+        }
+        self.transition_free(from, from_heading, to, to_heading)
+    }
+
+    // Tembel kipte düğümün ebeveyn kenarını sonradan doğrulanmak üzere işaretler
+    fn defer_edge_check(&mut self, index: usize) {
+        if self.lazy_collision {
+            self.nodes[index].edge_checked = false;
+        }
+    }
+
+    // En iyi yolun maliyeti her düştüğünde (yeni hedef bağlantısı ya da ataların yeniden
+    // bağlanmasıyla) yeni maliyetle çağrılacak fonksiyonu ayarlar
+    pub fn set_on_path_improved(&mut self, callback: impl FnMut(f32) + Send + 'static) {
+        self.on_path_improved = Some(Box::new(callback));
+    }
+
+    // Engellere ve doluluk ızgarasına ek olarak her nokta ve kenar denetiminde danışılacak
+    // özel çarpışma denetleyicisini bağlar; yalnızca bundan sonraki denetimleri etkiler
+    pub fn set_collision_checker(&mut self, checker: impl CollisionChecker + Send + 'static) {
+        self.collision_checker = Some(Box::new(checker));
+    }
+
+    // Bağlı özel çarpışma denetleyicisini kaldırır
+    pub fn clear_collision_checker(&mut self) {
+        self.collision_checker = None;
+    }
+
+    // Önbellekteki en iyi yol; henüz hedefe ulaşılmadıysa ya da yol geçersiz kılındıysa boştur.
+    // En iyi hedef düğümünün maliyeti ya da ata zinciri her değiştiğinde tazelenir
+    pub fn best_path(&self) -> &Path {
+        &self.best_path
+    }
+
+    // En iyi yolun maliyetini (maliyet bölgeleri dahil) parçalarından sıfırdan hesaplar;
+    // artımlı olarak biriken `best_cost`un aksine kayma içermez. Yol yoksa sonsuz döner
+    pub fn recompute_best_cost(&self) -> f32 {
+        if self.best_path.is_empty() {
+            return f32::INFINITY;
+        }
+        if self.steering == Steering::Straight {
+            return self.best_path.recompute_cost_with(|a, b| self.edge_cost(a, b));
+        }
+        // Eğrisel kenarların maliyeti uçlardaki yönlere de bağlıdır
+        self.best_path_nodes
+            .windows(2)
+            .map(|edge| f64::from(self.node_edge_cost(edge[0], edge[1])))
+            .sum::<f64>() as f32
+    }
+
+    // Önbellekteki yolu ağaçla eşitler. Yeniden bağlama yalnızca maliyeti düşürdüğünden
+    // ata zincirindeki her değişiklik en iyi maliyeti de düşürür ve `select_best_goal` üzerinden
+    // buraya ulaşır. Hedef tarafında ebeveyn zinciri değişmemiş kısım korunur; yalnızca
+    // ebeveyni değişen düğümün üstü ağaçtan yeniden izlenir
+    fn refresh_best_path(&mut self) {
+        let Some(goal_index) = self.best_goal_node else {
+            self.best_path.clear();
+            self.best_path_nodes.clear();
+            return;
+        };
+
+        // Önbellekle sondan eşleşen düğümleri say
+        let cached = self.best_path_nodes.len();
+        let mut kept = 0;
+        let mut current = Some(goal_index);
+        while let Some(index) = current {
+            if kept == cached || self.best_path_nodes[cached - 1 - kept] != index {
+                break;
+            }
+            kept += 1;
+            current = self.nodes[index].parent;
+        }
+
+        // Değişen kısmı köke kadar yeniden izle ve korunan kısmın önüne ekle
+        let mut prefix = Vec::new();
+        while let Some(index) = current {
+            prefix.push(index);
+            assert!(prefix.len() <= self.nodes.len(), "yeniden bağlama ebeveyn döngüsü oluşturmaz");
+            current = self.nodes[index].parent;
+        }
+        prefix.reverse();
+        self.best_path_nodes.splice(..cached - kept, prefix);
+        self.best_path = Path::new(self.best_path_nodes.iter().map(|&index| self.nodes[index].point).collect());
+    }
+
+    // Engeller değiştikten sonra önbellekteki en iyi yolun parçalarını yeniden denetler; herhangi
+    // bir parça artık çarpışıyorsa 'false' döner (bkz. `revalidate_best_path_by`). Ağacın
+    // kendisi değişmez, onu onarmak için `invalidate` ya da `trace_checked_path` kullanılır
+    pub fn revalidate_best_path(&mut self) -> bool {
+        self.revalidate_best_path_by(|tree| match tree.steering {
+            Steering::Straight => tree
+                .best_path
+                .windows(2)
+                .all(|segment| tree.is_edge_collision_free(&segment[0], &segment[1])),
+            Steering::Dubins { .. } | Steering::ReedsShepp { .. } | Steering::DoubleIntegrator(_) => {
+                tree.best_path_nodes.windows(2).all(|edge| tree.node_edge_free(edge[0], edge[1]))
+            }
+        })
+    }
+
+    // `revalidate_best_path` gibi, ama yolu planlayıcının kendi engelleri yerine verilen
+    // denetleyiciyle sınar; eğrisel kenarlar `best_path_polyline` ile çokgen çizgiye açılır
+    pub fn revalidate_best_path_with(&mut self, checker: &dyn CollisionChecker) -> bool {
+        self.revalidate_best_path_by(|tree| {
+            tree.best_path_polyline().windows(2).all(|segment| checker.edge_free(&segment[0], &segment[1]))
+        })
+    }
+
+    // En iyi yol geçersizse hedef düğümü hedef kümesinden çıkarılır ve en iyi hedef kalanlar
+    // arasından yeniden seçilir; bu, engelsiz yolu olan bir hedef bulunana ya da hedef
+    // kalmayana kadar sürer. Maliyet ve durum böylece her zaman var olan bir yola karşılık gelir
+    fn revalidate_best_path_by(&mut self, path_free: impl Fn(&Self) -> bool) -> bool {
+        if path_free(self) {
+            return true;
+        }
+        while let Some(index) = self.best_goal_node {
+            self.drop_goal_node(index);
+            self.select_best_goal();
+            if path_free(self) {
+                break;
+            }
+        }
+        false
+    }
+
+    // En iyi hedef düğümünden köke doğru geri izleyerek bir noktalar dizisi döner;
+    // henüz hedefe ulaşılmadıysa boş dizi döner. Ağaçtaki düğüm sayısından fazla adım
+    // atılırsa ebeveyn zincirinde döngü var demektir ve sonsuz döngü yerine hata döner
+    pub fn trace_path(&self) -> Result<Vec<Point>, TreeInvariantError> {
+        match self.best_goal_node {
+            Some(index) => self.trace_from(index),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // Hedefe en yakın (engelli olmayan) düğüme kökten giden yol; yalnızca kök varsa tek
+    // noktalı yol döner. Çözüm bulunamadığında ne kadar yaklaşıldığını göstermek içindir
+    pub fn closest_partial_path(&self) -> Option<Path> {
+        let closest = (0..self.nodes.len())
+            .filter(|&index| !self.nodes[index].blocked)
+            .min_by(|&a, &b| self.heuristic(a).total_cmp(&self.heuristic(b)).then(a.cmp(&b)))?;
+        self.trace_from(closest).ok().map(Path::new)
+    }
+
+    // Verilen düğümden köke geri izleyerek kökten düğüme giden noktalar dizisini döner
+    pub fn trace_from(&self, start_index: usize) -> Result<Vec<Point>, TreeInvariantError> {
+        let mut path = Vec::new();
+        let mut current_node_index = start_index;
+        while let Some(parent_index) = self.nodes[current_node_index].parent {
+            path.push(self.nodes[current_node_index].point);
+            if path.len() > self.nodes.len() {
+                return Err(TreeInvariantError::Cycle { node: start_index });
+            }
+            current_node_index = parent_index;
+        }
+        path.push(self.nodes[current_node_index].point);
+        path.reverse();
+        Ok(path)
+    }
+
+    // En iyi yolu izlemeden önce yol üzerindeki kontrol edilmemiş kenarları doğrular.
+    // Artık çarpışan bir kenar bulunursa o düğüm alt ağacıyla birlikte silinir, en iyi hedef
+    // düğümü yeniden seçilir ve doğrulama yeni yol için tekrarlanır
+    pub fn trace_checked_path(&mut self) -> Result<Vec<Point>, TreeInvariantError> {
+        'retry: loop {
+            let Some(mut current) = self.best_goal_node else {
+                return Ok(Vec::new());
+            };
+
+            while let Some(parent) = self.nodes[current].parent {
+                if !self.nodes[current].edge_checked {
+                    if self.node_edge_free(parent, current) {
+                        self.nodes[current].edge_checked = true;
+                    } else {
+                        let removed = self.mark_subtrees(|_, index| index == current);
+                        self.remove_nodes(&removed);
+                        continue 'retry;
+                    }
+                }
+                current = parent;
+            }
+            return self.trace_path();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Testlerde kullanılan temel yapılandırma: standart haritada köşeden köşeye
+    fn standard_config() -> PlanConfig {
+        PlanConfig {
+            start: Point { x: 20.0, y: 20.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            obstacles: maps::standard(),
+            max_iterations: 3000,
+            ..PlanConfig::default()
+        }
+    }
+
+    // İlk çözümün bulunduğu iterasyon (bütçe içinde bulunamazsa iterasyon sınırı)
+    fn first_solution_iteration(config: &PlanConfig, seed: u64) -> usize {
+        let mut rrt_star = RRTStar::from_config(config, seed);
+        while !rrt_star.is_finished() && !rrt_star.best_cost().is_finite() {
+            rrt_star.step();
+        }
+        rrt_star.iterations()
+    }
+
+    #[test]
+    fn best_cost_never_increases() {
+        let mut rrt_star = RRTStar::from_config(&standard_config(), 7);
+        let mut previous = f32::INFINITY;
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+            assert!(rrt_star.best_cost() <= previous, "{} > {previous}", rrt_star.best_cost());
+            previous = rrt_star.best_cost();
+        }
+        let history = rrt_star.cost_history();
+        assert!(history.len() > 1);
+        assert!(history.windows(2).all(|pair| pair[1].1 <= pair[0].1 && pair[1].0 >= pair[0].0));
+        assert_eq!(history.last().map(|&(_, cost)| cost), Some(rrt_star.best_cost()));
+    }
+
+    // Tembel kipte çarpıştığı anlaşılan kenarlar bir sonraki adımda silinir; arada yapılan
+    // budama indeksleri kaydırsa da yalnızca bu kenarların alt ağaçları silinmeli
+    #[test]
+    fn prune_keeps_pending_invalid_edges_in_sync() {
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { lazy_collision: true, max_iterations: 2000, ..standard_config() }, 3);
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+        }
+        assert!(rrt_star.best_cost().is_finite());
+        rrt_star.remove_invalid_edges();
+
+        // Budamadan sağ çıkan, en iyi yolda olmayan ve önünde budanan düğümler bulunan bir
+        // kenar reddedilmiş sayılır
+        let best = rrt_star.best_goal_node.unwrap();
+        let scale = rrt_star.cost_scale();
+        let bound = rrt_star.best_cost + rrt_star.heuristic(best) / scale;
+        let pruned = rrt_star.mark_subtrees(|tree, index| tree.nodes[index].cost + tree.heuristic(index) / scale > bound + COST_EPSILON * bound.max(1.0));
+        let first_pruned = pruned.iter().position(|&p| p).expect("budanacak düğüm olmalı");
+        let rejected = (first_pruned + 1..rrt_star.nodes.len())
+            .rev()
+            .find(|&index| !pruned[index] && !rrt_star.is_ancestor(index, best))
+            .expect("budamadan sağ çıkan bir düğüm olmalı");
+        rrt_star.invalid_edges.push(rejected);
+        let doomed = rrt_star.mark_subtrees(|_, index| index == rejected);
+        let doomed: Vec<Point> = rrt_star.nodes.iter().zip(&doomed).filter(|(_, &d)| d).map(|(node, _)| node.point).collect();
+
+        assert!(rrt_star.prune() > 0);
+        let survivors: Vec<Point> = rrt_star.nodes.iter().map(|node| node.point).filter(|point| !doomed.contains(point)).collect();
+        rrt_star.set_max_iterations(rrt_star.iterations() + 1);
+        rrt_star.step();
+        assert!(rrt_star.verify_tree().is_ok());
+        for point in &survivors {
+            assert!(rrt_star.nodes.iter().any(|node| node.point == *point), "collision-free node at {point:?} was removed");
+        }
+        assert!(rrt_star.nodes.iter().all(|node| !doomed.contains(&node.point)));
+    }
+
+    // Budama en iyi yol üzerindeki hiçbir düğümü silmez ve ağaç geçerli kalır
+    #[test]
+    fn prune_keeps_best_path() {
+        for seed in 0..5 {
+            let mut rrt_star = RRTStar::from_config(&standard_config(), seed);
+            while !rrt_star.is_finished() {
+                rrt_star.step();
+            }
+            let points: Vec<Point> = rrt_star.best_path_nodes.iter().map(|&index| rrt_star.nodes[index].point).collect();
+            let (path, cost, nodes) = (rrt_star.best_path().clone(), rrt_star.best_cost(), rrt_star.nodes.len());
+            assert!(cost.is_finite());
+
+            let removed = rrt_star.prune();
+            assert!(removed > 0);
+            assert_eq!(rrt_star.nodes.len(), nodes - removed);
+            assert_eq!(rrt_star.best_path_nodes.iter().map(|&index| rrt_star.nodes[index].point).collect::<Vec<_>>(), points);
+            assert_eq!(*rrt_star.best_path(), path);
+            assert_eq!(rrt_star.best_cost(), cost);
+            assert!(rrt_star.verify_tree().is_ok());
+        }
+    }
+
+    // Kaydedilen durum geri yüklendiğinde ağaç, hedefler ve en iyi yol aynen korunur;
+    // yeni tohumla planlamaya devam edilebilir
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut rrt_star = RRTStar::from_config(&standard_config(), 11);
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+        }
+        assert!(rrt_star.best_cost().is_finite());
+        let file = std::env::temp_dir().join(format!("rrt_star_round_trip_{}.json", std::process::id()));
+        let file = file.to_str().expect("geçici dosya yolu UTF-8 olmalı");
+        rrt_star.save_state(file).expect("durum kaydedilebilmeli");
+        let loaded = RRTStar::load_state(file);
+        std::fs::remove_file(file).ok();
+        let mut loaded = loaded.expect("durum yüklenebilmeli");
+
+        assert_eq!(loaded.nodes.len(), rrt_star.nodes.len());
+        for (a, b) in loaded.nodes.iter().zip(&rrt_star.nodes) {
+            assert_eq!((a.point, a.parent, a.cost, &a.children), (b.point, b.parent, b.cost, &b.children));
+        }
+        assert_eq!(loaded.goal_nodes, rrt_star.goal_nodes);
+        assert_eq!(loaded.best_cost(), rrt_star.best_cost());
+        assert_eq!(*loaded.best_path(), *rrt_star.best_path());
+
+        loaded.set_seed(1);
+        let iterations = loaded.iterations();
+        loaded.set_max_iterations(iterations + 50);
+        while !loaded.is_finished() {
+            loaded.step();
+        }
+        assert_eq!(loaded.iterations(), iterations + 50);
+        assert!(loaded.best_cost() <= rrt_star.best_cost());
+        assert!(loaded.verify_tree().is_ok());
+    }
+
+    #[test]
+    fn steer_stops_on_nearby_targets() {
+        let rrt_star = RRTStar::from_config(&PlanConfig::default(), 0);
+        let from = Point { x: 3.0, y: 4.0 };
+        // Tam bir adım uzaklıktaki ve aynı noktadaki hedefler olduğu gibi döner
+        let boundary = Point { x: 3.0 + 6.0, y: 4.0 + 8.0 };
+        assert_eq!(from.distance(&boundary), rrt_star.step_size);
+        assert_eq!(rrt_star.steer(&from, &boundary), boundary);
+        assert_eq!(rrt_star.steer(&from, &from), from);
+        // Uzaktaki hedefe doğru tam bir adım atılır
+        let far = rrt_star.steer(&from, &Point { x: 3.0 + 60.0, y: 4.0 + 80.0 });
+        assert!((from.distance(&far) - rrt_star.step_size).abs() < 1e-4);
+        assert!((far.x - 9.0).abs() < 1e-4 && (far.y - 12.0).abs() < 1e-4, "{far:?}");
+    }
+
+    // Bozuk bir düğüm (NaN koordinatlı) en yakın düğüm aramasını çökertmez ve seçilmez
+    #[test]
+    fn find_nearest_ignores_nan_node() {
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { start: Point { x: 50.0, y: 50.0 }, ..PlanConfig::default() }, 0);
+        rrt_star.nodes.push(Node::new(Point { x: f32::NAN, y: 60.0 }, 0.0, Some(0), 10.0));
+        rrt_star.nodes.push(Node::new(Point { x: 70.0, y: 50.0 }, 0.0, Some(0), 20.0));
+        assert_eq!(rrt_star.find_nearest(&Point { x: 65.0, y: 50.0 }), Some(2));
+        assert_eq!(rrt_star.find_nearest(&Point { x: 52.0, y: 60.0 }), Some(0));
+        assert_eq!(rrt_star.near_point(&Point { x: 60.0, y: 55.0 }, 15.0), vec![0, 2]);
+    }
+
+    // NaN ve sonsuz koordinatlı noktalar içeren bir kümede (NaN sorgu dahil) arama çökmez;
+    // geçerli bir düğüm varken NaN'lı düğümler en uzak sayılır
+    #[test]
+    fn find_nearest_survives_nan_point_set() {
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { start: Point { x: f32::NAN, y: f32::NAN }, ..PlanConfig::default() }, 0);
+        for point in [(f32::NAN, 1.0), (1.0, f32::NAN), (f32::INFINITY, 0.0), (f32::NAN, f32::NEG_INFINITY), (5.0, 5.0)] {
+            rrt_star.nodes.push(Node::new(Point { x: point.0, y: point.1 }, 0.0, Some(0), 1.0));
+        }
+        assert_eq!(rrt_star.find_nearest(&Point { x: 0.0, y: 0.0 }), Some(5));
+        assert_eq!(rrt_star.find_nearest(&Point { x: 1.0, y: 1.0 }), Some(5));
+        assert!(rrt_star.find_nearest(&Point { x: f32::NAN, y: 0.0 }).is_some());
+        assert!(rrt_star.near_point(&Point { x: f32::NAN, y: f32::NAN }, 10.0).is_empty());
+    }
+
+    // Sıkışık bir kümede yeni düğümün ebeveyni de komşuluk yarıçapının içindedir; yeniden
+    // bağlama hiçbir zaman ebeveyn döngüsü kurmaz ve yol izleme sonlanır
+    #[test]
+    fn rewire_in_tight_cluster_never_cycles() {
+        let config = PlanConfig {
+            start: Point { x: 5.0, y: 5.0 },
+            goal: Point { x: 25.0, y: 25.0 },
+            goal_threshold: 2.0,
+            bounds: Bounds::new(0.0, 30.0, 0.0, 30.0),
+            max_iterations: 800,
+            ..PlanConfig::default()
+        };
+        let mut rrt_star = RRTStar::from_config(&config, 6);
+        while !rrt_star.is_finished() {
+            if let StepOutcome::Added { index, .. } = rrt_star.step() {
+                let parent = rrt_star.nodes[index].parent.expect("eklenen düğümün ebeveyni olmalı");
+                assert!(rrt_star.near_except(index).contains(&parent));
+            }
+        }
+        assert!(rrt_star.rewires > 0);
+        assert!(rrt_star.verify_tree().is_ok());
+        for index in 0..rrt_star.nodes.len() {
+            assert!(rrt_star.trace_from(index).is_ok_and(|path| path.len() <= rrt_star.nodes.len()));
+        }
+        assert!(rrt_star.trace_path().is_ok_and(|path| !path.is_empty()));
+    }
+
+    // Yeni noktanın dört simetrik komşusu aynı maliyetle bağlanır; komşuların ağaca ekleniş
+    // sırası, aday listesindeki sıraları ve en yakın düğüm olarak hangisinin verildiği ne olursa
+    // olsun en küçük indeksli komşu ebeveyn seçilir
+    #[test]
+    fn choose_parent_breaks_ties_by_lowest_index() {
+        use rand::seq::SliceRandom;
+
+        let point = Point { x: 50.0, y: 50.0 };
+        let mut offsets = [(10.0, 0.0), (0.0, 10.0), (-10.0, 0.0), (0.0, -10.0)];
+        let mut rng = StdRng::seed_from_u64(5);
+        for construction in 0..100 {
+            let mut rrt_star = RRTStar::from_config(&PlanConfig::default(), construction);
+            // Komşular her kurulumda karışık bir sırayla eklenir; maliyetler elle eşitlenir
+            offsets.shuffle(&mut rng);
+            for (dx, dy) in offsets {
+                rrt_star.nodes.push(Node::new(Point { x: point.x + dx, y: point.y + dy }, 0.0, Some(0), 100.0));
+            }
+            let mut candidates = [1, 2, 3, 4];
+            candidates.shuffle(&mut rng);
+            let (nearest, neighbors) = candidates.split_first().expect("dört aday var");
+            assert_eq!(rrt_star.choose_parent(&point, *nearest, neighbors), Some(1), "nearest {nearest}, neighbors {neighbors:?}");
+        }
+    }
+
+    // Başlangıç hedefin kendisiyse (ya da kabul bölgesindeyse) iterasyon yapılmadan tek
+    // noktalı, sıfır maliyetli yol döner
+    #[test]
+    fn start_at_goal_is_trivially_solved() {
+        let point = Point { x: 120.0, y: 80.0 };
+        for goal in [point, Point { x: 125.0, y: 80.0 }] {
+            let mut rrt_star = RRTStar::from_config(&PlanConfig { start: point, goal, ..PlanConfig::default() }, 0);
+            assert_eq!(rrt_star.best_cost(), 0.0);
+            let stats = rrt_star.plan().expect("başlangıç hedefte olduğundan yol vardır");
+            assert_eq!((stats.iterations, stats.nodes), (0, 1));
+            assert_eq!(rrt_star.best_cost(), 0.0);
+            assert_eq!(rrt_star.best_path().points(), &[point]);
+            assert_eq!(rrt_star.nodes.len(), 1);
+        }
+    }
+
+    // Örneğe tam olarak eşit uzaklıktaki iki düğümden belgelenen kural seçilir
+    #[test]
+    fn find_nearest_tie_break_is_documented_winner() {
+        let mut rrt_star = RRTStar::from_config(&PlanConfig::default(), 0);
+        rrt_star.nodes.push(Node::new(Point { x: 40.0, y: 50.0 }, 0.0, Some(0), 30.0));
+        rrt_star.nodes.push(Node::new(Point { x: 60.0, y: 50.0 }, 0.0, Some(0), 10.0));
+        rrt_star.nodes.push(Node::new(Point { x: 50.0, y: 40.0 }, 0.0, Some(0), 10.0));
+        let sample = Point { x: 50.0, y: 50.0 };
+
+        assert_eq!(rrt_star.find_nearest(&sample), Some(1));
+        rrt_star.set_nearest_tie_break(NearestTieBreak::LowestCost);
+        // Maliyetler de eşitse indeks belirler
+        assert_eq!(rrt_star.find_nearest(&sample), Some(2));
+    }
+
+    // Köşedeki başlangıçtan bölge dışına yönlendirilen noktalar kırpılır ya da reddedilir;
+    // hiçbir düğüm örnekleme bölgesinin dışına çıkmaz
+    #[test]
+    fn nodes_stay_inside_bounds_from_corner_start() {
+        for out_of_bounds in [OutOfBoundsPolicy::Clamp, OutOfBoundsPolicy::Reject] {
+            let config = PlanConfig {
+                start: Point { x: 0.0, y: 0.0 },
+                goal: Point { x: 200.0, y: 390.0 },
+                max_iterations: 10_000,
+                out_of_bounds,
+                ..PlanConfig::default()
+            };
+            let mut rrt_star = RRTStar::from_config(&config, 1);
+            rrt_star.plan().ok();
+            assert_eq!(rrt_star.iterations(), 10_000);
+            assert!(rrt_star.nodes.iter().all(|node| config.bounds.contains(&node.point)), "{out_of_bounds:?}");
+        }
+    }
+
+    // Adım boyutunun yarısı kadar ayrım zorunluyken küçük bir kutu birkaç yüz düğümle dolar;
+    // sonraki örnekler çoğunlukla mevcut düğümlere fazla yakın olduğundan reddedilir
+    #[test]
+    fn min_node_separation_bounds_tree_size() {
+        let config = PlanConfig {
+            start: Point { x: 5.0, y: 5.0 },
+            goal: Point { x: 95.0, y: 95.0 },
+            goal_threshold: 0.0,
+            bounds: Bounds::new(0.0, 100.0, 0.0, 100.0),
+            max_iterations: 10_000,
+            ..PlanConfig::default()
+        };
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { min_node_separation: config.step_size / 2.0, ..config }, 8);
+        let mut too_close = 0;
+        while !rrt_star.is_finished() {
+            too_close += usize::from(rrt_star.step() == StepOutcome::TooClose);
+        }
+        assert_eq!(rrt_star.iterations(), 10_000);
+        assert!(rrt_star.nodes.len() < 1000, "{} nodes", rrt_star.nodes.len());
+        assert!(too_close > 5000);
+        // Ayrım zorunluluğu tam hedef bağlantısını engellemez
+        assert_eq!(rrt_star.best_path().last(), Some(&Point { x: 95.0, y: 95.0 }));
+        assert!(rrt_star.verify_tree().is_ok());
+    }
+
+    // Yol kabul bölgesindeki düğümde değil tam hedefte biter; son bacak da maliyete dahildir
+    #[test]
+    fn path_ends_exactly_on_goal() {
+        let goal = Point { x: 380.3, y: 379.7 };
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { goal, ..standard_config() }, 5);
+        rrt_star.plan().expect("yol bulunmalı");
+        let last = *rrt_star.best_path().last().expect("yol boş olmamalı");
+        assert_eq!((last.x.to_bits(), last.y.to_bits()), (goal.x.to_bits(), goal.y.to_bits()));
+        assert!((rrt_star.best_path().recompute_cost() - rrt_star.best_cost()).abs() < 1e-2);
+    }
+
+    // Ağacın bir kısmının üzerine sonradan engel çizilir; planlama sürerken kurulan (yeni ya
+    // da yeniden bağlanan) hiçbir kenarın ucu engelin içinde değildir
+    #[test]
+    fn no_new_edge_touches_obstacle_drawn_over_tree() {
+        let config = PlanConfig { goal: Point { x: 380.0, y: 380.0 }, start: Point { x: 20.0, y: 20.0 }, max_iterations: 1000, ..PlanConfig::default() };
+        let mut rrt_star = RRTStar::from_config(&config, 12);
+        rrt_star.plan().ok();
+        let obstacle = Obstacle::Rect { min: Point { x: 60.0, y: 60.0 }, max: Point { x: 180.0, y: 180.0 } };
+        rrt_star.add_obstacle(obstacle);
+        let covered = rrt_star.nodes.iter().filter(|node| obstacle.contains(&node.point)).count();
+        assert!(covered > 10);
+
+        let before: Vec<(Point, Option<usize>)> = rrt_star.nodes.iter().map(|node| (node.point, node.parent)).collect();
+        rrt_star.set_max_iterations(2000);
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+        }
+        assert!(rrt_star.nodes.len() > before.len());
+        for (index, node) in rrt_star.nodes.iter().enumerate().skip(1) {
+            let parent = node.parent.expect("kök dışındaki düğümlerin ebeveyni olmalı");
+            if before.get(index) == Some(&(node.point, node.parent)) {
+                continue;
+            }
+            let parent_point = rrt_star.nodes[parent].point;
+            assert!(!obstacle.contains(&node.point) && !obstacle.contains(&parent_point), "edge {parent_point:?} -> {:?}", node.point);
+        }
+    }
+
+    // Hedef düğümünün yalnızca bir atası yeniden bağlanır; hedefin yakınına düğüm eklenmese
+    // de daha düşük maliyet geri çağırmayla bildirilir
+    #[test]
+    fn ancestor_rewire_reports_improved_path() {
+        use std::sync::{Arc, Mutex};
+
+        let config = PlanConfig { start: Point { x: 0.0, y: 0.0 }, goal: Point { x: 100.0, y: 0.0 }, goal_threshold: 0.0, ..PlanConfig::default() };
+        let mut rrt_star = RRTStar::from_config(&config, 0);
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reported);
+        rrt_star.set_on_path_improved(move |cost| sink.lock().unwrap().push(cost));
+
+        // Kök -> (0, 40) -> (50, 40) -> hedef: dolambaçlı bir ilk yol
+        let detour = rrt_star.add_node(Point { x: 0.0, y: 40.0 }, 0);
+        let ancestor = rrt_star.add_node(Point { x: 50.0, y: 40.0 }, detour);
+        let goal = rrt_star.add_node(config.goal, ancestor);
+        assert!(rrt_star.update_best_path());
+        let first = rrt_star.best_cost();
+
+        // Yeni düğüm atayı kısaltır; hedef düğümü ebeveynini korur
+        let shortcut = rrt_star.add_node(Point { x: 25.0, y: 20.0 }, 0);
+        assert!(!rrt_star.update_best_path());
+        rrt_star.rewire_neighbors(shortcut, &[ancestor]);
+
+        assert_eq!(rrt_star.nodes[ancestor].parent, Some(shortcut));
+        assert_eq!(rrt_star.nodes[goal].parent, Some(ancestor));
+        assert!(rrt_star.best_cost() < first);
+        assert!((rrt_star.best_cost() - 2.0 * 25.0f32.hypot(20.0) - 50.0f32.hypot(40.0)).abs() < 1e-3);
+        assert_eq!(*reported.lock().unwrap(), vec![first, rrt_star.best_cost()]);
+        assert_eq!(rrt_star.best_path()[1], Point { x: 25.0, y: 20.0 });
+    }
+
+    // Kuş uçuşu en yakın hedef duvarlarla çevrili; planlayıcı ulaşılabilen ikinci en yakın
+    // hedefe gider
+    #[test]
+    fn multi_goal_skips_walled_off_nearest_goal() {
+        let start = Point { x: 20.0, y: 200.0 };
+        let goals = vec![Point { x: 120.0, y: 200.0 }, Point { x: 200.0, y: 60.0 }, Point { x: 380.0, y: 380.0 }];
+        let mut rrt_star = RRTStar::new_multi(start, goals.clone(), 10.0, 10.0, 15.0);
+        let rect = |min_x, min_y, max_x, max_y| Obstacle::Rect { min: Point { x: min_x, y: min_y }, max: Point { x: max_x, y: max_y } };
+        for wall in [rect(90.0, 170.0, 150.0, 175.0), rect(90.0, 225.0, 150.0, 230.0), rect(90.0, 170.0, 95.0, 230.0), rect(145.0, 170.0, 150.0, 230.0)] {
+            rrt_star.add_obstacle(wall);
+        }
+        rrt_star.set_max_iterations(3000);
+        rrt_star.set_seed(4);
+        rrt_star.plan().expect("ulaşılabilen hedeflere yol bulunmalı");
+
+        assert_eq!(rrt_star.reached_goal(), Some(1));
+        assert_eq!(rrt_star.best_path().last(), Some(&goals[1]));
+        assert!(rrt_star.best_cost() < start.distance(&goals[2]));
+    }
+
+    // Engelsiz haritada her açılı ebeveyn seçimi neredeyse düz bir yol verir: ara nokta sayısı
+    // mesafe / adım boyutu yerine birkaç tanedir
+    #[test]
+    fn any_angle_path_has_few_waypoints() {
+        let config = PlanConfig { start: Point { x: 20.0, y: 20.0 }, goal: Point { x: 380.0, y: 380.0 }, max_iterations: 2000, ..PlanConfig::default() };
+        let hops = (config.start.distance(&config.goal) / config.step_size) as usize;
+        let mut plain = RRTStar::from_config(&config, 3);
+        plain.plan().expect("yol bulunmalı");
+        let mut any_angle = RRTStar::from_config(&PlanConfig { any_angle: true, ..config.clone() }, 3);
+        any_angle.plan().expect("yol bulunmalı");
+
+        assert!(plain.best_path().len() > hops / 2);
+        assert!(any_angle.best_path().len() <= 5, "{} waypoints", any_angle.best_path().len());
+        assert!(any_angle.best_cost() < config.start.distance(&config.goal) * 1.02);
+    }
+
+    // Uyarlanır yarıçap ağaç büyüdükçe küçülür; engelsiz haritada maliyet yine de düz çizgi
+    // mesafesine yaklaşır. Büyük adım, yarıçapın üst sınırının küçülmeyi gizlememesi içindir
+    #[test]
+    fn adaptive_radius_shrinks_and_converges() {
+        let config = PlanConfig {
+            start: Point { x: 20.0, y: 20.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            step_size: 25.0,
+            radius_mode: RadiusMode::adaptive(400.0 * 400.0),
+            max_iterations: 6000,
+            ..PlanConfig::default()
+        };
+        let straight = config.start.distance(&config.goal);
+        let mut rrt_star = RRTStar::from_config(&config, 10);
+        let mut radii = Vec::new();
+        let mut costs = Vec::new();
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+            if rrt_star.iterations().is_multiple_of(1000) {
+                radii.push(rrt_star.search_radius());
+                costs.push(rrt_star.best_cost());
+            }
+        }
+        assert!(radii.windows(2).all(|pair| pair[1] <= pair[0]), "{radii:?}");
+        assert!(radii.last() < radii.first(), "{radii:?}");
+        assert!(rrt_star.best_cost() < straight * 1.05, "{costs:?} vs {straight}");
+    }
+
+    // Üç yozlaşmış başlangıç: hedefle çakışan, hedefin kabul bölgesinde kalan ve hedefe tek
+    // adımdan yakın, aradaki doğru parçası engelsiz olan
+    #[test]
+    fn degenerate_start_goal_configurations() {
+        let start = Point { x: 100.0, y: 100.0 };
+        for goal in [start, Point { x: 106.0, y: 100.0 }] {
+            let rrt_star = RRTStar::from_config(&PlanConfig { start, goal, ..PlanConfig::default() }, 0);
+            assert_eq!(rrt_star.status(), PlanStatus::SolutionFound { cost: 0.0 });
+            assert_eq!(rrt_star.best_path().points(), &[start]);
+        }
+
+        let goal = Point { x: 100.0, y: 108.0 };
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { start, goal, goal_threshold: 0.0, ..PlanConfig::default() }, 0);
+        assert_eq!(rrt_star.status(), PlanStatus::Planning);
+        assert_eq!(rrt_star.step(), StepOutcome::Added { index: 1, improved: true });
+        assert_eq!(rrt_star.iterations(), 1);
+        assert_eq!(rrt_star.best_path().points(), &[start, goal]);
+        assert_eq!(rrt_star.best_cost(), 8.0);
+    }
+
+    // Ebeveyn seçimi ve yeniden bağlama komşuları adım başına tek bir taramayla paylaşır;
+    // düğüm eklenmeyen adımlarda tarama yapılmaz
+    #[test]
+    fn one_neighbor_scan_per_step() {
+        let mut rrt_star = RRTStar::from_config(&PlanConfig { max_iterations: 1500, ..standard_config() }, 13);
+        let mut added = 0;
+        while !rrt_star.is_finished() {
+            let scans = rrt_star.neighbor_queries();
+            let outcome = rrt_star.step();
+            let expected = usize::from(matches!(outcome, StepOutcome::Added { .. }));
+            assert_eq!(rrt_star.neighbor_queries() - scans, expected, "{outcome:?}");
+            added += expected;
+        }
+        assert!(added > 500);
+    }
+
+    // Eşik 0 iken yalnızca tam hedef kabul edilir: planlama biter ve son ara nokta hedeftir
+    #[test]
+    fn zero_threshold_ends_exactly_on_goal() {
+        for seed in 0..3 {
+            let mut rrt_star = RRTStar::from_config(&PlanConfig { goal_threshold: 0.0, ..standard_config() }, seed);
+            rrt_star.plan().expect("eşik 0 iken de yol bulunmalı");
+            assert_eq!(rrt_star.best_path().last(), Some(&Point { x: 380.0, y: 380.0 }));
+            assert!(rrt_star.goal_nodes.iter().all(|&index| rrt_star.nodes[index].point == Point { x: 380.0, y: 380.0 }));
+        }
+    }
+
+    // Engelsiz haritada %10 hedef örneklemesi ilk çözümü çok daha erken getirir
+    #[test]
+    fn goal_bias_speeds_up_first_solution() {
+        let config = PlanConfig { start: Point { x: 20.0, y: 20.0 }, goal: Point { x: 380.0, y: 380.0 }, ..PlanConfig::default() };
+        let biased = PlanConfig { goal_bias: GoalBias::Fixed(0.1), ..config.clone() };
+        let (mut uniform_total, mut biased_total) = (0, 0);
+        for seed in 0..5 {
+            uniform_total += first_solution_iteration(&config, seed);
+            biased_total += first_solution_iteration(&biased, seed);
+        }
+        assert!(biased_total * 2 < uniform_total, "{biased_total} vs {uniform_total}");
+    }
+
+    // Aynı senaryoda düz RRT'nin yolu RRT*'ınkinden ucuz değildir, ama iterasyon başına
+    // daha az çarpışma denetimi yapar ve hiç komşu taraması yapmaz
+    #[test]
+    fn plain_rrt_costs_more_and_works_less() {
+        for seed in 0..3 {
+            let mut rrt = RRTStar::from_config(&PlanConfig { optimize: false, ..standard_config() }, seed);
+            rrt.plan().expect("RRT yol bulmalı");
+            let mut rrt_star = RRTStar::from_config(&standard_config(), seed);
+            rrt_star.plan().expect("RRT* yol bulmalı");
+
+            assert!(rrt.best_cost() >= rrt_star.best_cost(), "{} < {}", rrt.best_cost(), rrt_star.best_cost());
+            assert_eq!((rrt.rewires, rrt.neighbor_queries()), (0, 0));
+            let per_iteration = |tree: &RRTStar| tree.collision_checks() as f32 / tree.iterations() as f32;
+            assert!(per_iteration(&rrt) < per_iteration(&rrt_star), "{} >= {}", per_iteration(&rrt), per_iteration(&rrt_star));
+        }
+    }
+
+    // Bir engel biraz kaydırıldığında onarım ağacın büyük çoğunluğunu korur; sıfırdan yeniden
+    // planlama ise yalnızca kökle başlar. Onarılan ağaç geçerlidir ve planlama sürer
+    #[test]
+    fn repair_keeps_most_of_the_tree() {
+        let mut rrt_star = RRTStar::from_config(&standard_config(), 14);
+        rrt_star.plan().expect("yol bulunmalı");
+        let before = rrt_star.nodes.len();
+
+        let shifted = Obstacle::Rect { min: Point { x: 90.0, y: 60.0 }, max: Point { x: 130.0, y: 260.0 } };
+        rrt_star.update_obstacle(0, shifted);
+        let stats = rrt_star.repair();
+        let retained = rrt_star.nodes.len();
+        assert_eq!(retained, before - stats.removed);
+        assert!(retained * 10 >= before * 9, "{retained} of {before} retained");
+        assert!(stats.severed > 0 && stats.reattached > 0, "{stats:?}");
+        assert!(rrt_star.verify_tree().is_ok());
+        assert!((1..retained).all(|index| rrt_star.node_edge_free(rrt_star.nodes[index].parent.unwrap(), index)));
+
+        let replan = RRTStar::from_config(&PlanConfig { obstacles: rrt_star.obstacles().to_vec(), ..standard_config() }, 14);
+        assert!(retained > 100 * replan.nodes.len());
+        rrt_star.set_max_iterations(rrt_star.iterations() + 500);
+        rrt_star.plan().expect("onarılan ağaçla yol bulunmalı");
+    }
+
+    // Hedef biraz kaydırıldığında ağaç korunur: düğüm sayısı sıfırlanmaz, yeni hedefe olan
+    // maliyet planlama sürdükçe düşer ve sıfırdan kurulan bir ağaçla karşılaştırılabilir olur
+    #[test]
+    fn goal_move_keeps_tree_and_converges() {
+        let moved = Point { x: 370.0, y: 350.0 };
+        let mut rrt_star = RRTStar::from_config(&standard_config(), 15);
+        rrt_star.plan().expect("yol bulunmalı");
+        let before = rrt_star.nodes.len();
+
+        rrt_star.set_goal(moved).expect("yeni hedef engelsiz");
+        // Yalnızca yeni hedefin tam hedef düğümü eklenebilir
+        assert!(rrt_star.nodes.len() >= before && rrt_star.nodes.len() <= before + 1);
+        assert!(rrt_star.best_cost().is_finite(), "existing nodes should reach the nearby goal");
+        let mut previous = rrt_star.best_cost();
+        rrt_star.set_max_iterations(rrt_star.iterations() + 2000);
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+            assert!(rrt_star.best_cost() <= previous);
+            previous = rrt_star.best_cost();
+        }
+        assert!(rrt_star.nodes.len() > before);
+        assert_eq!(rrt_star.best_path().last(), Some(&moved));
+
+        let fresh_config = PlanConfig { goal: moved, max_iterations: rrt_star.iterations(), ..standard_config() };
+        let mut fresh = RRTStar::from_config(&fresh_config, 15);
+        fresh.plan().expect("yol bulunmalı");
+        assert!(rrt_star.best_cost() <= fresh.best_cost() * 1.05, "{} vs {}", rrt_star.best_cost(), fresh.best_cost());
+    }
+
+    // Dışarıdan verilen denetleyici yalnızca yolu kesen bir engel içerdiğinde yolu düşürür;
+    // planlayıcının kendi engelleri bu denetimde kullanılmaz
+    #[test]
+    fn revalidate_with_external_checker() {
+        let mut rrt_star = RRTStar::from_config(&standard_config(), 42);
+        rrt_star.plan().expect("standart harita çözülür");
+        let (path, cost) = (rrt_star.best_path().clone(), rrt_star.best_cost());
+        assert!(rrt_star.revalidate_best_path_with(&Vec::<Obstacle>::new()));
+        assert_eq!(rrt_star.best_path().len(), path.len());
+        let blocker = Obstacle::Circle { center: path[1].lerp(&path[2], 0.5), radius: 1.0 };
+        assert!(!rrt_star.revalidate_best_path_with(&vec![blocker]));
+        // Maliyet ve durum düşürülen yolu göstermez; kalan hedeflerden engelsiz biri seçilir
+        assert!(rrt_star.best_cost() >= cost);
+        match rrt_star.status() {
+            PlanStatus::SolutionFound { cost } => {
+                assert_eq!(cost, rrt_star.best_cost());
+                assert!(rrt_star.best_path().windows(2).all(|segment| blocker.edge_free(&segment[0], &segment[1])));
+            }
+            status => {
+                assert_eq!(status, PlanStatus::ExhaustedNoSolution);
+                assert!(rrt_star.best_path().is_empty());
+            }
+        }
+        rrt_star.verify_tree().expect("ağaç değişmezleri korunur");
+    }
+
+    // 3 birimlik geçitte (2 birim çaplı robotun 1.5 katı) köprü testli örnekleme ilk çözüme
+    // kadarki iterasyonların ortancasını tekdüze örneklemeye göre en az üçte birine indirir
+    #[test]
+    fn bridge_sampler_speeds_up_narrow_gap() {
+        let config = PlanConfig { obstacles: maps::narrow_gap(3.0), max_iterations: 30_000, ..standard_config() };
+        let bridge = PlanConfig { sampler: Sampler::Bridge { probability: 0.5, sigma: 20.0 }, ..config.clone() };
+        let median = |config: &PlanConfig| {
+            let mut iterations: Vec<usize> = (0..5).map(|seed| first_solution_iteration(config, seed)).collect();
+            iterations.sort_unstable();
+            iterations[2]
+        };
+        let (uniform, bridged) = (median(&config), median(&bridge));
+        assert!(bridged * 3 < uniform, "{bridged} vs {uniform}");
+    }
+
+    // `Forget` politikasında ağaç sınırın çok ötesinde çalışırken düğüm sınırında kalır (hedef
+    // düğümü eklenen adımda bir fazlası, bkz. `step`), en iyi maliyet ise iyileşmeyi sürdürür
+    #[test]
+    fn forget_policy_keeps_tree_flat_while_improving() {
+        let max_nodes = 1000;
+        let config = PlanConfig { max_nodes, node_limit_policy: NodeLimitPolicy::Forget, max_iterations: 6000, ..standard_config() };
+        let mut rrt_star = RRTStar::from_config(&config, 3);
+        let (mut first, mut previous) = (None, f32::INFINITY);
+        while !rrt_star.is_finished() {
+            rrt_star.step();
+            assert!(rrt_star.nodes.len() <= max_nodes + 1, "{} nodes", rrt_star.nodes.len());
+            assert!(rrt_star.best_cost() <= previous);
+            previous = rrt_star.best_cost();
+            if first.is_none() && previous.is_finite() {
+                first = Some(previous);
+            }
+        }
+        let first = first.expect("sınırlı ağaç da çözüm bulur");
+        assert!(rrt_star.nodes.len() >= max_nodes);
+        assert!(rrt_star.best_cost() < first, "{} vs {first}", rrt_star.best_cost());
+        rrt_star.verify_tree().expect("ağaç değişmezleri korunur");
+    }
+
+    // Yarısı açık, yarısı sıkışık haritada engel mesafesine göre ölçeklenen adım, ağaç sıkışık
+    // bölgeye ulaşana kadar açık yarıya çok daha az düğüm ekler; hedef sıkışık bölgede
+    // olduğu halde yine bulunur
+    #[test]
+    fn clearance_scaled_step_saves_nodes_in_open_half() {
+        let config = PlanConfig { obstacles: maps::half_cluttered(), radius_mode: RadiusMode::FixedRadius(45.0), max_iterations: 10_000, ..standard_config() };
+        let scaled = PlanConfig { step_size: 40.0, step_mode: StepMode::ClearanceScaled { min_step: 4.0, scale: 1.0 }, ..config.clone() };
+        let open_nodes = |config: &PlanConfig, seed| {
+            let mut rrt_star = RRTStar::from_config(config, seed);
+            while !rrt_star.nodes.iter().any(|node| node.point.x >= 200.0) {
+                rrt_star.step();
+            }
+            let open = rrt_star.nodes.iter().filter(|node| node.point.x < 200.0).count();
+            while !rrt_star.is_finished() && !rrt_star.best_cost().is_finite() {
+                rrt_star.step();
+            }
+            assert!(rrt_star.best_cost().is_finite(), "seed {seed} never reached the cluttered goal");
+            open
+        };
+        let (mut fixed_total, mut scaled_total) = (0, 0);
+        for seed in 0..3 {
+            fixed_total += open_nodes(&config, seed);
+            scaled_total += open_nodes(&scaled, seed);
+        }
+        assert!(scaled_total * 3 < fixed_total, "{scaled_total} vs {fixed_total}");
+    }
+}
@@ -1,235 +1,1080 @@
 use macroquad::prelude::*;
-use ::rand::Rng;
-use ::rand::rngs::ThreadRng;
+use ::rand::{Rng, SeedableRng};
+use ::rand::rngs::StdRng;
+use rrt_rrt_star::{maps, plan_through, BiRrtStar, Bit, Bounds, COST_EPSILON, DoubleIntegrator, Fmt, GoalBias, NearestTieBreak, Node, NodeLimitPolicy, Obstacle, OccupancyGrid, OutOfBoundsPolicy, Path, PlanConfig, PlanStatus, Planner, Point, RRTStar, RadiusMode, RrtConnect, Sampler, StepMode, StepOutcome, Steering, Termination};
 
-// İki boyutlu bir noktayı temsil eden yapı
-#[derive(Clone, Copy)]
-struct Point {
-    x: f32,
-    y: f32,
+const USAGE: &str = "usage: rrt_rrt_star [--start X,Y] [--goal X,Y]... [--iters N] [--seed S] [--goal-bias P] [--informed] [--halton] [--smart] [--bridge] [--moving-obstacle] [--connect] [--algo rrt|rrt-star|fmt|bit|bi-rrt-star|compare] [--grid MAP.pgm] [--obstacles MAP.json] [--dubins RADIUS] [--reeds-shepp RADIUS] [--reverse-penalty P] [--kinodynamic] [--max-nodes N] [--forget] [--bounds MIN_X,MAX_X,MIN_Y,MAX_Y] [--lazy] [--any-angle] [--waypoints X1,Y1;X2,Y2;...]";
+
+// Komut satırı seçenekleri; verilmeyenler için varsayılan davranış korunur
+struct Args {
+    start: Option<Point>, // Başlangıç noktası (yoksa rastgele)
+    goal: Option<Point>,  // Hedef nokta (yoksa rastgele)
+    extra_goals: Vec<Point>, // Tekrarlanan --goal ile verilen diğer kabul edilebilir hedefler
+    iters: usize,         // İterasyon sınırı
+    seed: Option<u64>,    // Rastgele sayı üreteci tohumu (yoksa rastgele)
+    goal_bias: f32,       // Örneğin doğrudan hedef olarak seçilme olasılığı
+    informed: bool,       // Çözüm bulunduktan sonra informed elips içinden örneklenir
+    halton: bool,         // Tekdüze rastgele yerine Halton dizisiyle örneklenir
+    smart: bool,          // Çözüm bulunduktan sonra RRT*-Smart işaret noktaları çevresinden örneklenir
+    bridge: bool,         // Örneklerin bir kısmı köprü testiyle dar geçitlerden seçilir
+    moving_obstacle: bool, // Harita ortasında salınan bir engel eklenir
+    connect: bool,        // RRT* yerine iki ağaçlı RRT-Connect çalıştırılır
+    optimize: bool,       // false: yeniden bağlamasız düz RRT
+    fmt: bool,            // RRT* yerine `--iters` örnekli FMT* çalıştırılır
+    bit: bool,            // RRT* yerine BIT* çalıştırılır
+    bi: bool,             // RRT* yerine çift yönlü RRT* çalıştırılır
+    compare: bool,        // Aynı tohumlu RRT ve RRT* bölünmüş ekranda yan yana çalıştırılır
+    grid: Option<String>, // Engel olarak yüklenecek PGM doluluk haritası (1 piksel = 1 birim)
+    obstacles: Option<String>, // Engel listesinin yükleneceği JSON dosyası
+    dubins: Option<f32>,  // Verilirse kenarlar bu dönüş yarıçapıyla Dubins yollarıdır
+    reeds_shepp: Option<f32>, // Verilirse kenarlar bu dönüş yarıçapıyla Reeds-Shepp yollarıdır
+    reverse_penalty: f32, // Reeds-Shepp yollarında geri giden parçaların maliyet çarpanı
+    kinodynamic: bool,    // Düğümler hız da taşır; kenarlar sınırlı ivmeli çift integratör benzetimidir
+    max_nodes: usize,     // Ağaçtaki en fazla düğüm sayısı (0 = sınırsız)
+    bounds: Option<Bounds>, // Örnekleme bölgesi (None = 400x400 pencere)
+    lazy: bool,           // Kenarlar yalnızca en iyi yol adayına düştüğünde denetlenir
+    any_angle: bool,      // Ebeveyn seçiminde görüş hattı olan atalara doğrudan bağlanılır (Theta* tarzı)
+    forget: bool,         // Düğüm sınırında en kötü yapraklar unutularak planlamaya devam edilir
+    waypoints: Vec<Point>, // Başlangıçla hedef arasında sırayla uğranacak ara noktalar
 }
 
-impl Point {
-    // İki nokta arasındaki öklid mesafesini hesaplayan fonksiyon
-    fn distance(&self, other: &Point) -> f32 {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
-    }
+// Kinodinamik gösterimdeki hareket modeli
+const DEMO_DYNAMICS: DoubleIntegrator = DoubleIntegrator { max_acceleration: 30.0, max_speed: 40.0, time_step: 0.5, velocity_weight: 0.5 };
+
+// RRT*-Smart gösteriminde işaret noktalarına yönelik örneklerin oranı ve disk yarıçapı
+const SMART_SAMPLER: Sampler = Sampler::Smart { probability: 0.3, radius: 15.0 };
+
+// Köprü örneklemesi gösteriminde köprü denenen örneklerin oranı ve köprü uzunluğunun ölçeği
+const BRIDGE_SAMPLER: Sampler = Sampler::Bridge { probability: 0.5, sigma: 20.0 };
+
+// Ara nokta rotasında bacakların sırayla çizildiği renkler
+const LEG_COLORS: [Color; 5] = [GREEN, ORANGE, MAGENTA, SKYBLUE, PURPLE];
+
+// Bölünmüş ekranlı RRT/RRT* karşılaştırmasında durum satırları için alttan bırakılan yükseklik
+const COMPARE_HUD_HEIGHT: f32 = 40.0;
+
+// Kamera ağaca sığdırılırken kutunun her kenarına bırakılan pay
+const FIT_MARGIN: f32 = 20.0;
+
+// Görselleştirmede kare başına çalıştırılabilecek en fazla iterasyon
+const MAX_ITERS_PER_FRAME: usize = 1024;
+
+// Gösterilen yolun yumuşatılmasında denenen rastgele kısayol sayısı ve tohumu
+const SMOOTHING_ITERATIONS: usize = 200;
+const SMOOTHING_SEED: u64 = 0;
+// Gösterilen eğride her yol parçası için örneklenen nokta sayısı
+const SPLINE_SAMPLES: usize = 10;
+
+// Hız oklarının uzunluğu için saniye cinsinden ölçek (ok, düğümün bu sürede gideceği yeri gösterir)
+const VELOCITY_ARROW_SCALE: f32 = 0.2;
+
+// Yol takibi gösteriminde robotun hızı (birim/s) ve yeniden köklemede robotun arkasında
+// korunan düğümlerin en büyük uzaklığı
+const ROBOT_SPEED: f32 = 40.0;
+const REROOT_PRUNE_DISTANCE: f32 = 60.0;
+
+// Haritanın engelsiz oranı tahmin edilirken çekilen örnek sayısı
+const FREE_RATIO_SAMPLES: usize = 10_000;
+
+// Salınan engelin yarıçapı, salınım genliği ve açısal hızı (rad/s)
+const MOVING_OBSTACLE_RADIUS: f32 = 30.0;
+const MOVING_OBSTACLE_AMPLITUDE: f32 = 120.0;
+const MOVING_OBSTACLE_SPEED: f32 = 0.5;
+
+// Salınan engelin verilen andaki konumu: harita ortasında x ekseni boyunca sinüs hareketi
+fn moving_obstacle_at(time: f64) -> Obstacle {
+    let offset = MOVING_OBSTACLE_AMPLITUDE * (MOVING_OBSTACLE_SPEED * time as f32).sin();
+    Obstacle::Circle { center: Point { x: 200.0 + offset, y: 200.0 }, radius: MOVING_OBSTACLE_RADIUS }
 }
 
-// Düğüm yapısı: bir nokta, ebeveyn düğüm indeksi ve maliyet içerir
-#[derive(Clone)]
-struct Node {
-    point: Point,
-    parent: Option<usize>,
-    cost: f32,
+// "x,y" biçimindeki bir noktayı ayrıştırır
+fn parse_point(value: &str) -> Result<Point, String> {
+    let (x, y) = value
+        .split_once(',')
+        .ok_or_else(|| format!("expected X,Y but got '{value}'"))?;
+    let parse = |part: &str| {
+        part.trim()
+            .parse::<f32>()
+            .map_err(|_| format!("invalid coordinate '{part}' in '{value}'"))
+    };
+    Ok(Point { x: parse(x)?, y: parse(y)? })
 }
 
-impl Node {
-    // Yeni bir düğüm oluşturan yardımcı fonksiyon
-    fn new(point: Point, parent: Option<usize>, cost: f32) -> Self {
-        Node { point, parent, cost }
+// "min_x,max_x,min_y,max_y" biçimindeki bir örnekleme bölgesini ayrıştırır
+fn parse_bounds(value: &str) -> Result<Bounds, String> {
+    let parts = value
+        .split(',')
+        .map(|part| part.trim().parse::<f32>().map_err(|_| format!("invalid bound '{part}' in '{value}'")))
+        .collect::<Result<Vec<_>, _>>()?;
+    match parts[..] {
+        [min_x, max_x, min_y, max_y] if min_x < max_x && min_y < max_y => Ok(Bounds::new(min_x, max_x, min_y, max_y)),
+        [_, _, _, _] => Err(format!("bounds '{value}' must satisfy MIN_X < MAX_X and MIN_Y < MAX_Y")),
+        _ => Err(format!("expected MIN_X,MAX_X,MIN_Y,MAX_Y but got '{value}'")),
     }
 }
 
-// RRT* algoritmasını tanımlayan yapı
-struct RRTStar {
-    nodes: Vec<Node>, // Ağaçtaki düğümler
-    goal: Point,    // Hedef nokta
-    step_size: f32, // Adım boyutu
-    goal_threshold: f32, // Hedef eşiği
-    search_radius: f32, // Yakınlık yarıçapı
-    best_cost: f32,     // En iyi maliyet
-    rng: ThreadRng,     // Rastgele sayı üreteci
+// Ekrandaki görüntüyü zaman damgalı bir PNG dosyasına kaydeder ve dosya adını döner
+fn save_screenshot() -> Result<String, image::ImageError> {
+    let screen = get_screen_data();
+    let (width, height) = (screen.width as usize, screen.height as usize);
+
+    // Çerçeve arabelleği alttan üste saklanır, kaydetmeden önce dikey olarak çevrilir
+    let mut bytes = Vec::with_capacity(screen.bytes.len());
+    for row in screen.bytes.chunks_exact(width * 4).rev() {
+        bytes.extend_from_slice(row);
+    }
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+    let filename = format!("rrt_star_{timestamp}.png");
+    image::save_buffer(&filename, &bytes, width as u32, height as u32, image::ColorType::Rgba8)?;
+    Ok(filename)
 }
 
-impl RRTStar {
-    // RRT* algoritmasını başlatan fonksiyon
-    fn new(start: Point, goal: Point, step_size: f32, goal_threshold: f32, search_radius: f32) -> Self {
-        // Başlangıç düğümünü kök olarak ekler, maliyet sıfırdır
-        let root = Node::new(start, None, 0.0);
-        RRTStar {
-            nodes: vec![root],
-            goal,
-            step_size,
-            goal_threshold,
-            search_radius,
-            best_cost: f32::INFINITY, // Başlangıçta en iyi yol maliyeti sonsuz
-            rng: ::rand::thread_rng(),
+// Komut satırı argümanlarını ayrıştırır
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args { start: None, goal: None, extra_goals: Vec::new(), iters: 5000, seed: None, goal_bias: 0.0, informed: false, halton: false, smart: false, bridge: false, moving_obstacle: false, connect: false, optimize: true, fmt: false, bit: false, bi: false, compare: false, grid: None, obstacles: None, dubins: None, reeds_shepp: None, reverse_penalty: 1.0, kinodynamic: false, max_nodes: 0, forget: false, bounds: None, lazy: false, any_angle: false, waypoints: Vec::new() };
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {flag}"));
+        match flag.as_str() {
+            "--start" => parsed.start = Some(parse_point(&value()?)?),
+            "--goal" => {
+                let goal = parse_point(&value()?)?;
+                match parsed.goal {
+                    None => parsed.goal = Some(goal),
+                    Some(_) => parsed.extra_goals.push(goal),
+                }
+            }
+            "--iters" => {
+                let value = value()?;
+                parsed.iters = value.parse().map_err(|_| format!("invalid iteration count '{value}'"))?;
+            }
+            "--seed" => {
+                let value = value()?;
+                parsed.seed = Some(value.parse().map_err(|_| format!("invalid seed '{value}'"))?);
+            }
+            "--goal-bias" => {
+                let value = value()?;
+                parsed.goal_bias = value.parse().map_err(|_| format!("invalid goal bias '{value}'"))?;
+            }
+            "--informed" => parsed.informed = true,
+            "--halton" => parsed.halton = true,
+            "--smart" => parsed.smart = true,
+            "--bridge" => parsed.bridge = true,
+            "--moving-obstacle" => parsed.moving_obstacle = true,
+            "--connect" => parsed.connect = true,
+            "--grid" => parsed.grid = Some(value()?),
+            "--obstacles" => parsed.obstacles = Some(value()?),
+            "--dubins" => {
+                let value = value()?;
+                parsed.dubins = Some(value.parse().map_err(|_| format!("invalid turn radius '{value}'"))?);
+            }
+            "--reeds-shepp" => {
+                let value = value()?;
+                parsed.reeds_shepp = Some(value.parse().map_err(|_| format!("invalid turn radius '{value}'"))?);
+            }
+            "--kinodynamic" => parsed.kinodynamic = true,
+            "--max-nodes" => {
+                let value = value()?;
+                parsed.max_nodes = value.parse().map_err(|_| format!("invalid node limit '{value}'"))?;
+            }
+            "--forget" => parsed.forget = true,
+            "--lazy" => parsed.lazy = true,
+            "--any-angle" => parsed.any_angle = true,
+            "--waypoints" => {
+                parsed.waypoints = value()?.split(';').map(parse_point).collect::<Result<_, _>>()?;
+            }
+            "--bounds" => parsed.bounds = Some(parse_bounds(&value()?)?),
+            "--reverse-penalty" => {
+                let value = value()?;
+                parsed.reverse_penalty = value.parse().map_err(|_| format!("invalid reverse penalty '{value}'"))?;
+            }
+            "--algo" => {
+                match value()?.as_str() {
+                    "rrt" => parsed.optimize = false,
+                    "rrt-star" => parsed.optimize = true,
+                    "fmt" => parsed.fmt = true,
+                    "bit" => parsed.bit = true,
+                    "bi-rrt-star" => parsed.bi = true,
+                    "compare" => parsed.compare = true,
+                    other => return Err(format!("unknown algorithm '{other}', expected rrt, rrt-star, fmt, bit, bi-rrt-star or compare")),
+                }
+            }
+            _ => return Err(format!("unknown argument '{flag}'")),
         }
     }
+    Ok(parsed)
+}
 
-    // Rastgele bir nokta oluşturan fonksiyon
-    fn random_point(&mut self, min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> Point {
-        let x = self.rng.gen_range(min_x..max_x);
-        let y = self.rng.gen_range(min_y..max_y);
-        Point { x, y }
+// Engelleri gri olarak çizer
+fn draw_obstacles(obstacles: &[Obstacle]) {
+    for obstacle in obstacles {
+        match *obstacle {
+            Obstacle::Circle { center, radius } => draw_circle(center.x, center.y, radius, GRAY),
+            Obstacle::Rect { min, max } => draw_rectangle(min.x, min.y, max.x - min.x, max.y - min.y, GRAY),
+        }
     }
+}
 
-        // Verilen bir noktaya en yakın düğümün indeksini bulur
-    fn find_nearest(&self, point: &Point) -> usize {
-        self.nodes
-            .iter()
-            .enumerate()
-            .min_by(|(_, a), (_, b)| {
-                a.point
-                    .distance(point)
-                    .partial_cmp(&b.point.distance(point))
-                    .unwrap()
-            })
-            .map(|(index, _)| index)
-            .unwrap()
+// Doluluk ızgarasını dolu hücreleri gri olan bir dokuya çevirir
+fn grid_texture(grid: &OccupancyGrid) -> Texture2D {
+    let mut image = Image::gen_image_color(grid.width() as u16, grid.height() as u16, BLANK);
+    for row in 0..grid.height() {
+        for column in 0..grid.width() {
+            if grid.is_occupied(column as i64, row as i64) {
+                image.set_pixel(column as u32, row as u32, GRAY);
+            }
+        }
     }
+    let texture = Texture2D::from_image(&image);
+    texture.set_filter(FilterMode::Nearest);
+    texture
+}
+
+// Doluluk ızgarasının dokusunu dünya koordinatlarındaki yerine çizer
+fn draw_grid(grid: &OccupancyGrid, texture: &Texture2D) {
+    let origin = grid.origin();
+    let size = vec2(grid.width() as f32, grid.height() as f32) * grid.resolution();
+    draw_texture_ex(texture, origin.x, origin.y, WHITE, DrawTextureParams { dest_size: Some(size), ..Default::default() });
+}
 
-    // Bir noktadan diğerine belirli bir açıyla ilerleyen fonksiyon
-    fn steer(&self, from: &Point, to: &Point) -> Point {
-        let angle = (to.y - from.y).atan2(to.x - from.x);
-        Point {
-            x: from.x + self.step_size * angle.cos(),
-            y: from.y + self.step_size * angle.sin(),
+// Her düğümün hızını düğümden çıkan küçük bir okla çizer (hızı sıfır olanlar atlanır)
+fn draw_velocity_arrows(nodes: &[Node]) {
+    for node in nodes {
+        let (start, velocity) = (vec2(node.point.x, node.point.y), vec2(node.velocity.x, node.velocity.y));
+        if velocity == Vec2::ZERO {
+            continue;
+        }
+        let end = start + velocity * VELOCITY_ARROW_SCALE;
+        draw_line(start.x, start.y, end.x, end.y, 1.0, DARKGREEN);
+        // Ok ucu, ok yönüne göre ±150 derece döndürülmüş iki kısa çizgi
+        let head = velocity.normalize() * 3.0;
+        for angle in [2.6f32, -2.6] {
+            let wing = end + Vec2::from_angle(angle).rotate(head);
+            draw_line(end.x, end.y, wing.x, wing.y, 1.0, DARKGREEN);
         }
     }
+}
 
-    // Çarpışma kontrol fonksiyonu ??????
-    fn is_collision_free(&self, _point: &Point) -> bool {
-        true
+// Ağaçtaki düğümler arası bağlantıları verilen renkle çizer
+fn draw_tree(nodes: &[Node], color: Color) {
+    for node in nodes {
+        if let Some(parent_index) = node.parent {
+            let parent_node = &nodes[parent_index];
+            draw_line(node.point.x, node.point.y, parent_node.point.x, parent_node.point.y, 1.0, color);
+        }
     }
+}
+
+// Verilen kutuyu (kenarlarda pay bırakarak) ekran en-boy oranını koruyarak gösteren kamera;
+// y ekseni ekran koordinatlarındaki gibi aşağı doğrudur
+fn fit_camera(bounds: (Point, Point)) -> Camera2D {
+    fit_camera_with_aspect(bounds, screen_width() / screen_height())
+}
 
-    // Yeni bir düğüm ekler ve bu düğüm için maliyet hesaplar
-    fn add_node(&mut self, point: Point, parent_index: usize) -> usize {
-        let cost = self.nodes[parent_index].cost + point.distance(&self.nodes[parent_index].point);
-        let new_node = Node::new(point, Some(parent_index), cost);
-        self.nodes.push(new_node);
-        self.nodes.len() - 1
+// `fit_camera` ile aynı, ancak kutu verilen en-boy oranlı bir görüntü alanına sığdırılır
+fn fit_camera_with_aspect((min, max): (Point, Point), aspect: f32) -> Camera2D {
+    let mut width = max.x - min.x + 2.0 * FIT_MARGIN;
+    let mut height = max.y - min.y + 2.0 * FIT_MARGIN;
+    if width / height < aspect {
+        width = height * aspect;
+    } else {
+        height = width / aspect;
     }
+    Camera2D {
+        target: vec2((min.x + max.x) / 2.0, (min.y + max.y) / 2.0),
+        zoom: vec2(2.0 / width, 2.0 / height),
+        ..Default::default()
+    }
+}
+
+// RRT-Connect görselleştirmesi: başlangıç ağacı mavi, hedef ağacı turuncu, ağaçlar
+// buluştuğunda birleştirilen yol yeşil ile gösterilir
+async fn run_connect(config: &PlanConfig, seed: u64) {
+    let mut rrt_connect = match RrtConnect::try_from_config(config, seed) {
+        Ok(rrt_connect) => rrt_connect,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    let grid_texture = config.occupancy_grid.as_ref().map(|grid| (grid, grid_texture(grid)));
+
+    while !rrt_connect.is_finished() {
+        rrt_connect.step();
+
+        clear_background(WHITE);
+        if let Some((grid, texture)) = &grid_texture {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(rrt_connect.start_tree().obstacles());
+        draw_tree(rrt_connect.start_tree().nodes(), BLUE);
+        draw_tree(rrt_connect.goal_tree().nodes(), ORANGE);
+        draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+        draw_circle(config.goal.x, config.goal.y, 5.0, RED);
 
-    // Yeni eklenen düğümün yakınında olan düğümlerin indekslerini döndürür
-    fn near(&self, new_node_index: usize) -> Vec<usize> {
-        let new_node = &self.nodes[new_node_index];
-        self.nodes
-            .iter()
-            .enumerate()
-            .filter(|(i, node)| *i != new_node_index && node.point.distance(&new_node.point) < self.search_radius)
-            .map(|(i, _)| i)
-            .collect()
+        let status = format!(
+            "iterations: {}  nodes: {} + {}",
+            rrt_connect.iterations(),
+            rrt_connect.start_tree().nodes().len(),
+            rrt_connect.goal_tree().nodes().len()
+        );
+        draw_text(&status, 10.0, 420.0, 20.0, BLACK);
+
+        next_frame().await; // Ekranı güncelle
     }
 
-    // Daha kısa maliyetli yollar bulunursa düğümleri yeniden bağlar
-    fn rewire(&mut self, new_node_index: usize) {
-        let neighbors = self.near(new_node_index);
-        let new_node = self.nodes[new_node_index].clone();
+    // Ağaçlar buluştuğunda yol son kez gösterilir; pencere kapanana kadar sonuç ekranda kalır
+    if rrt_connect.best_cost().is_finite() {
+        println!(
+            "Trees connected after {} iterations with path cost: {}",
+            rrt_connect.iterations(),
+            rrt_connect.best_cost()
+        );
+    } else {
+        println!("Trees did not connect within {} iterations.", rrt_connect.iterations());
+    }
+    loop {
+        clear_background(WHITE);
+        if let Some((grid, texture)) = &grid_texture {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(rrt_connect.start_tree().obstacles());
+        draw_tree(rrt_connect.start_tree().nodes(), BLUE);
+        draw_tree(rrt_connect.goal_tree().nodes(), ORANGE);
+        for segment in rrt_connect.best_path().windows(2) {
+            draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, GREEN);
+        }
+        draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+        draw_circle(config.goal.x, config.goal.y, 5.0, RED);
+        next_frame().await;
+    }
+}
 
-        for &neighbor_index in &neighbors {
-            let neighbor = &self.nodes[neighbor_index];
-            let new_cost = new_node.cost + new_node.point.distance(&neighbor.point);
+// FMT* görselleştirmesi: örnekler gri noktalar, dalga cephesiyle büyüyen ağaç mavi, hedefe
+// ulaşıldığında yol yeşil ile gösterilir
+async fn run_fmt(config: &PlanConfig, seed: u64) {
+    let mut fmt = match Fmt::try_from_config(config, seed) {
+        Ok(fmt) => fmt,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    let grid_texture = config.occupancy_grid.as_ref().map(|grid| (grid, grid_texture(grid)));
 
-            // Eğer yeni maliyet mevcut maliyetten düşükse, düğümü yeniden bağla
-            if new_cost < neighbor.cost {
-                self.nodes[neighbor_index].parent = Some(new_node_index);
-                self.nodes[neighbor_index].cost = new_cost;
+    loop {
+        // Planlama bittikten sonra pencere kapanana kadar sonuç ekranda kalır
+        if !fmt.is_finished() {
+            fmt.step();
+            if fmt.is_finished() {
+                if fmt.best_cost().is_finite() {
+                    println!("FMT* reached the goal after {} expansions with path cost: {}", fmt.iterations(), fmt.best_cost());
+                } else {
+                    println!("FMT* wavefront ran out after {} expansions without reaching the goal.", fmt.iterations());
+                }
             }
         }
+
+        clear_background(WHITE);
+        if let Some((grid, texture)) = &grid_texture {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(fmt.space().obstacles());
+        for node in fmt.nodes() {
+            draw_circle(node.point.x, node.point.y, 1.0, LIGHTGRAY);
+        }
+        draw_tree(fmt.nodes(), BLUE);
+        for segment in fmt.best_path().windows(2) {
+            draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, GREEN);
+        }
+        draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+        draw_circle(config.goal.x, config.goal.y, 5.0, RED);
+
+        let status = format!("FMT*  expansions: {}  open: {}  samples: {}", fmt.iterations(), fmt.open_count(), fmt.nodes().len());
+        draw_text(&status, 10.0, 420.0, 20.0, BLACK);
+
+        next_frame().await; // Ekranı güncelle
     }
+}
+
+// BIT* görselleştirmesi: bağlanmamış örnekler gri noktalar, ağaç mavi, en iyi yol yeşil ile
+// gösterilir; durum satırında şu anki yığının sırası yer alır
+async fn run_bit(config: &PlanConfig, seed: u64) {
+    let mut bit = match Bit::try_from_config(config, seed) {
+        Ok(bit) => bit,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    let grid_texture = config.occupancy_grid.as_ref().map(|grid| (grid, grid_texture(grid)));
+
+    loop {
+        // Planlama bittikten sonra pencere kapanana kadar sonuç ekranda kalır
+        if !bit.is_finished() {
+            bit.step();
+            if bit.is_finished() {
+                if bit.best_cost().is_finite() {
+                    println!("BIT* finished after {} batches with path cost: {}", bit.batch(), bit.best_cost());
+                } else {
+                    println!("BIT* found no path within {} iterations.", bit.iterations());
+                }
+            }
+        }
 
-    // En iyi yolu günceller, eğer hedefe ulaşılmış ve maliyet iyileşmişse 'true' döner
-    fn update_best_path(&mut self) -> bool {
-        let last_node = &self.nodes[self.nodes.len() - 1];
-        let distance_to_goal = last_node.point.distance(&self.goal);
-        
-        if distance_to_goal < self.goal_threshold && last_node.cost < self.best_cost {
-            self.best_cost = last_node.cost;
-            return true;
+        clear_background(WHITE);
+        if let Some((grid, texture)) = &grid_texture {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(bit.space().obstacles());
+        for sample in bit.samples() {
+            draw_circle(sample.x, sample.y, 1.0, LIGHTGRAY);
         }
-        false
+        draw_tree(bit.nodes(), BLUE);
+        for segment in bit.best_path().windows(2) {
+            draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, GREEN);
+        }
+        draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+        draw_circle(config.goal.x, config.goal.y, 5.0, RED);
+
+        let status = format!("BIT*  batch: {}  iterations: {}  cost: {:.2}", bit.batch(), bit.iterations(), bit.best_cost());
+        draw_text(&status, 10.0, 420.0, 20.0, BLACK);
+
+        next_frame().await; // Ekranı güncelle
     }
+}
 
-    // En iyi yolu geri izleyerek bir noktalar dizisi döner
-    fn trace_path(&self) -> Vec<Point> {
-        let mut path = Vec::new();
-        let mut current_node_index = self.nodes.len() - 1;
+// Çift yönlü RRT* görselleştirmesi: başlangıç ağacı mavi, hedef ağacı turuncu, en iyi yol
+// yeşil ve iki ağacı birleştiren köprü kenarı kalın macenta ile gösterilir
+async fn run_bi(config: &PlanConfig, seed: u64) {
+    let mut bi = match BiRrtStar::try_from_config(config, seed) {
+        Ok(bi) => bi,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    let grid_texture = config.occupancy_grid.as_ref().map(|grid| (grid, grid_texture(grid)));
+
+    loop {
+        // Planlama bittikten sonra pencere kapanana kadar sonuç ekranda kalır
+        if !bi.is_finished() {
+            if bi.step().improved() {
+                println!("New optimal path with cost: {}", bi.best_cost());
+            }
+            if bi.is_finished() {
+                if bi.best_cost().is_finite() {
+                    println!("B-RRT* finished after {} iterations with path cost: {}", bi.iterations(), bi.best_cost());
+                } else {
+                    println!("B-RRT* found no path within {} iterations.", bi.iterations());
+                }
+            }
+        }
 
-        while let Some(parent_index) = self.nodes[current_node_index].parent {
-            path.push(self.nodes[current_node_index].point);
-            current_node_index = parent_index;
+        clear_background(WHITE);
+        if let Some((grid, texture)) = &grid_texture {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(bi.start_tree().obstacles());
+        draw_tree(bi.start_tree().nodes(), BLUE);
+        draw_tree(bi.goal_tree().nodes(), ORANGE);
+        for segment in bi.best_path().windows(2) {
+            draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, GREEN);
         }
-        path.push(self.nodes[current_node_index].point);
-        path.reverse();
-        path
+        if let Some((from, to)) = bi.bridge() {
+            draw_line(from.x, from.y, to.x, to.y, 4.0, MAGENTA);
+        }
+        draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+        draw_circle(config.goal.x, config.goal.y, 5.0, RED);
+
+        let status = format!(
+            "B-RRT*  iterations: {}  nodes: {} + {}  cost: {:.2}",
+            bi.iterations(),
+            bi.start_tree().nodes().len(),
+            bi.goal_tree().nodes().len(),
+            bi.best_cost()
+        );
+        draw_text(&status, 10.0, 420.0, 20.0, BLACK);
+
+        next_frame().await; // Ekranı güncelle
+    }
+}
+
+// Bölünmüş ekranda RRT ile RRT* karşılaştırması: aynı tohum ve engellerle kurulan iki
+// planlayıcı her karede birer iterasyon ilerler; solda yeniden bağlamasız RRT, sağda RRT*
+// kendi yarısında çizilir ve alttaki durum satırlarında iki maliyet yan yana gösterilir
+async fn run_compare(config: &PlanConfig, seed: u64) {
+    let mut planners = [false, true].map(|optimize| {
+        RRTStar::try_from_config(&PlanConfig { optimize, ..config.clone() }, seed).unwrap_or_else(|error| {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        })
+    });
+    let grid_texture = config.occupancy_grid.as_ref().map(|grid| (grid, grid_texture(grid)));
+    let bounds = planners[0].bounds();
+    let world = (Point { x: bounds.min_x, y: bounds.min_y }, Point { x: bounds.max_x, y: bounds.max_y });
+
+    loop {
+        // Planlama bittikten sonra pencere kapanana kadar sonuç ekranda kalır
+        for planner in &mut planners {
+            if !planner.is_finished() {
+                planner.step();
+                if planner.is_finished() {
+                    let name = if planner.optimize() { "RRT*" } else { "RRT" };
+                    println!("{name} finished after {} iterations with path cost: {}", planner.iterations(), planner.best_cost());
+                }
+            }
+        }
+
+        clear_background(WHITE);
+        // Her planlayıcı ekranın kendi yarısında, durum satırları için alttan pay bırakılarak
+        // çizilir; görüntü alanının y koordinatı pencerenin altından ölçülür
+        let half = screen_width() / 2.0;
+        let height = screen_height() - COMPARE_HUD_HEIGHT;
+        for (side, planner) in planners.iter().enumerate() {
+            let camera = Camera2D {
+                viewport: Some(((side as f32 * half) as i32, COMPARE_HUD_HEIGHT as i32, half as i32, height as i32)),
+                ..fit_camera_with_aspect(world, half / height)
+            };
+            set_camera(&camera);
+            if let Some((grid, texture)) = &grid_texture {
+                draw_grid(grid, texture);
+            }
+            draw_obstacles(planner.obstacles());
+            draw_tree(planner.nodes(), BLUE);
+            for segment in planner.best_path().windows(2) {
+                draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, GREEN);
+            }
+            draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+            draw_circle(config.goal.x, config.goal.y, 5.0, RED);
+        }
+
+        set_default_camera();
+        draw_line(half, 0.0, half, screen_height(), 2.0, BLACK);
+        for (side, planner) in planners.iter().enumerate() {
+            let status = format!(
+                "{}  iterations: {}  nodes: {}  cost: {:.2}",
+                if planner.optimize() { "RRT*" } else { "RRT" },
+                planner.iterations(),
+                planner.nodes().len(),
+                planner.best_cost()
+            );
+            draw_text(&status, side as f32 * half + 10.0, screen_height() - COMPARE_HUD_HEIGHT / 2.0, 20.0, BLACK);
+        }
+
+        next_frame().await; // Ekranı güncelle
+    }
+}
+
+// Ara nokta rotası görselleştirmesi: rota önceden bacak bacak planlanır, her bacak farklı
+// renkle çizilir ve ara noktalar sarı ile işaretlenir. Bir bacak başarısız olursa o bacağa
+// kadarki kısmi yol gösterilir ve başarısız bacağın hedefi kırmızı halkayla işaretlenir
+async fn run_waypoints(config: &PlanConfig, waypoints: &[Point], seed: u64) {
+    let (legs, failed) = match plan_through(waypoints, config, None, config.termination, seed) {
+        Ok(plan) => {
+            for (leg, stats) in plan.legs.iter().enumerate() {
+                println!("Leg {}: cost {:.2} after {} iterations", leg + 1, stats.best_cost, stats.iterations);
+            }
+            println!("Waypoint route found with total cost: {:.2}", plan.total_cost);
+            (plan.legs, None)
+        }
+        Err(error) => {
+            println!("{error}");
+            println!("Partial route through {} leg(s) has cost: {:.2}", error.legs.len(), error.partial.recompute_cost());
+            (error.legs, Some(error.leg))
+        }
+    };
+    let grid_texture = config.occupancy_grid.as_ref().map(|grid| (grid, grid_texture(grid)));
+
+    loop {
+        clear_background(WHITE);
+        if let Some((grid, texture)) = &grid_texture {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(&config.obstacles);
+        for (leg, stats) in legs.iter().enumerate() {
+            for segment in stats.path.windows(2) {
+                draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, LEG_COLORS[leg % LEG_COLORS.len()]);
+            }
+        }
+        for waypoint in waypoints {
+            draw_circle(waypoint.x, waypoint.y, 4.0, YELLOW);
+        }
+        draw_circle(config.start.x, config.start.y, 5.0, GREEN);
+        draw_circle(config.goal.x, config.goal.y, 5.0, RED);
+        if let Some(leg) = failed {
+            let target = waypoints.get(leg).unwrap_or(&config.goal);
+            draw_circle_lines(target.x, target.y, 9.0, 2.0, RED);
+        }
+
+        let status = match failed {
+            Some(leg) => format!("waypoints: {}  leg {} failed", waypoints.len(), leg + 1),
+            None => format!("waypoints: {}  legs: {}  cost: {:.2}", waypoints.len(), legs.len(), legs.iter().map(|stats| stats.best_cost).sum::<f32>()),
+        };
+        draw_text(&status, 10.0, 420.0, 20.0, BLACK);
+
+        next_frame().await; // Ekranı güncelle
     }
 }
 
 #[macroquad::main("RRT* Visualization")]
 async fn main() {
-    let mut rng = ::rand::thread_rng();
-    
-    // Rastgele bir başlangıç ve hedef noktası seçilir
-    let start = Point {
-        x: rng.gen_range(0.0..400.0),
-        y: rng.gen_range(0.0..400.0),
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            std::process::exit(2);
+        }
     };
-    let goal = Point {
-        x: rng.gen_range(0.0..400.0),
-        y: rng.gen_range(0.0..400.0),
+    let seed = args.seed.unwrap_or_else(|| ::rand::thread_rng().gen());
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // Verildiyse doluluk haritası yüklenir
+    let occupancy_grid = args.grid.as_deref().map(|path| {
+        OccupancyGrid::from_pgm(path, 1.0, Point { x: 0.0, y: 0.0 }).unwrap_or_else(|error| {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        })
+    });
+    let grid_texture = occupancy_grid.as_ref().map(grid_texture);
+
+    // Verildiyse engel listesi yüklenir; salınan engel her zaman ilk sıradadır
+    let mut obstacles = if args.moving_obstacle { vec![moving_obstacle_at(0.0)] } else { Vec::new() };
+    if let Some(path) = &args.obstacles {
+        match maps::load_obstacles(path) {
+            Ok(loaded) => obstacles.extend(loaded),
+            Err(error) => {
+                eprintln!("{error}\n{USAGE}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    // Verilmediyse engellerin dışında, örnekleme bölgesinin içinde rastgele bir başlangıç ve
+    // hedef noktası seçilir
+    let bounds = args.bounds.unwrap_or_default();
+    let is_free = |point: &Point| {
+        obstacles.iter().all(|obstacle| !obstacle.contains(point))
+            && occupancy_grid.as_ref().is_none_or(|grid| !grid.contains(point))
+    };
+    let mut random_point = || loop {
+        let point = Point { x: rng.gen_range(bounds.min_x..bounds.max_x), y: rng.gen_range(bounds.min_y..bounds.max_y) };
+        if is_free(&point) {
+            break point;
+        }
     };
-    
+    let start = args.start.unwrap_or_else(&mut random_point);
+    let goal = args.goal.unwrap_or_else(&mut random_point);
+
     // RRT* ağacı başlatılır
-    let mut rrt_star = RRTStar::new(start, goal, 10.0, 10.0, 15.0);
-    let mut optimal_path: Vec<Point> = Vec::new();
-    let mut iterations = 0;
-    let max_iterations = 5000; // İstenilen iterasyon sınırı
+    let config = PlanConfig {
+        start,
+        start_heading: 0.0,
+        goal,
+        goal_heading: None,
+        step_size: 10.0,
+        step_mode: StepMode::Fixed,
+        goal_threshold: 10.0,
+        radius_mode: RadiusMode::FixedRadius(15.0),
+        bounds,
+        obstacles,
+        occupancy_grid,
+        max_iterations: args.iters,
+        min_node_separation: 0.0,
+        collision_resolution: 0.0,
+        out_of_bounds: OutOfBoundsPolicy::Clamp,
+        nearest_tie_break: NearestTieBreak::LowestIndex,
+        stall_limit: 0,
+        max_nodes: args.max_nodes,
+        node_limit_policy: if args.forget { NodeLimitPolicy::Forget } else { NodeLimitPolicy::Stop },
+        lazy_collision: args.lazy,
+        termination: Some(Termination::CostWithinFactor(1.05)),
+        cost_regions: Vec::new(),
+        rewire_improvement_threshold: 0.0,
+        goal_bias: GoalBias::Fixed(args.goal_bias),
+        sampler: if args.informed {
+            Sampler::Informed
+        } else if args.smart {
+            SMART_SAMPLER
+        } else if args.bridge {
+            BRIDGE_SAMPLER
+        } else if args.halton {
+            Sampler::Halton
+        } else {
+            Sampler::Uniform
+        },
+        sampler_blend: Vec::new(),
+        try_goal_every: 0,
+        max_turn_angle: None,
+        optimize: args.optimize,
+        any_angle: args.any_angle,
+        steering: match (args.dubins, args.reeds_shepp) {
+            _ if args.kinodynamic => Steering::DoubleIntegrator(DEMO_DYNAMICS),
+            (_, Some(turn_radius)) => Steering::ReedsShepp { turn_radius, reverse_penalty: args.reverse_penalty },
+            (Some(turn_radius), None) => Steering::Dubins { turn_radius },
+            (None, None) => Steering::Straight,
+        },
+    };
+    if args.connect {
+        run_connect(&config, seed).await;
+        return;
+    }
+    if args.fmt {
+        run_fmt(&config, seed).await;
+        return;
+    }
+    if args.bit {
+        run_bit(&config, seed).await;
+        return;
+    }
+    if args.bi {
+        run_bi(&config, seed).await;
+        return;
+    }
+    if args.compare {
+        run_compare(&config, seed).await;
+        return;
+    }
+    if !args.waypoints.is_empty() {
+        run_waypoints(&config, &args.waypoints, seed).await;
+        return;
+    }
+    let mut rrt_star = match RRTStar::try_from_config(&config, seed) {
+        Ok(rrt_star) => rrt_star,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
+    // Diğer hedefler yalnızca RRT* ağacında kullanılır; yol en ucuz ulaşılan hedefte biter
+    for &goal in &args.extra_goals {
+        if let Err(error) = rrt_star.add_goal(goal) {
+            eprintln!("{error}\n{USAGE}");
+            std::process::exit(2);
+        }
+    }
+    // Izgarada hedef başlangıçtan tamamen ayrılmışsa planlamaya hiç başlanmaz
+    if !rrt_star.goal_reachable() {
+        println!("Goal is not connected to the start on the occupancy grid; it is unreachable.");
+        return;
+    }
+    println!("Map is {:.1}% free.", rrt_star.estimate_free_ratio(FREE_RATIO_SAMPLES) * 100.0);
+    let mut show_costs = false; // C tuşu ile düğüm maliyeti renklendirmesi açılıp kapatılır
+    let mut show_radius = false; // R tuşu ile yeniden bağlama yarıçapı gösterimi açılıp kapatılır
+    let mut show_rejections = false; // X tuşu ile çarpışan örneklerin gösterimi açılıp kapatılır
+    let mut show_informed = args.informed; // E tuşu ile informed elips ve başlangıç-hedef doğrusu açılıp kapatılır
+    let mut show_smoothed = false; // P tuşu ile kısayollarla yumuşatılmış yolun gösterimi açılıp kapatılır
+    let mut show_spline = false;  // K tuşu ile yumuşatılmış yoldan geçen eğrinin gösterimi açılıp kapatılır
+    let mut smoothed = (Path::default(), Path::default(), Path::default()); // Son yumuşatılan ham yol, yumuşatılmış hali ve eğrisi
+    let mut last_added = None;   // En son eklenen düğümün indeksi
+    let mut fit_to_tree = false;  // F tuşu ile kameranın ağaca sığdırılması açılıp kapatılır
+    let mut iters_per_frame: usize = 1; // Her karede çalıştırılan iterasyon sayısı (+/- tuşları)
+    let mut driving = false;      // G tuşu ile robot en iyi yol boyunca sürülür ve ağaç robotta yeniden köklenir
+    let mut robot_progress = 0.0; // Robotun kökten yoldaki sonraki düğüme doğru katettiği mesafe
+    rrt_star.set_reroot_prune_distance(REROOT_PRUNE_DISTANCE);
 
-    loop {
-        if iterations >= max_iterations {
-            println!("Optimal path found within iteration limit.");
-            break;
+    while !rrt_star.is_finished() {
+        // Salınan engeli yeni konumuna taşı ve artık çarpışan dalları ağaca yeniden bağla;
+        // bağlanamayanlar silinir
+        if args.moving_obstacle {
+            rrt_star.update_obstacle(0, moving_obstacle_at(get_time()));
+            if rrt_star.repair().removed > 0 {
+                last_added = None;
+            }
         }
 
-        // Rastgele bir nokta oluştur ve ağaca en yakın düğümü bul
-        let rand_point = rrt_star.random_point(0.0, 400.0, 0.0, 400.0);
-        let nearest_index = rrt_star.find_nearest(&rand_point);
-        let nearest_node = &rrt_star.nodes[nearest_index];
-        let new_point = rrt_star.steer(&nearest_node.point, &rand_point);
+        // Sol tıklama hedefi tıklanan noktaya taşır; ağaç korunur, yeni hedef için en iyi yol
+        // var olan düğümlerden yeniden seçilir ve iterasyon bütçesi yenilenir
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let (x, y) = mouse_position();
+            let click = if fit_to_tree { fit_camera(rrt_star.tree_bounds()).screen_to_world(vec2(x, y)) } else { vec2(x, y) };
+            let goal = Point { x: click.x, y: click.y };
+            match rrt_star.set_goal(goal) {
+                Ok(()) => {
+                    rrt_star.set_max_iterations(rrt_star.iterations() + args.iters);
+                    println!("Goal moved to ({:.1}, {:.1}); best cost is now {}", goal.x, goal.y, rrt_star.best_cost());
+                }
+                Err(error) => eprintln!("Cannot move the goal: {error}"),
+            }
+        }
 
-        // Çarpışma kontrolünden geçerse düğümü ekle ve yeniden bağla
-        if rrt_star.is_collision_free(&new_point) {
-            let new_node_index = rrt_star.add_node(new_point, nearest_index);
-            rrt_star.rewire(new_node_index);
+        // Sürüşte robot kökten yoldaki sonraki düğüme ilerler; o düğüme vardığında ağaç orada
+        // yeniden köklenir ve robotun gerisinde kalan dallar silinir
+        if is_key_pressed(KeyCode::G) {
+            driving = !driving;
+        }
+        if let Some(&next) = rrt_star.best_path().get(1).filter(|_| driving) {
+            robot_progress += ROBOT_SPEED * get_frame_time();
+            if robot_progress >= rrt_star.nodes()[0].point.distance(&next) {
+                robot_progress = 0.0;
+                rrt_star.reroot_at(next);
+                last_added = None;
+            }
+        }
+
+        // + ve - tuşları kare başına çalıştırılan iterasyon sayısını ikiye katlar ya da yarıya indirir
+        if is_key_pressed(KeyCode::Equal) || is_key_pressed(KeyCode::KpAdd) {
+            iters_per_frame = (iters_per_frame * 2).min(MAX_ITERS_PER_FRAME);
+        }
+        if is_key_pressed(KeyCode::Minus) || is_key_pressed(KeyCode::KpSubtract) {
+            iters_per_frame = (iters_per_frame / 2).max(1);
+        }
 
-            // Yeni bir en iyi yol bulunursa optimal yolu güncelle
-            if rrt_star.update_best_path() {
-                optimal_path = rrt_star.trace_path();
-                println!("New optimal path with cost: {}", rrt_star.best_cost);
+        // Bu karenin iterasyonlarını çalıştır
+        for _ in 0..iters_per_frame {
+            if rrt_star.is_finished() {
+                break;
+            }
+            let outcome = rrt_star.step();
+            if let StepOutcome::Added { index, .. } = outcome {
+                last_added = Some(index);
             }
+            // Yeni bir en iyi yol bulunursa maliyetini bildir ve artık işe yaramayan düğümleri buda
+            if outcome.improved() {
+                println!("New optimal path with cost: {}", rrt_star.recompute_best_cost());
+                if rrt_star.prune() > 0 {
+                    last_added = None;
+                }
+            }
+        }
+
+        // F tuşu ile kamera her karede ağacın kapladığı bölgeye yakınlaştırılır
+        if is_key_pressed(KeyCode::F) {
+            fit_to_tree = !fit_to_tree;
+        }
+        if fit_to_tree {
+            set_camera(&fit_camera(rrt_star.tree_bounds()));
         }
 
         clear_background(WHITE);
 
-        // Düğümler arası bağlantıları çiz
-        for node in &rrt_star.nodes {
-            if let Some(parent_index) = node.parent {
-                let parent_node = &rrt_star.nodes[parent_index];
-                draw_line(
-                    node.point.x,
-                    node.point.y,
-                    parent_node.point.x,
-                    parent_node.point.y,
-                    1.0,
-                    BLUE,
-                );
+        // Maliyet bölgelerini çarpanla koyulaşan yarı saydam kahverengi ile çiz
+        for &(region, multiplier) in rrt_star.cost_regions() {
+            let color = Color::new(0.55, 0.35, 0.15, (0.1 * multiplier).min(0.6));
+            match region {
+                Obstacle::Circle { center, radius } => draw_circle(center.x, center.y, radius, color),
+                Obstacle::Rect { min, max } => draw_rectangle(min.x, min.y, max.x - min.x, max.y - min.y, color),
+            }
+        }
+
+        // Engelleri ve düğümler arası bağlantıları çiz
+        if let (Some(grid), Some(texture)) = (rrt_star.occupancy_grid(), &grid_texture) {
+            draw_grid(grid, texture);
+        }
+        draw_obstacles(rrt_star.obstacles());
+        // Varsayılandan farklı bir örnekleme bölgesi gri bir çerçeveyle gösterilir
+        if args.bounds.is_some() {
+            let Bounds { min_x, max_x, min_y, max_y } = rrt_star.bounds();
+            draw_rectangle_lines(min_x, min_y, max_x - min_x, max_y - min_y, 2.0, GRAY);
+        }
+        // Dubins kenarları yay olarak çizilir
+        for index in 1..rrt_star.nodes().len() {
+            for segment in rrt_star.edge_polyline(index).windows(2) {
+                draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 1.0, BLUE);
+            }
+        }
+
+        if rrt_star.double_integrator().is_some() {
+            draw_velocity_arrows(rrt_star.nodes());
+        }
+
+        // Maliyet görünümünde her düğüm maliyetine göre maviden kırmızıya renklendirilir;
+        // renkler en iyi yolun maliyetine (yoksa en yüksek maliyete) göre ölçeklenir
+        if is_key_pressed(KeyCode::C) {
+            show_costs = !show_costs;
+        }
+        if show_costs {
+            let scale = if rrt_star.best_cost().is_finite() {
+                rrt_star.best_cost()
+            } else {
+                rrt_star.nodes().iter().map(|node| node.cost).fold(0.0, f32::max)
+            };
+            for node in rrt_star.nodes() {
+                let t = if scale > 0.0 { (node.cost / scale).min(1.0) } else { 0.0 };
+                draw_circle(node.point.x, node.point.y, 2.0, Color::new(t, 0.0, 1.0 - t, 1.0));
+            }
+        }
+
+        // Yarıçap görünümünde son eklenen düğümün çevresinde yeniden bağlama yarıçapı ve bu
+        // yarıçap içindeki komşular turuncu ile gösterilir
+        if is_key_pressed(KeyCode::R) {
+            show_radius = !show_radius;
+        }
+        if let Some(index) = last_added.filter(|&index| show_radius && index < rrt_star.nodes().len()) {
+            let center = rrt_star.nodes()[index].point;
+            let radius = rrt_star.search_radius();
+            draw_circle_lines(center.x, center.y, radius, 1.0, ORANGE);
+            for neighbor in rrt_star.near_point(&center, radius) {
+                let point = rrt_star.nodes()[neighbor].point;
+                draw_circle(point.x, point.y, 3.0, ORANGE);
+            }
+        }
+
+        // Reddedilen örnekler gösterimi: çarpışma nedeniyle reddedilen son noktalar yarı saydam
+        // kırmızı noktalar olarak çizilir, eskidikçe soluklaşır ve birkaç kare sonra kaybolur
+        if is_key_pressed(KeyCode::X) {
+            show_rejections = !show_rejections;
+        }
+        if show_rejections {
+            let rejections = rrt_star.recent_rejections();
+            for (age, point) in rejections.iter().rev().enumerate() {
+                let alpha = 0.5 * (1.0 - age as f32 / rejections.len() as f32);
+                draw_circle(point.x, point.y, 2.0, Color::new(1.0, 0.0, 0.0, alpha));
+            }
+        }
+
+        // Yolu iyileştirebilecek örneklerin elipsi (informed örneklemede örneklerin seçildiği
+        // bölge) ve odaklarını birleştiren başlangıç-hedef doğrusu ince çizgilerle gösterilir
+        if is_key_pressed(KeyCode::E) {
+            show_informed = !show_informed;
+        }
+        if show_informed {
+            let (start, goal) = (rrt_star.nodes()[0].point, rrt_star.goal());
+            draw_line(start.x, start.y, goal.x, goal.y, 1.0, DARKPURPLE);
+        }
+        if let Some(ellipse) = rrt_star.informed_ellipse().filter(|_| show_informed) {
+            draw_ellipse_lines(
+                ellipse.center.x,
+                ellipse.center.y,
+                ellipse.semi_major,
+                ellipse.semi_minor,
+                ellipse.angle.to_degrees(),
+                1.0,
+                DARKPURPLE,
+            );
+        }
+
+        // RRT*-Smart işaret noktaları küçük turuncu dairelerle gösterilir
+        if args.smart {
+            for beacon in rrt_star.smart_waypoints() {
+                draw_circle_lines(beacon.x, beacon.y, 4.0, 2.0, ORANGE);
             }
         }
 
         // Optimal yol (eğer bulunmuşsa) yeşil çizgi ile gösterilir
-        for i in 1..optimal_path.len() {
-            let start = optimal_path[i - 1];
-            let end = optimal_path[i];
-            draw_line(start.x, start.y, end.x, end.y, 2.0, GREEN);
+        // (planlayıcının önbelleğinden her karede okunur, böylece ağaçla hep uyumludur)
+        for segment in rrt_star.best_path_polyline().windows(2) {
+            draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, GREEN);
+        }
+
+        // Yumuşatılmış yol ham yolun üzerine macenta ile, ondan geçen eğri altın rengiyle çizilir;
+        // yalnızca en iyi yol değiştiğinde yeniden hesaplanır. Kısayollar düz parçalar olduğundan
+        // eğrisel yönlendirmede gösterilmez
+        if is_key_pressed(KeyCode::P) {
+            show_smoothed = !show_smoothed;
+        }
+        if is_key_pressed(KeyCode::K) {
+            show_spline = !show_spline;
+        }
+        if (show_smoothed || show_spline) && rrt_star.steering() == Steering::Straight {
+            if smoothed.0 != *rrt_star.best_path() {
+                let raw = rrt_star.best_path().clone();
+                let mut rng = StdRng::seed_from_u64(SMOOTHING_SEED);
+                let path = raw.shortcut_greedy(&rrt_star).shortcut(&rrt_star, SMOOTHING_ITERATIONS, &mut rng);
+                let spline = path.smooth_spline(&rrt_star, SPLINE_SAMPLES);
+                smoothed = (raw, path, spline);
+            }
+            for (show, path, color) in [(show_smoothed, &smoothed.1, MAGENTA), (show_spline, &smoothed.2, GOLD)] {
+                for segment in path.windows(2).filter(|_| show) {
+                    draw_line(segment[0].x, segment[0].y, segment[1].x, segment[1].y, 2.0, color);
+                }
+            }
         }
 
         // Başlangıç ve hedef noktalarını daire olarak çiz
-        draw_circle(rrt_star.nodes[0].point.x, rrt_star.nodes[0].point.y, 5.0, GREEN);
-        draw_circle(rrt_star.goal.x, rrt_star.goal.y, 5.0, RED);
+        draw_circle(rrt_star.nodes()[0].point.x, rrt_star.nodes()[0].point.y, 5.0, GREEN);
+        // Sürülen robot kökten sonraki yol düğümüne giden kenarın üzerinde siyah bir noktadır
+        if let Some(&next) = rrt_star.best_path().get(1).filter(|_| driving) {
+            let root = rrt_star.nodes()[0].point;
+            let robot = root.lerp(&next, (robot_progress / root.distance(&next)).min(1.0));
+            draw_circle(robot.x, robot.y, 4.0, BLACK);
+        }
+        for goal in rrt_star.goals() {
+            draw_circle(goal.x, goal.y, 5.0, RED);
+        }
+        // Birden çok hedef varsa en iyi yolun ulaştığı hedef halkayla işaretlenir
+        if let Some(reached) = rrt_star.reached_goal().filter(|_| !args.extra_goals.is_empty()) {
+            let goal = rrt_star.goals()[reached];
+            draw_circle_lines(goal.x, goal.y, 10.0, 2.0, RED);
+        }
+
+        // Durum göstergesi ekran koordinatlarında çizilir
+        set_default_camera();
+
+        // Durum göstergesi; artımlı maliyet yoldan yeniden hesaplanan maliyetten sapmışsa
+        // iki değer de kırmızı ile gösterilir
+        let stored = rrt_star.best_cost();
+        let recomputed = rrt_star.recompute_best_cost();
+        let status = format!(
+            "{}  iterations: {}  nodes: {}{}  rewires: {}  cost: {recomputed:.2}  speed: {iters_per_frame}/frame",
+            if rrt_star.double_integrator().is_some() {
+                "Kinodynamic RRT"
+            } else if rrt_star.optimize() {
+                "RRT*"
+            } else {
+                "RRT"
+            },
+            rrt_star.iterations(),
+            rrt_star.nodes().len(),
+            // Düğüm sınırı varsa doluluk da gösterilir
+            match rrt_star.max_nodes() {
+                0 => String::new(),
+                max_nodes => format!("/{max_nodes}"),
+            },
+            rrt_star.rewires()
+        );
+        draw_text(&status, 10.0, 420.0, 20.0, BLACK);
+        if stored.is_finite() && (stored - recomputed).abs() > COST_EPSILON * recomputed.max(1.0) {
+            let drift = format!("cost drift: stored {stored:.4} vs recomputed {recomputed:.4}");
+            draw_text(&drift, 10.0, 440.0, 20.0, RED);
+        }
+        // Tembel kipte yapılan ve ertelenen kenar denetimleri gösterilir
+        if rrt_star.lazy_collision() {
+            let checks = format!("edge checks: {}  deferred: {}", rrt_star.collision_checks(), rrt_star.skipped_collision_checks());
+            draw_text(&checks, 10.0, 460.0, 20.0, DARKGRAY);
+        }
+
+        // S tuşu ile mevcut görüntünün ekran görüntüsü alınır
+        if is_key_pressed(KeyCode::S) {
+            match save_screenshot() {
+                Ok(filename) => println!("Saved screenshot to {filename}"),
+                Err(error) => eprintln!("Failed to save screenshot: {error}"),
+            }
+        }
+        // H tuşu ile yakınsama eğrisi (iterasyona göre en iyi maliyet) CSV olarak kaydedilir
+        if is_key_pressed(KeyCode::H) {
+            let filename = format!("rrt_star_convergence_{seed}_{}.csv", rrt_star.iterations());
+            match rrt_star.save_convergence_csv(&filename) {
+                Ok(()) => println!("Saved convergence history to {filename}"),
+                Err(error) => eprintln!("Failed to save convergence history: {error}"),
+            }
+        }
 
         next_frame().await; // Ekranı güncelle
-        iterations += 1;    // İterasyon sayacını artır
+    }
+
+    // Planlamanın gerçek sonucunu bildir
+    match rrt_star.status() {
+        PlanStatus::SolutionFound { cost } if cost == 0.0 => {
+            println!("Start already satisfies the goal; trivial path with cost: {cost}")
+        }
+        PlanStatus::SolutionFound { cost } if rrt_star.is_converged() => {
+            println!("Converged after {} iterations with cost: {cost}", rrt_star.iterations())
+        }
+        PlanStatus::SolutionFound { cost } => println!("Optimal path found within iteration limit with cost: {cost}"),
+        PlanStatus::ExhaustedNoSolution => {
+            println!("No path found within {} iterations.", rrt_star.iterations());
+            if let Some(closest) = rrt_star.closest_partial_path().and_then(|path| path.last().copied()) {
+                let distance = rrt_star.goals().iter().map(|goal| closest.distance(goal)).fold(f32::INFINITY, f32::min);
+                println!("Closest node reached is {distance} from the goal.");
+            }
+        }
+        PlanStatus::Planning => {}
+    }
+    if let Some(reached) = rrt_star.reached_goal().filter(|_| !args.extra_goals.is_empty()) {
+        let goal = rrt_star.goals()[reached];
+        println!("Reached goal {reached} at ({}, {}).", goal.x, goal.y);
     }
 }
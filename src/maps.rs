@@ -0,0 +1,74 @@
+use std::fs::File;
+use std::io::BufReader;
+
+use crate::{Obstacle, Point, RrtError};
+
+// Karşılaştırmalar ve kıyaslamalar için hazır engel haritaları (400x400 bölge için)
+
+// Haritanın ortasına dağılmış birkaç daire ve dikdörtgenden oluşan standart harita;
+// köşeler (başlangıç/hedef için) boş bırakılmıştır
+pub fn standard() -> Vec<Obstacle> {
+    vec![
+        Obstacle::Rect { min: Point { x: 80.0, y: 60.0 }, max: Point { x: 120.0, y: 260.0 } },
+        Obstacle::Rect { min: Point { x: 180.0, y: 140.0 }, max: Point { x: 340.0, y: 180.0 } },
+        Obstacle::Rect { min: Point { x: 260.0, y: 260.0 }, max: Point { x: 300.0, y: 360.0 } },
+        Obstacle::Circle { center: Point { x: 200.0, y: 300.0 }, radius: 40.0 },
+        Obstacle::Circle { center: Point { x: 300.0, y: 70.0 }, radius: 30.0 },
+    ]
+}
+
+// Haritayı ortadan bölen, yalnızca dar bir geçit bırakan duvar; dar geçit problemlerinde
+// örnekleme stratejilerini karşılaştırmak için
+pub fn narrow_passage() -> Vec<Obstacle> {
+    narrow_gap(20.0)
+}
+
+// `narrow_passage` duvarının geçidi verilen genişlikte olanı (geçit y = 200 çevresinde)
+pub fn narrow_gap(width: f32) -> Vec<Obstacle> {
+    vec![
+        Obstacle::Rect { min: Point { x: 190.0, y: 0.0 }, max: Point { x: 210.0, y: 200.0 - width / 2.0 } },
+        Obstacle::Rect { min: Point { x: 190.0, y: 200.0 + width / 2.0 }, max: Point { x: 210.0, y: 400.0 } },
+    ]
+}
+
+// Üç duvarın sırayla üstte ve altta geçit bıraktığı yılan biçimli labirent; başlangıç sol
+// alt, hedef sağ üst köşededir ve yol her duvarın ucundan dolanmak zorundadır
+pub fn maze() -> Vec<Obstacle> {
+    vec![
+        Obstacle::Rect { min: Point { x: 100.0, y: 0.0 }, max: Point { x: 120.0, y: 300.0 } },
+        Obstacle::Rect { min: Point { x: 190.0, y: 100.0 }, max: Point { x: 210.0, y: 400.0 } },
+        Obstacle::Rect { min: Point { x: 280.0, y: 0.0 }, max: Point { x: 300.0, y: 300.0 } },
+    ]
+}
+
+// Başlangıcı (120, 120) içine alan, ağzı hedefin (sağ alt köşe) tersine, sola bakan dar bir
+// yarıktan ibaret kafes ("böcek kapanı"); tek ağaçlı planlayıcılar çıkışı bulmakta zorlanır,
+// dışarıdan büyüyen bir ağaç ise yarığa kolayca ulaşır
+pub fn bug_trap() -> Vec<Obstacle> {
+    vec![
+        Obstacle::Rect { min: Point { x: 60.0, y: 60.0 }, max: Point { x: 180.0, y: 70.0 } },
+        Obstacle::Rect { min: Point { x: 60.0, y: 170.0 }, max: Point { x: 180.0, y: 180.0 } },
+        Obstacle::Rect { min: Point { x: 170.0, y: 60.0 }, max: Point { x: 180.0, y: 180.0 } },
+        Obstacle::Rect { min: Point { x: 60.0, y: 60.0 }, max: Point { x: 70.0, y: 110.0 } },
+        Obstacle::Rect { min: Point { x: 60.0, y: 122.0 }, max: Point { x: 70.0, y: 180.0 } },
+    ]
+}
+
+// Sol yarısı boş, sağ yarısı 40 birim aralıklı küçük dairelerle dolu harita; hedef (380, 380)
+// dairelerin arasındadır. Açık alanla sıkışık bölgenin birlikte olduğu senaryolar için
+pub fn half_cluttered() -> Vec<Obstacle> {
+    (0..4)
+        .flat_map(|column| (0..9).map(move |row| (column, row)))
+        .map(|(column, row)| Obstacle::Circle {
+            center: Point { x: 230.0 + 40.0 * column as f32, y: 30.0 + 40.0 * row as f32 },
+            radius: 10.0,
+        })
+        .collect()
+}
+
+// JSON dosyasından bir engel listesi yükler (`Obstacle` dizisi, ör.
+// `[{"Circle": {"center": {"x": 200.0, "y": 200.0}, "radius": 30.0}}]`)
+pub fn load_obstacles(path: &str) -> Result<Vec<Obstacle>, RrtError> {
+    let reader = BufReader::new(File::open(path)?);
+    Ok(serde_json::from_reader(reader)?)
+}
@@ -0,0 +1,66 @@
+use crate::geometry::{point_segment_distance, segment_segment_distance};
+use serde::{Deserialize, Serialize};
+
+use crate::Point;
+
+// Haritadaki bir engeli temsil eden yapı
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Obstacle {
+    // Merkezi ve yarıçapı verilen dairesel engel
+    Circle { center: Point, radius: f32 },
+    // Köşeleri (min, max) verilen eksenlere hizalı dikdörtgen engel
+    Rect { min: Point, max: Point },
+}
+
+impl Obstacle {
+    // Noktanın engelin içinde olup olmadığını kontrol eder
+    pub fn contains(&self, point: &Point) -> bool {
+        match self {
+            Obstacle::Circle { center, radius } => center.distance(point) <= *radius,
+            Obstacle::Rect { min, max } => {
+                point.x >= min.x && point.x <= max.x && point.y >= min.y && point.y <= max.y
+            }
+        }
+    }
+
+    // Noktanın engele olan en kısa mesafesi (içerideyse 0)
+    pub fn distance(&self, point: &Point) -> f32 {
+        match self {
+            Obstacle::Circle { center, radius } => (center.distance(point) - radius).max(0.0),
+            Obstacle::Rect { min, max } => {
+                let dx = (min.x - point.x).max(point.x - max.x).max(0.0);
+                let dy = (min.y - point.y).max(point.y - max.y).max(0.0);
+                dx.hypot(dy)
+            }
+        }
+    }
+
+    // [a, b] doğru parçasının engele olan en kısa mesafesi (kesişiyorsa 0)
+    pub fn segment_distance(&self, a: &Point, b: &Point) -> f32 {
+        match self {
+            Obstacle::Circle { center, radius } => (point_segment_distance(center, a, b) - radius).max(0.0),
+            Obstacle::Rect { min, max } => {
+                if self.contains(a) || self.contains(b) {
+                    return 0.0;
+                }
+                let corners = [
+                    *min,
+                    Point { x: max.x, y: min.y },
+                    *max,
+                    Point { x: min.x, y: max.y },
+                ];
+                (0..4)
+                    .map(|i| segment_segment_distance(a, b, &corners[i], &corners[(i + 1) % 4]))
+                    .fold(f32::INFINITY, f32::min)
+            }
+        }
+    }
+
+    // Engelin kapladığı alan (serbest alan ölçüsü hesabı için)
+    pub fn area(&self) -> f32 {
+        match self {
+            Obstacle::Circle { radius, .. } => std::f32::consts::PI * radius * radius,
+            Obstacle::Rect { min, max } => (max.x - min.x) * (max.y - min.y),
+        }
+    }
+}
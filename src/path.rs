@@ -0,0 +1,250 @@
+use std::ops::Deref;
+
+use rand::Rng;
+
+use crate::{CollisionChecker, Point};
+
+// Başlangıçtan hedefe sıralı noktalar dizisi olarak bir yol
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    points: Vec<Point>,
+}
+
+impl Path {
+    // Verilen noktalardan bir yol oluşturur
+    pub fn new(points: Vec<Point>) -> Self {
+        Path { points }
+    }
+
+    // Yolun noktaları
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    // Yolu boşaltır
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    // Yolun maliyetini parça uzunluklarından sıfırdan hesaplar. Ağaçtaki maliyetler
+    // `ebeveyn maliyeti + kenar` olarak f32'de biriktiği için uzun zincirlerde kayabilir;
+    // burada toplam f64'te tutulur ve yalnızca sonuç f32'ye çevrilir
+    pub fn recompute_cost(&self) -> f32 {
+        self.recompute_cost_with(|a, b| a.distance(b))
+    }
+
+    // `recompute_cost` ile aynı, ancak her parçanın maliyeti verilen fonksiyonla hesaplanır
+    // (örneğin maliyet bölgeleri için `RRTStar::edge_cost`)
+    pub fn recompute_cost_with(&self, edge_cost: impl Fn(&Point, &Point) -> f32) -> f32 {
+        self.points
+            .windows(2)
+            .map(|segment| f64::from(edge_cost(&segment[0], &segment[1])))
+            .sum::<f64>() as f32
+    }
+
+    // Rastgele kısayollarla yumuşatma: her iterasyonda yol üzerinde yay uzunluğuna göre iki
+    // rastgele nokta seçilir ve aradaki kısım, denetleyiciye göre engelsizse tek bir doğru
+    // parçasıyla değiştirilir. Seçilen noktalar ara noktalara denk gelmek zorunda olmadığından
+    // köşeler de kırpılır. Üçgen eşitsizliğinden uzunluk hiçbir zaman artmaz ve eklenen her
+    // parça (bölünen uç parçalar dahil) denetlenmiştir; uç noktalar korunur
+    pub fn shortcut(&self, checker: &dyn CollisionChecker, iterations: usize, rng: &mut impl Rng) -> Path {
+        let mut points = self.points.clone();
+        for _ in 0..iterations {
+            if points.len() < 3 {
+                break;
+            }
+            // Her noktaya kadar biriken yay uzunluğu
+            let mut arc = vec![0.0];
+            for segment in points.windows(2) {
+                arc.push(arc[arc.len() - 1] + segment[0].distance(&segment[1]));
+            }
+            let total = arc[arc.len() - 1];
+            if total <= 0.0 {
+                break;
+            }
+            let (a, b) = (rng.gen_range(0.0..total), rng.gen_range(0.0..total));
+            let (i, p) = point_at(&points, &arc, a.min(b));
+            let (j, q) = point_at(&points, &arc, a.max(b));
+            // Aynı parça üzerindeki iki nokta arasında kısaltılacak bir şey yoktur. Bölünen uç
+            // parçalar da yeni parçalardır ve denetlenir: örnekleyen bir denetleyici, engelin
+            // köşesini sıyıran bir parçanın bir kısmını reddedebilir
+            if i == j
+                || !checker.edge_free(&p, &q)
+                || !checker.edge_free(&points[i], &p)
+                || !checker.edge_free(&q, &points[j + 1])
+            {
+                continue;
+            }
+            let mut shortened = points[..=i].to_vec();
+            for point in [p, q] {
+                if shortened.last() != Some(&point) {
+                    shortened.push(point);
+                }
+            }
+            let rest = &points[j + 1..];
+            shortened.extend_from_slice(if rest.first() == Some(&q) { &rest[1..] } else { rest });
+            points = shortened;
+        }
+        Path::new(points)
+    }
+
+    // Açgözlü, belirlenimci kısaltma: her noktadan denetleyiciye göre engelsiz görülebilen en
+    // uzak ara noktaya atlanır. Yalnızca ara noktalar atlanır; uç noktalar korunur
+    pub fn shortcut_greedy(&self, checker: &dyn CollisionChecker) -> Path {
+        let Some(&first) = self.points.first() else {
+            return Path::default();
+        };
+        let mut shortcut = vec![first];
+        let mut anchor = 0;
+        while anchor + 1 < self.points.len() {
+            anchor = (anchor + 2..self.points.len())
+                .rev()
+                .find(|&candidate| checker.edge_free(&self.points[anchor], &self.points[candidate]))
+                .unwrap_or(anchor + 1);
+            shortcut.push(self.points[anchor]);
+        }
+        Path::new(shortcut)
+    }
+
+    // Yürütme için C1 sürekli eğri: ara noktalardan geçen tekdüze Catmull-Rom eğrisi her parça
+    // için `samples_per_segment` noktayla örneklenir; uçlarda eksik kontrol noktası komşusunun
+    // yansımasıdır. Örneklenen eğri parça parça denetleyiciyle doğrulanır ve bir köşeyi
+    // engelin içinden kesen parçalarda yerel olarak doğru parça korunur (o ara noktada
+    // süreklilik C0'a düşer). Üçten az noktalı yollar olduğu gibi döner
+    pub fn smooth_spline(&self, checker: &dyn CollisionChecker, samples_per_segment: usize) -> Path {
+        let points = &self.points;
+        if points.len() < 3 {
+            return self.clone();
+        }
+        let samples = samples_per_segment.max(1);
+        let last = points.len() - 1;
+        let mut smooth = vec![points[0]];
+        for i in 0..last {
+            let p0 = if i == 0 { reflect(&points[1], &points[0]) } else { points[i - 1] };
+            let p3 = if i + 1 == last { reflect(&points[last - 1], &points[last]) } else { points[i + 2] };
+            let mut curve: Vec<Point> = (1..=samples)
+                .map(|k| catmull_rom(&p0, &points[i], &points[i + 1], &p3, k as f32 / samples as f32))
+                .collect();
+            // Son örnek tam ara noktaya düşmeyebilir (yuvarlama); parçalar ara noktada birleşir
+            curve[samples - 1] = points[i + 1];
+            let mut previous = points[i];
+            let free = curve.iter().all(|point| {
+                let free = checker.edge_free(&previous, point);
+                previous = *point;
+                free
+            });
+            if free {
+                smooth.extend(curve);
+            } else {
+                smooth.extend((1..=samples).map(|k| points[i].lerp(&points[i + 1], k as f32 / samples as f32)));
+            }
+        }
+        Path::new(smooth)
+    }
+
+    // Her noktadaki ayrık eğrilik (1 / yarıçap): komşu iki noktayla oluşturulan üçgenin çevrel
+    // çemberinden hesaplanır; sola dönüşler pozitif, sağa dönüşler negatiftir. Uç noktalarda
+    // ve çakışan komşularda 0'dır. Sonuç noktalarla aynı uzunluktadır
+    pub fn curvature(&self) -> Vec<f32> {
+        let mut curvature = vec![0.0; self.points.len()];
+        for (i, window) in self.points.windows(3).enumerate() {
+            let (a, b, c) = (&window[0], &window[1], &window[2]);
+            let cross = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+            let lengths = a.distance(b) * b.distance(c) * c.distance(a);
+            if lengths > 0.0 {
+                curvature[i + 1] = 2.0 * cross / lengths;
+            }
+        }
+        curvature
+    }
+}
+
+// `point`un `center`e göre yansıması
+fn reflect(point: &Point, center: &Point) -> Point {
+    Point { x: 2.0 * center.x - point.x, y: 2.0 * center.y - point.y }
+}
+
+// p1'den p2'ye giden tekdüze Catmull-Rom parçasının `t` ∈ [0, 1] parametresindeki noktası
+fn catmull_rom(p0: &Point, p1: &Point, p2: &Point, p3: &Point, t: f32) -> Point {
+    let (t2, t3) = (t * t, t * t * t);
+    let blend = |a: f32, b: f32, c: f32, d: f32| {
+        0.5 * (2.0 * b + (c - a) * t + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2 + (3.0 * b - a - 3.0 * c + d) * t3)
+    };
+    Point { x: blend(p0.x, p1.x, p2.x, p3.x), y: blend(p0.y, p1.y, p2.y, p3.y) }
+}
+
+// Yol üzerinde başından `distance` yay uzunluğu sonraki nokta ve bulunduğu parçanın indeksi
+// (`i`inci parça `points[i]`den `points[i + 1]`e gider)
+fn point_at(points: &[Point], arc: &[f32], distance: f32) -> (usize, Point) {
+    let segment = arc.partition_point(|&length| length <= distance).clamp(1, points.len() - 1) - 1;
+    let length = arc[segment + 1] - arc[segment];
+    let t = if length > 0.0 { ((distance - arc[segment]) / length).clamp(0.0, 1.0) } else { 0.0 };
+    (segment, points[segment].lerp(&points[segment + 1], t))
+}
+
+impl Deref for Path {
+    type Target = [Point];
+
+    fn deref(&self) -> &[Point] {
+        &self.points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Obstacle;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn rect(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Obstacle {
+        Obstacle::Rect { min: Point { x: min_x, y: min_y }, max: Point { x: max_x, y: max_y } }
+    }
+
+    // Tek bir dikdörtgenin altından dolaşan, engelsiz ama girintili çıkıntılı bir yol
+    fn jagged_path() -> Path {
+        let points = [(0.0, 50.0), (20.0, 50.0), (30.0, 35.0), (35.0, 30.0), (42.0, 20.0), (50.0, 25.0), (58.0, 15.0), (65.0, 30.0), (70.0, 40.0), (80.0, 50.0), (100.0, 50.0)];
+        Path::new(points.into_iter().map(|(x, y)| Point { x, y }).collect())
+    }
+
+    fn length(path: &Path) -> f32 {
+        path.windows(2).map(|segment| segment[0].distance(&segment[1])).sum()
+    }
+
+    #[test]
+    fn shortcut_never_lengthens_or_collides() {
+        let obstacles = vec![rect(40.0, 40.0, 60.0, 60.0)];
+        let path = jagged_path();
+        assert!(path.windows(2).all(|segment| obstacles.edge_free(&segment[0], &segment[1])));
+
+        let mut shortened = vec![path.shortcut_greedy(&obstacles)];
+        for seed in 0..20 {
+            shortened.push(path.shortcut(&obstacles, 50, &mut StdRng::seed_from_u64(seed)));
+        }
+        for smoothed in &shortened {
+            assert!(length(smoothed) <= length(&path) + 1e-3, "{} > {}", length(smoothed), length(&path));
+            assert_eq!((smoothed.first(), smoothed.last()), (path.first(), path.last()));
+            assert!(smoothed.windows(2).all(|segment| obstacles.edge_free(&segment[0], &segment[1])), "{smoothed:?}");
+        }
+        assert!(shortened.iter().all(|smoothed| length(smoothed) < length(&path)));
+    }
+
+    // 90° köşede eğri köşenin dışına taşar: taşan parçanın yanındaki engel o parçada doğru
+    // parçasına dönülmesine yol açar, diğer parça eğri olarak kalır
+    #[test]
+    fn spline_falls_back_at_blocked_corner() {
+        let obstacles = vec![rect(10.0, 5.0, 45.0, 45.0), rect(51.0, 5.0, 60.0, 45.0)];
+        let path = Path::new(vec![Point { x: 0.0, y: 0.0 }, Point { x: 50.0, y: 0.0 }, Point { x: 50.0, y: 50.0 }]);
+        let smooth = path.smooth_spline(&obstacles, 10);
+
+        assert_eq!(smooth.len(), 21);
+        assert_eq!((smooth.first(), smooth[10], smooth.last()), (path.first(), path[1], path.last()));
+        assert!(smooth.windows(2).all(|segment| obstacles.edge_free(&segment[0], &segment[1])), "{smooth:?}");
+        assert!(smooth[1..10].iter().any(|point| point.y < 0.0), "the free segment was not curved");
+        assert!(smooth[10..].iter().all(|point| point.x == 50.0), "the blocked segment was not kept straight");
+
+        // Engel kaldırılınca iki parça da eğri olur
+        let free = path.smooth_spline(&Vec::<Obstacle>::new(), 10);
+        assert!(free[11..20].iter().all(|point| point.x > 50.0));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{Path, RRTStar, StepOutcome};
+
+// Farklı planlama algoritmalarının (RRT*, RRT-Connect, ...) ortak arayüzü; görselleştirme
+// ve karşılaştırmalar algoritmadan bağımsız olarak bu arayüz üzerinden yürütülür
+pub trait Planner {
+    // Tek bir iterasyon çalıştırır
+    fn step(&mut self) -> StepOutcome;
+    // İterasyon sınırına ulaşıldı ya da algoritmaya göre planlama tamamlandı mı
+    fn is_finished(&self) -> bool;
+    // Şu ana kadar çalıştırılan iterasyon sayısı
+    fn iterations(&self) -> usize;
+    // Bulunan en iyi yolun maliyeti (yol yoksa sonsuz)
+    fn best_cost(&self) -> f32;
+    // Bulunan en iyi yol (yol yoksa boş)
+    fn best_path(&self) -> &Path;
+}
+
+impl Planner for RRTStar {
+    fn step(&mut self) -> StepOutcome {
+        RRTStar::step(self)
+    }
+
+    fn is_finished(&self) -> bool {
+        RRTStar::is_finished(self)
+    }
+
+    fn iterations(&self) -> usize {
+        RRTStar::iterations(self)
+    }
+
+    fn best_cost(&self) -> f32 {
+        RRTStar::best_cost(self)
+    }
+
+    fn best_path(&self) -> &Path {
+        RRTStar::best_path(self)
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Point, RRTStar};
+
+// Yapay potansiyel alanıyla örnek itme: ham tekdüze örnek, hedefe çeken ve engellerden iten
+// potansiyelin negatif gradyanı boyunca birkaç küçük adım ilerletilir. Çekim
+// `attractive_gain * (hedef - p)`, itme ise `influence` mesafesinin içinde
+// `repulsive_gain * (1/d - 1/influence) / d²` büyüklüğünde, engel mesafesi alanının
+// (`RRTStar::clearance`) gradyanı yönündedir. Örneklerin tek bir yerel minimuma çökmemesi
+// için örnek ham noktadan en fazla `max_nudge` uzaklaşabilir
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PotentialFieldSampler {
+    pub attractive_gain: f32, // Her adımda hedefe olan farkın bu oranı kadar çekilir
+    pub repulsive_gain: f32,  // Engellerden itme kazancı
+    pub influence: f32,       // İtmenin etkili olduğu en büyük engel mesafesi
+    pub steps: usize,         // Her örnekte yapılan itme adımı sayısı
+    pub max_nudge: f32,       // Örneğin ham noktadan en fazla uzaklaşabileceği mesafe
+}
+
+// Engel mesafesi gradyanının merkezi farklarla hesaplandığı adım
+const GRADIENT_STEP: f32 = 0.5;
+
+impl RRTStar {
+    // Ham örneği potansiyel alanı boyunca iter. Nokta bir engelin içindeyse ya da itmenin
+    // etkili olduğu bölgede engel mesafesinin gradyanı tanımsızsa (sıfır ya da sonlu değil,
+    // örneğin iki engelin tam ortasında) itme durur ve ham örnek döner; itilen nokta bir
+    // engele düşerse de ham örnek kullanılır
+    pub(crate) fn nudge_sample(&self, raw: Point, field: &PotentialFieldSampler) -> Point {
+        let goal = self.goal_point(self.nearest_goal(&raw));
+        let mut point = raw;
+        for _ in 0..field.steps {
+            let clearance = self.clearance(&point);
+            let mut force = Point { x: field.attractive_gain * (goal.x - point.x), y: field.attractive_gain * (goal.y - point.y) };
+            if clearance < field.influence {
+                let Some(gradient) = self.clearance_gradient(&point).filter(|_| clearance > 0.0) else {
+                    return raw;
+                };
+                let magnitude = field.repulsive_gain * (1.0 / clearance - 1.0 / field.influence) / (clearance * clearance);
+                force.x += magnitude * gradient.x;
+                force.y += magnitude * gradient.y;
+            }
+            point = Point { x: point.x + force.x, y: point.y + force.y };
+            // Yerel minimuma çökmeyi önlemek için ham noktadan uzaklık sınırlanır
+            let offset = raw.distance(&point);
+            if offset > field.max_nudge {
+                point = raw.lerp(&point, field.max_nudge / offset);
+            }
+        }
+        let point = self.bounds.clamp(&point);
+        if point.is_finite() && self.is_collision_free(&point) {
+            point
+        } else {
+            raw
+        }
+    }
+
+    // Engel mesafesi alanının birim gradyanı (engelden uzaklaşan yön); tanımsızsa None
+    fn clearance_gradient(&self, point: &Point) -> Option<Point> {
+        let h = GRADIENT_STEP;
+        let at = |dx: f32, dy: f32| self.clearance(&Point { x: point.x + dx, y: point.y + dy });
+        let gradient = Point { x: (at(h, 0.0) - at(-h, 0.0)) / (2.0 * h), y: (at(0.0, h) - at(0.0, -h)) / (2.0 * h) };
+        let length = gradient.x.hypot(gradient.y);
+        if !length.is_finite() || length <= f32::EPSILON {
+            return None;
+        }
+        Some(Point { x: gradient.x / length, y: gradient.y / length })
+    }
+}
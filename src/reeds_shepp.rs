@@ -0,0 +1,327 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::dubins::{advance, Segment};
+use crate::Point;
+
+// Formüllerdeki kabul sınırlarında kayan nokta hatalarına tanınan pay
+const TOLERANCE: f32 = 1e-5;
+
+// En küçük dönüş yarıçapı sabit, ileri ve geri gidebilen bir aracın iki konum ve yön
+// arasındaki en kısa yolu. En fazla beş parçadan oluşur; negatif uzunluklu parçalar geri
+// gidilerek izlenir. Açılar Dubins yollarındaki gibi ölçülür
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReedsSheppPath {
+    start: Point,           // Başlangıç noktası
+    start_heading: f32,     // Başlangıç yönü (radyan)
+    turn_radius: f32,       // Dönüş yarıçapı
+    segments: [Segment; 5], // Parça türleri
+    lengths: [f32; 5],      // İşaretli parça uzunlukları (yarıçapa bölünmüş; kullanılmayanlar 0)
+}
+
+// Bir yol ailesi: sol dönüşle başlayan temel biçimin parça türleri ve normalize edilmiş
+// problemde (hedef başlangıç çerçevesinde, yarıçapa bölünmüş) parça uzunlukları. Diğer
+// aileler zaman tersine çevirme, yansıtma ve (`backward` ise) yolu sondan başa izleme
+// simetrileriyle elde edilir
+struct Family {
+    word: &'static [Segment],
+    lengths: fn(f32, f32, f32) -> Option<[f32; 5]>,
+    backward: bool,
+}
+
+// Reeds ve Shepp'in 48 yol biçimini simetrilerle üreten temel aileler (CSC, CCC, CCCC,
+// CCSC, CCSCC)
+const FAMILIES: [Family; 8] = {
+    use Segment::{Left as L, Right as R, Straight as S};
+    [
+        Family { word: &[L, S, L], lengths: lp_sp_lp, backward: false },
+        Family { word: &[L, S, R], lengths: lp_sp_rp, backward: false },
+        Family { word: &[L, R, L], lengths: lp_rm_l, backward: true },
+        Family { word: &[L, R, L, R], lengths: lp_rup_lum_rm, backward: false },
+        Family { word: &[L, R, L, R], lengths: lp_rum_lum_rp, backward: false },
+        Family { word: &[L, R, S, L], lengths: lp_rm_sm_lm, backward: true },
+        Family { word: &[L, R, S, R], lengths: lp_rm_sm_rm, backward: true },
+        Family { word: &[L, R, S, L, R], lengths: lp_rm_s_lm_rp, backward: false },
+    ]
+};
+
+// Açıyı (-π, π] aralığına getirir
+fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = angle.rem_euclid(TAU);
+    if wrapped > PI {
+        wrapped - TAU
+    } else {
+        wrapped
+    }
+}
+
+// Kutupsal koordinatlar (yarıçap, açı)
+fn polar(x: f32, y: f32) -> (f32, f32) {
+    (x.hypot(y), y.atan2(x))
+}
+
+// CCCC ailelerinde ilk ve son dönüş açıları
+fn tau_omega(u: f32, v: f32, xi: f32, eta: f32, phi: f32) -> (f32, f32) {
+    let delta = wrap_angle(u - v);
+    let a = u.sin() - delta.sin();
+    let b = u.cos() - delta.cos() - 1.0;
+    let t1 = (eta * a - xi * b).atan2(xi * a + eta * b);
+    let t2 = 2.0 * (delta.cos() - v.cos() - u.cos()) + 3.0;
+    let tau = if t2 < 0.0 { wrap_angle(t1 + PI) } else { wrap_angle(t1) };
+    (tau, wrap_angle(tau - u + v - phi))
+}
+
+// L+ S+ L+
+fn lp_sp_lp(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (u, t) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    let v = wrap_angle(phi - t);
+    (t >= -TOLERANCE && v >= -TOLERANCE).then_some([t, u, v, 0.0, 0.0])
+}
+
+// L+ S+ R+
+fn lp_sp_rp(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (rho, theta) = polar(x + phi.sin(), y - 1.0 - phi.cos());
+    if rho * rho < 4.0 {
+        return None;
+    }
+    let u = (rho * rho - 4.0).sqrt();
+    let t = wrap_angle(theta + 2.0f32.atan2(u));
+    let v = wrap_angle(t - phi);
+    (t >= -TOLERANCE && v >= -TOLERANCE).then_some([t, u, v, 0.0, 0.0])
+}
+
+// L+ R- L
+fn lp_rm_l(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (rho, theta) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if rho > 4.0 {
+        return None;
+    }
+    let u = -2.0 * (0.25 * rho).asin();
+    let t = wrap_angle(theta + 0.5 * u + PI);
+    let v = wrap_angle(phi - t + u);
+    (t >= -TOLERANCE && u <= TOLERANCE).then_some([t, u, v, 0.0, 0.0])
+}
+
+// L+ R+ L- R-
+fn lp_rup_lum_rm(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let rho = 0.25 * (2.0 + xi.hypot(eta));
+    if rho > 1.0 {
+        return None;
+    }
+    let u = rho.acos();
+    let (t, v) = tau_omega(u, -u, xi, eta, phi);
+    (t >= -TOLERANCE && v <= TOLERANCE).then_some([t, u, -u, v, 0.0])
+}
+
+// L+ R- L- R+
+fn lp_rum_lum_rp(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let rho = (20.0 - xi * xi - eta * eta) / 16.0;
+    if !(0.0..=1.0).contains(&rho) {
+        return None;
+    }
+    let u = -rho.acos();
+    if u < -FRAC_PI_2 {
+        return None;
+    }
+    let (t, v) = tau_omega(u, u, xi, eta, phi);
+    (t >= -TOLERANCE && v >= -TOLERANCE).then_some([t, u, u, v, 0.0])
+}
+
+// L+ R-(π/2) S- L-
+fn lp_rm_sm_lm(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (rho, theta) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if rho < 2.0 {
+        return None;
+    }
+    let r = (rho * rho - 4.0).sqrt();
+    let u = 2.0 - r;
+    let t = wrap_angle(theta + r.atan2(-2.0));
+    let v = wrap_angle(phi - FRAC_PI_2 - t);
+    (t >= -TOLERANCE && u <= TOLERANCE && v <= TOLERANCE).then_some([t, -FRAC_PI_2, u, v, 0.0])
+}
+
+// L+ R-(π/2) S- R-
+fn lp_rm_sm_rm(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let (rho, theta) = polar(-eta, xi);
+    if rho < 2.0 {
+        return None;
+    }
+    let t = theta;
+    let u = 2.0 - rho;
+    let v = wrap_angle(t + FRAC_PI_2 - phi);
+    (t >= -TOLERANCE && u <= TOLERANCE && v <= TOLERANCE).then_some([t, -FRAC_PI_2, u, v, 0.0])
+}
+
+// L+ R-(π/2) S- L-(π/2) R+
+fn lp_rm_s_lm_rp(x: f32, y: f32, phi: f32) -> Option<[f32; 5]> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let rho = xi.hypot(eta);
+    if rho < 2.0 {
+        return None;
+    }
+    let u = 4.0 - (rho * rho - 4.0).sqrt();
+    if u > TOLERANCE {
+        return None;
+    }
+    let t = wrap_angle(((4.0 - u) * xi - 2.0 * eta).atan2(-2.0 * xi + (u - 4.0) * eta));
+    let v = wrap_angle(t - phi);
+    (t >= -TOLERANCE && v >= -TOLERANCE).then_some([t, -FRAC_PI_2, u, -FRAC_PI_2, v])
+}
+
+// Sol ve sağ dönüşleri yer değiştirir
+fn reflect(segment: Segment) -> Segment {
+    match segment {
+        Segment::Left => Segment::Right,
+        Segment::Right => Segment::Left,
+        Segment::Straight => Segment::Straight,
+    }
+}
+
+// Normalize edilmiş problemde tüm aday yolların parça türleri ve işaretli uzunlukları
+fn candidates(x: f32, y: f32, phi: f32) -> Vec<([Segment; 5], [f32; 5])> {
+    let (xb, yb) = (x * phi.cos() + y * phi.sin(), x * phi.sin() - y * phi.cos());
+    let mut candidates = Vec::new();
+    for family in &FAMILIES {
+        let count = family.word.len();
+        let directions: &[bool] = if family.backward { &[false, true] } else { &[false] };
+        for &backward in directions {
+            let (x, y) = if backward { (xb, yb) } else { (x, y) };
+            for (timeflip, reflected) in [(false, false), (true, false), (false, true), (true, true)] {
+                let sx = if timeflip { -1.0 } else { 1.0 };
+                let sy = if reflected { -1.0 } else { 1.0 };
+                let Some(lengths) = (family.lengths)(sx * x, sy * y, sx * sy * phi) else {
+                    continue;
+                };
+                let mut path = ([Segment::Straight; 5], [0.0; 5]);
+                for i in 0..count {
+                    // Geriye izlenen ailelerde parçaların sırası ters çevrilir
+                    let source = if backward { count - 1 - i } else { i };
+                    let segment = family.word[source];
+                    path.0[i] = if reflected { reflect(segment) } else { segment };
+                    path.1[i] = sx * lengths[source];
+                }
+                candidates.push(path);
+            }
+        }
+    }
+    candidates
+}
+
+impl ReedsSheppPath {
+    // İki konum ve yön arasındaki en düşük maliyetli Reeds-Shepp yolu; geri giden parçaların
+    // uzunluğu `reverse_penalty` ile çarpılır (1 = en kısa yol). Yarıçap pozitif olmalıdır
+    pub fn shortest(start: Point, start_heading: f32, goal: Point, goal_heading: f32, turn_radius: f32, reverse_penalty: f32) -> ReedsSheppPath {
+        // Hedef, başlangıcın yerel çerçevesine taşınır ve yarıçapa bölünür
+        let (dx, dy) = ((goal.x - start.x) / turn_radius, (goal.y - start.y) / turn_radius);
+        let (sin, cos) = start_heading.sin_cos();
+        let (x, y) = (dx * cos + dy * sin, -dx * sin + dy * cos);
+        let phi = goal_heading - start_heading;
+
+        let (segments, lengths) = candidates(x, y, phi)
+            .into_iter()
+            .min_by(|(_, a), (_, b)| weighted(a, reverse_penalty).total_cmp(&weighted(b, reverse_penalty)))
+            .expect("L+ S+ L+ ailesinin simetrilerinden en az biri her zaman tanımlıdır");
+        ReedsSheppPath { start, start_heading, turn_radius, segments, lengths }
+    }
+
+    // Yolun toplam uzunluğu (ileri ve geri)
+    pub fn length(&self) -> f32 {
+        weighted(&self.lengths, 1.0) * self.turn_radius
+    }
+
+    // Geri giden parçaların uzunluğu cezayla çarpılmış yol uzunluğu
+    pub fn cost(&self, reverse_penalty: f32) -> f32 {
+        weighted(&self.lengths, reverse_penalty) * self.turn_radius
+    }
+
+    // Yol geri gidilerek izlenen bir parça içeriyor mu
+    pub fn reverses(&self) -> bool {
+        self.lengths.iter().any(|&length| length < 0.0)
+    }
+
+    // Yolun başından `distance` uzunluk sonraki noktada geri mi gidiliyor
+    pub fn is_reversing_at(&self, distance: f32) -> bool {
+        let mut remaining = distance / self.turn_radius;
+        for &length in &self.lengths {
+            if remaining < length.abs() {
+                return length < 0.0;
+            }
+            remaining -= length.abs();
+        }
+        self.lengths.iter().rev().find(|&&length| length != 0.0).is_some_and(|&length| length < 0.0)
+    }
+
+    // Yolun başından `distance` uzunluk sonraki konum ve yön (yol dışına taşan mesafeler
+    // kırpılır); yön geri giderken de aracın baktığı yöndür
+    pub fn sample(&self, distance: f32) -> (Point, f32) {
+        let mut remaining = distance.clamp(0.0, self.length()) / self.turn_radius;
+        let (mut point, mut heading) = (self.start, self.start_heading);
+        for (&segment, &length) in self.segments.iter().zip(&self.lengths) {
+            let travel = remaining.min(length.abs());
+            (point, heading) = advance(point, heading, segment, travel.copysign(length), self.turn_radius);
+            remaining -= travel;
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        (point, heading)
+    }
+
+    // Yolu en fazla `spacing` uzunluklu parçalarla yaklaşan noktalar dizisi (uçlar dahil)
+    pub fn polyline(&self, spacing: f32) -> Vec<Point> {
+        let length = self.length();
+        let pieces = (length / spacing).ceil().max(1.0) as usize;
+        (0..=pieces).map(|i| self.sample(length * i as f32 / pieces as f32).0).collect()
+    }
+}
+
+// Geri giden parçalar cezayla çarpılarak toplanmış normalize uzunluk
+fn weighted(lengths: &[f32; 5], reverse_penalty: f32) -> f32 {
+    lengths
+        .iter()
+        .map(|&length| if length < 0.0 { -length * reverse_penalty } else { length })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bounds, Obstacle, PlanConfig, RRTStar, Steering};
+
+    // Paralel park: hedef başlangıcın hemen yanında ve aynı yöne bakıyor; park yeri dönüş
+    // çapından dar olduğundan yalnızca ileri giden bir araç burada geri dönemez
+    fn parking_config(steering: Steering) -> PlanConfig {
+        let wall = |min_x, min_y, max_x, max_y| Obstacle::Rect { min: Point { x: min_x, y: min_y }, max: Point { x: max_x, y: max_y } };
+        PlanConfig {
+            start: Point { x: 50.0, y: 8.0 },
+            goal: Point { x: 50.0, y: 22.0 },
+            goal_heading: Some(0.0),
+            step_size: 10.0,
+            goal_threshold: 0.0,
+            bounds: Bounds::new(0.0, 100.0, 0.0, 30.0),
+            obstacles: vec![wall(-10.0, -10.0, 110.0, 0.0), wall(-10.0, 30.0, 110.0, 40.0), wall(-10.0, 0.0, 0.0, 30.0), wall(100.0, 0.0, 110.0, 30.0)],
+            max_iterations: 800,
+            steering,
+            ..PlanConfig::default()
+        }
+    }
+
+    #[test]
+    fn parallel_parking_needs_reverse() {
+        let mut dubins = RRTStar::from_config(&parking_config(Steering::Dubins { turn_radius: 20.0 }), 5);
+        dubins.plan().ok();
+        assert!(!dubins.best_cost().is_finite(), "Dubins found a path of cost {}", dubins.best_cost());
+
+        let mut reeds_shepp = RRTStar::from_config(&parking_config(Steering::ReedsShepp { turn_radius: 20.0, reverse_penalty: 1.0 }), 5);
+        reeds_shepp.plan().expect("Reeds-Shepp park manevrasını bulmalı");
+        assert!(reeds_shepp.best_cost().is_finite());
+        assert!(reeds_shepp.verify_tree().is_ok());
+        let reverses = reeds_shepp.best_path_nodes.windows(2).any(|pair| {
+            let (from, to) = (&reeds_shepp.nodes[pair[0]], &reeds_shepp.nodes[pair[1]]);
+            ReedsSheppPath::shortest(from.point, from.heading, to.point, to.heading, 20.0, 1.0).reverses()
+        });
+        assert!(reverses, "the parking path has no reverse segment");
+    }
+}
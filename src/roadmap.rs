@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{Path, Point, RRTStar};
+
+// Çok sorgulu planlama için bir kez kurulup birçok başlangıç/hedef çifti için kullanılan
+// olasılıksal yol haritası (PRM). Örnekleme, çarpışma denetimi ve kenar maliyetleri için
+// haritanın kurulduğu planlayıcı kullanılır; sorgular da aynı planlayıcıyla yapılmalıdır
+pub struct Roadmap {
+    points: Vec<Point>,                // Haritadaki boş alan örnekleri
+    edges: Vec<Vec<(usize, f32)>>,     // Her düğümün komşuları ve kenar maliyetleri
+    connection_radius: f32,            // Düğümlerin birbirine bağlandığı en büyük mesafe
+}
+
+// Dijkstra kuyruğundaki bir düğüm; en düşük maliyet önce çıkar (FMT* de kullanır)
+pub(crate) struct QueueEntry {
+    pub(crate) cost: f32,
+    pub(crate) node: usize,
+}
+
+impl PartialEq for QueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueueEntry {}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap en büyüğü çıkardığından karşılaştırma ters çevrilir
+        other.cost.total_cmp(&self.cost).then(other.node.cmp(&self.node))
+    }
+}
+
+impl Roadmap {
+    // Planlayıcının örnekleme bölgesinden `samples` adet boş nokta örnekler ve aralarında
+    // `connection_radius` mesafeden yakın, engelsiz olan tüm çiftleri birbirine bağlar
+    pub fn build(planner: &mut RRTStar, samples: usize, connection_radius: f32) -> Self {
+        let bounds = planner.bounds();
+        let mut points = Vec::with_capacity(samples);
+        while points.len() < samples {
+            let point = planner.random_point(bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y);
+            if planner.is_collision_free(&point) {
+                points.push(point);
+            }
+        }
+
+        let mut edges = vec![Vec::new(); points.len()];
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                if points[i].distance(&points[j]) < connection_radius
+                    && planner.is_edge_collision_free(&points[i], &points[j])
+                {
+                    let cost = planner.edge_cost(&points[i], &points[j]);
+                    edges[i].push((j, cost));
+                    edges[j].push((i, cost));
+                }
+            }
+        }
+        Roadmap { points, edges, connection_radius }
+    }
+
+    // Haritadaki düğümler
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    // Haritadaki (yönsüz) kenar sayısı
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    // Başlangıç ve hedefi bağlantı yarıçapı içindeki harita düğümlerine bağlar ve en ucuz yolu
+    // Dijkstra ile arar; harita değişmez. Bağlantı kurulamazsa None döner
+    pub fn query(&self, planner: &RRTStar, start: Point, goal: Point) -> Option<Path> {
+        let count = self.points.len();
+        let (start_node, goal_node) = (count, count + 1);
+
+        // Başlangıç ve hedef için geçici kenarlar
+        let links = |point: &Point| -> Vec<(usize, f32)> {
+            (0..count)
+                .filter(|&i| {
+                    self.points[i].distance(point) < self.connection_radius
+                        && planner.is_edge_collision_free(point, &self.points[i])
+                })
+                .map(|i| (i, planner.edge_cost(point, &self.points[i])))
+                .collect()
+        };
+        let mut start_links = links(&start);
+        let mut goal_links = vec![None; count];
+        for (i, cost) in links(&goal) {
+            goal_links[i] = Some(cost);
+        }
+        if start.distance(&goal) < self.connection_radius && planner.is_edge_collision_free(&start, &goal) {
+            start_links.push((goal_node, planner.edge_cost(&start, &goal)));
+        }
+
+        let mut best = vec![f32::INFINITY; count + 2];
+        let mut previous = vec![None; count + 2];
+        let mut queue = BinaryHeap::new();
+        best[start_node] = 0.0;
+        queue.push(QueueEntry { cost: 0.0, node: start_node });
+
+        while let Some(QueueEntry { cost, node }) = queue.pop() {
+            if node == goal_node {
+                break;
+            }
+            if cost > best[node] {
+                continue;
+            }
+            let neighbors: Box<dyn Iterator<Item = (usize, f32)>> = if node == start_node {
+                Box::new(start_links.iter().copied())
+            } else {
+                let to_goal = goal_links[node].map(|edge| (goal_node, edge));
+                Box::new(self.edges[node].iter().copied().chain(to_goal))
+            };
+            for (next, edge) in neighbors {
+                let next_cost = cost + edge;
+                if next_cost < best[next] {
+                    best[next] = next_cost;
+                    previous[next] = Some(node);
+                    queue.push(QueueEntry { cost: next_cost, node: next });
+                }
+            }
+        }
+
+        if !best[goal_node].is_finite() {
+            return None;
+        }
+        let point_of = |node: usize| match node {
+            n if n == start_node => start,
+            n if n == goal_node => goal,
+            n => self.points[n],
+        };
+        let mut path = vec![goal];
+        let mut current = goal_node;
+        while let Some(node) = previous[current] {
+            path.push(point_of(node));
+            current = node;
+        }
+        path.reverse();
+        Some(Path::new(path))
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{ConfigError, Path, PlanConfig, Planner, Point, RRTStar, StepOutcome, Steering};
+
+// Bir ağacı hedef noktaya doğru bir adım genişletmenin sonucu
+enum Extend {
+    // En yakın düğümden hedefe doğru engelsiz ilerlenemedi
+    Trapped,
+    // Hedefe doğru yeni bir düğüm eklendi
+    Advanced,
+    // Hedef noktasına ulaşıldı (verilen indeksteki düğüm tam hedefte)
+    Reached(usize),
+}
+
+// Hızlıca herhangi bir yol bulmak için iki ağaçlı RRT-Connect: biri başlangıçta, biri hedefte
+// köklenen ağaçlar sırayla rastgele örneğe doğru genişletilir ve diğer ağaç yeni düğüme
+// açgözlü biçimde bağlanmaya çalışır; ağaçlar buluştuğunda planlama biter. Ağaçlar yeniden
+// bağlama yapmaz, yalnızca RRT* ağacının örnekleme, yönlendirme ve çarpışma denetimini kullanır
+pub struct RrtConnect {
+    start_tree: RRTStar,   // Başlangıçta köklenen ağaç
+    goal_tree: RRTStar,    // Hedefte köklenen ağaç
+    swapped: bool,         // Bu iterasyonda hedef ağacı mı genişletilecek
+    iterations: usize,     // Şu ana kadar çalıştırılan iterasyon sayısı
+    max_iterations: usize, // İterasyon sınırı
+    best_cost: f32,        // Birleştirilen yolun maliyeti (yol yoksa sonsuz)
+    best_path: Path,       // Başlangıçtan hedefe birleştirilen yol
+}
+
+impl RrtConnect {
+    // Yapılandırmadan, verilen tohumla tekrarlanabilir bir planlayıcı oluşturur
+    // Geçersiz parametrelerde paniğe girer (bkz. `try_from_config`)
+    pub fn from_config(config: &PlanConfig, seed: u64) -> Self {
+        Self::try_from_config(config, seed).unwrap_or_else(|error| panic!("invalid RRT-Connect configuration: {error}"))
+    }
+
+    // Yapılandırmayı doğrulayarak tekrarlanabilir bir planlayıcı oluşturur; hedef ağacı
+    // başlangıç ve hedefi yer değiştirmiş aynı yapılandırmayla kurulur
+    pub fn try_from_config(config: &PlanConfig, seed: u64) -> Result<Self, ConfigError> {
+        // Ağaçlar doğrusal kenarlarla birleştirilir; iki ağacın yönleri buluşma noktasında
+        // uyuşmayacağından eğrisel yönlendirmeler desteklenmez
+        let forward = PlanConfig { steering: Steering::Straight, ..config.clone() };
+        let start_tree = RRTStar::try_from_config(&forward, seed)?;
+        let reversed = PlanConfig { start: config.goal, goal: config.start, ..forward };
+        let goal_tree = RRTStar::try_from_config(&reversed, seed.wrapping_add(1))?;
+
+        let mut rrt_connect = RrtConnect {
+            start_tree,
+            goal_tree,
+            swapped: false,
+            iterations: 0,
+            max_iterations: config.max_iterations,
+            best_cost: f32::INFINITY,
+            best_path: Path::default(),
+        };
+        // Başlangıç hedefin kabul bölgesindeyse kök zaten bir çözümdür
+        if rrt_connect.start_tree.best_cost().is_finite() {
+            rrt_connect.best_cost = rrt_connect.start_tree.best_cost();
+            rrt_connect.best_path = rrt_connect.start_tree.best_path().clone();
+        }
+        Ok(rrt_connect)
+    }
+
+    // Başlangıçta köklenen ağaç
+    pub fn start_tree(&self) -> &RRTStar {
+        &self.start_tree
+    }
+
+    // Hedefte köklenen ağaç
+    pub fn goal_tree(&self) -> &RRTStar {
+        &self.goal_tree
+    }
+
+    // Ağacı hedefe doğru bir adım genişletir
+    fn extend(tree: &mut RRTStar, target: &Point) -> Extend {
+        let Some(nearest) = tree.find_nearest(target) else {
+            return Extend::Trapped;
+        };
+        let from = tree.nodes()[nearest].point;
+        if from == *target {
+            return Extend::Reached(nearest);
+        }
+        let new_point = tree.steer(&from, target);
+        if !new_point.is_finite() || !tree.is_edge_collision_free(&from, &new_point) {
+            return Extend::Trapped;
+        }
+        let index = tree.add_node(new_point, nearest);
+        if new_point == *target {
+            Extend::Reached(index)
+        } else {
+            Extend::Advanced
+        }
+    }
+
+    // İki ağacın buluştuğu düğümlerden başlangıçtan hedefe tek bir yol oluşturur; hedef
+    // ağacındaki yarı ters çevrilir ve buluşma noktası bir kez yazılır
+    fn join(&mut self, start_index: usize, goal_index: usize) {
+        let mut path = self.start_tree.trace_from(start_index).expect("ağaç yeniden bağlanmadığından döngü olamaz");
+        let goal_half = self.goal_tree.trace_from(goal_index).expect("ağaç yeniden bağlanmadığından döngü olamaz");
+        path.extend(goal_half.into_iter().rev().skip(1));
+        self.best_path = Path::new(path);
+        self.best_cost = self.best_path.recompute_cost_with(|a, b| self.start_tree.edge_cost(a, b));
+    }
+}
+
+impl Planner for RrtConnect {
+    fn step(&mut self) -> StepOutcome {
+        if self.best_cost.is_finite() {
+            return StepOutcome::Converged;
+        }
+        self.iterations += 1;
+
+        let (tree, other) = if self.swapped {
+            (&mut self.goal_tree, &mut self.start_tree)
+        } else {
+            (&mut self.start_tree, &mut self.goal_tree)
+        };
+        let sample = tree.sample();
+        let outcome = match Self::extend(tree, &sample) {
+            Extend::Trapped => StepOutcome::Collision,
+            Extend::Advanced | Extend::Reached(_) => {
+                // Diğer ağacı yeni düğüme ulaşana ya da takılana kadar genişlet
+                let index = tree.nodes().len() - 1;
+                let target = tree.nodes()[index].point;
+                let mut met = None;
+                loop {
+                    match Self::extend(other, &target) {
+                        Extend::Advanced => {}
+                        Extend::Reached(other_index) => {
+                            met = Some(other_index);
+                            break;
+                        }
+                        Extend::Trapped => break,
+                    }
+                }
+                if let Some(other_index) = met {
+                    let (start_index, goal_index) =
+                        if self.swapped { (other_index, index) } else { (index, other_index) };
+                    self.join(start_index, goal_index);
+                }
+                StepOutcome::Added { index, improved: met.is_some() }
+            }
+        };
+        self.swapped = !self.swapped;
+        outcome
+    }
+
+    fn is_finished(&self) -> bool {
+        self.best_cost.is_finite() || self.iterations >= self.max_iterations
+    }
+
+    fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    fn best_cost(&self) -> f32 {
+        self.best_cost
+    }
+
+    fn best_path(&self) -> &Path {
+        &self.best_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maps;
+
+    // Dar geçitli haritada RRT-Connect ilk çözümü RRT*'tan çok daha az iterasyonda bulur
+    #[test]
+    fn connect_solves_narrow_passage_faster() {
+        let config = PlanConfig {
+            start: Point { x: 20.0, y: 20.0 },
+            goal: Point { x: 380.0, y: 380.0 },
+            obstacles: maps::narrow_passage(),
+            max_iterations: 5000,
+            ..PlanConfig::default()
+        };
+        let (mut connect_total, mut rrt_star_total) = (0, 0);
+        for seed in 0..3 {
+            let mut rrt_connect = RrtConnect::from_config(&config, seed);
+            while !rrt_connect.is_finished() && !rrt_connect.best_cost().is_finite() {
+                rrt_connect.step();
+            }
+            assert!(rrt_connect.best_cost().is_finite());
+            assert_eq!((rrt_connect.best_path().first(), rrt_connect.best_path().last()), (Some(&config.start), Some(&config.goal)));
+            connect_total += rrt_connect.iterations();
+
+            let mut rrt_star = RRTStar::from_config(&config, seed);
+            while !rrt_star.is_finished() && !rrt_star.best_cost().is_finite() {
+                rrt_star.step();
+            }
+            rrt_star_total += rrt_star.iterations();
+        }
+        assert!(connect_total * 3 < rrt_star_total, "{connect_total} vs {rrt_star_total}");
+    }
+}
@@ -0,0 +1,473 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{Bounds, Path, Point, PotentialFieldSampler, RRTStar};
+
+// Hedef örneklemesi dışında kalan örneklerin nasıl üretileceği
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Sampler {
+    // Örnekleme bölgesinden tekdüze rastgele
+    #[default]
+    Uniform,
+    // Bir yol bulunduktan sonra `probability` olasılıkla en iyi yolun rastgele bir parçası
+    // üzerindeki rastgele bir nokta çevresinde `sigma` standart sapmalı Gauss dağılımıyla,
+    // aksi halde (keşif için) tekdüze
+    PathBiased { probability: f32, sigma: f32 },
+    // Informed RRT*: bir yol bulunduktan sonra yalnızca yolu iyileştirebilecek noktaları içeren
+    // elipsin (odakları başlangıç ve hedef) içinden tekdüze, öncesinde tekdüze
+    Informed,
+    // RRT*-Smart: bir yol bulunduktan sonra `probability` olasılıkla rastgele bir işaret
+    // noktasının (bkz. `smart_waypoints`) `radius` yarıçaplı çevresinden tekdüze, aksi halde
+    // tekdüze. İşaret noktaları engellerin köşelerinde toplandığından yol bu köşelere doğru gerilir
+    Smart { probability: f32, radius: f32 },
+    // Köprü testi (dar geçitler için): `probability` olasılıkla engel içindeki tekdüze bir
+    // noktanın çevresinden `sigma` standart sapmalı Gauss dağılımıyla ikinci bir nokta çekilir;
+    // iki uç da çarpışırken orta noktaları engelsizse orta nokta örnek olur. Böylece örnekler
+    // iki engel arasındaki dar koridorlarda toplanır. Köprü `BRIDGE_MAX_ATTEMPTS` denemede
+    // kurulamazsa ve diğer durumlarda tekdüze
+    Bridge { probability: f32, sigma: f32 },
+    // Hedefin kendisi (çok hedefli planlamada hedeflerden biri eşit olasılıkla); tek başına
+    // değil, karışık örneklemede hedef örneklemesinin payı olarak kullanılır (bkz.
+    // `RRTStar::set_sampler_blend`)
+    Goal,
+    // Tekdüze örnek, hedefe çeken ve engellerden iten yapay bir potansiyel alanı boyunca
+    // birkaç adım itilir (bkz. `PotentialFieldSampler`)
+    PotentialField(PotentialFieldSampler),
+    // Düşük tutarsızlıklı Halton dizisi (x için taban 2, y için taban 3): örnekler bölgeyi
+    // tekdüze rastgele örneklerden daha eşit kaplar. Dizi tohumdan türetilen bir kaydırmayla
+    // döndürülür, böylece farklı tohumlu çalıştırmalar farklı noktalar üretir
+    Halton,
+}
+
+// Tohumdan türetilen kaydırmanın ağacın rastgele sayı akışıyla örtüşmemesi için karıştırma sabiti
+const HALTON_SEED_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+// Birim karede Cranley-Patterson kaydırmalı iki boyutlu Halton dizisi
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HaltonSequence {
+    index: u64,       // Bir sonraki elemanın sırası (0. eleman (0, 0) olduğundan 1'den başlar)
+    offset: [f64; 2], // Her eksende [0, 1) kaydırma
+}
+
+// Tamsayının verilen tabanda basamakları ters çevrilerek elde edilen [0, 1) değeri
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let (mut value, mut scale) = (0.0, 1.0 / base as f64);
+    while index > 0 {
+        value += (index % base) as f64 * scale;
+        index /= base;
+        scale /= base as f64;
+    }
+    value
+}
+
+impl HaltonSequence {
+    // Verilen tohumdan türetilen kaydırmayla dizinin başı
+    pub fn new(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed ^ HALTON_SEED_SALT);
+        HaltonSequence { index: 1, offset: [rng.gen(), rng.gen()] }
+    }
+
+    // Kaydırmasız dizinin başı (1. elemandan itibaren 1/2, 1/4, 3/4, ... ve 1/3, 2/3, 1/9, ...)
+    pub fn unshifted() -> Self {
+        HaltonSequence { index: 1, offset: [0.0; 2] }
+    }
+
+    // Dizinin bir sonraki elemanı ([0, 1) x [0, 1) içinde)
+    pub fn next_point(&mut self) -> (f32, f32) {
+        let x = (radical_inverse(self.index, 2) + self.offset[0]).fract();
+        let y = (radical_inverse(self.index, 3) + self.offset[1]).fract();
+        self.index += 1;
+        (x as f32, y as f32)
+    }
+}
+
+// Odakları başlangıç ve hedef olan, en iyi yolu iyileştirebilecek noktaları içeren elips
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InformedEllipse {
+    pub center: Point,    // Elipsin merkezi (odakların orta noktası)
+    pub semi_major: f32,  // Büyük yarı eksen
+    pub semi_minor: f32,  // Küçük yarı eksen
+    pub angle: f32,       // Büyük eksenin x ekseniyle yaptığı açı (radyan)
+}
+
+impl InformedEllipse {
+    // Noktanın elipsin içinde (sınır dahil) olup olmadığı
+    pub fn contains(&self, point: &Point) -> bool {
+        let (sin, cos) = self.angle.sin_cos();
+        let (dx, dy) = (point.x - self.center.x, point.y - self.center.y);
+        // Elipsin eksenlerine göre koordinatlar
+        let (u, v) = (dx * cos + dy * sin, dy * cos - dx * sin);
+        if self.semi_minor <= 0.0 {
+            return v == 0.0 && u.abs() <= self.semi_major;
+        }
+        (u / self.semi_major).powi(2) + (v / self.semi_minor).powi(2) <= 1.0
+    }
+}
+
+// Elips dışında kalan (örnekleme bölgesi dışındaki) örnekler için en fazla deneme sayısı
+const INFORMED_MAX_ATTEMPTS: usize = 16;
+
+// Köprü örneklemesinde tekdüzeye dönmeden önce denenen en fazla köprü sayısı
+const BRIDGE_MAX_ATTEMPTS: usize = 32;
+
+impl RRTStar {
+    // Bir sonraki genişletme hedefini seçer: şu anki iterasyondaki hedef örnekleme olasılığıyla
+    // hedefin kendisi, aksi halde örnekleme stratejisinin (karışık örneklemede ağırlığına göre
+    // seçilen örnekleyicinin) ürettiği bir nokta. Sonuç her zaman örnekleme bölgesinin
+    // içindedir; bölge dışındaki bir hedef bölgenin kenarına çekilir
+    pub fn sample(&mut self) -> Point {
+        let goal_probability = self.goal_bias.probability(self.iterations);
+        if goal_probability > 0.0 && self.rng.gen::<f32>() < goal_probability {
+            return self.sample_goal();
+        }
+        let sampler = self.pick_sampler();
+        self.sample_with(sampler)
+    }
+
+    // Hedeflerden biri; çok hedefli planlamada eşit olasılıkla seçilir
+    fn sample_goal(&mut self) -> Point {
+        let goal = match self.goal_count() {
+            1 => self.goal,
+            count => {
+                let goal = self.rng.gen_range(0..count);
+                self.goal_point(goal)
+            }
+        };
+        self.bounds.clamp(&goal)
+    }
+
+    // Bu iterasyonun örnekleyicisi: karışık örneklemede ağırlığıyla orantılı olasılıkla
+    // seçilir, aksi halde `sampler`. Karıştırma kapalıyken rastgele sayı çekilmez
+    fn pick_sampler(&mut self) -> Sampler {
+        let Some(&(last, _)) = self.sampler_blend.last() else {
+            return self.sampler;
+        };
+        let mut draw = self.rng.gen::<f32>();
+        for &(sampler, weight) in &self.sampler_blend {
+            if draw < weight {
+                return sampler;
+            }
+            draw -= weight;
+        }
+        // Ağırlıkların toplamı yuvarlamayla 1'in biraz altında kalabilir
+        last
+    }
+
+    // Verilen örnekleyiciyle bir nokta üretir
+    fn sample_with(&mut self, sampler: Sampler) -> Point {
+        match sampler {
+            Sampler::Uniform => self.sample_uniform(),
+            Sampler::Goal => self.sample_goal(),
+            Sampler::PotentialField(field) => {
+                let raw = self.sample_uniform();
+                self.nudge_sample(raw, &field)
+            }
+            Sampler::PathBiased { probability, sigma } => {
+                if self.best_path.is_empty() || self.rng.gen::<f32>() >= probability {
+                    return self.sample_uniform();
+                }
+                // Tek noktalı yolda (kök hedefte) parça yoktur; nokta kendisiyle eşleştirilir
+                let segment = self.rng.gen_range(0..self.best_path.len().saturating_sub(1).max(1));
+                let end = (segment + 1).min(self.best_path.len() - 1);
+                let waypoint = self.best_path[segment].lerp(&self.best_path[end], self.rng.gen::<f32>());
+                let (dx, dy) = self.gaussian_pair();
+                self.bounds.clamp(&Point { x: waypoint.x + sigma * dx, y: waypoint.y + sigma * dy })
+            }
+            Sampler::Smart { probability, radius } => {
+                // İşaret noktaları yalnızca en iyi yol değiştiğinde yeniden hesaplanır
+                if self.smart_beacons.0 != self.best_path {
+                    self.smart_beacons = (self.best_path.clone(), self.smart_waypoints());
+                }
+                let beacons = &self.smart_beacons.1;
+                if beacons.is_empty() || self.rng.gen::<f32>() >= probability {
+                    return self.sample_uniform();
+                }
+                let beacon = beacons[self.rng.gen_range(0..beacons.len())];
+                // Alan olarak tekdüze olması için yarıçap karekökle ölçeklenir
+                let distance = radius * self.rng.gen::<f32>().sqrt();
+                let theta = std::f32::consts::TAU * self.rng.gen::<f32>();
+                self.bounds.clamp(&Point { x: beacon.x + distance * theta.cos(), y: beacon.y + distance * theta.sin() })
+            }
+            Sampler::Bridge { probability, sigma } => {
+                if self.rng.gen::<f32>() >= probability {
+                    return self.sample_uniform();
+                }
+                self.sample_bridge(sigma).unwrap_or_else(|| self.sample_uniform())
+            }
+            Sampler::Halton => {
+                let (u, v) = self.halton.next_point();
+                let Bounds { min_x, max_x, min_y, max_y } = self.bounds;
+                Point { x: min_x + u * (max_x - min_x), y: min_y + v * (max_y - min_y) }
+            }
+            // Ağacın kendi yolundan ucuz, dışarıda bulunmuş bir yol varsa elips ona göre kurulur
+            Sampler::Informed if self.shared_cost < self.best_cost => self.sample_informed(self.shared_cost),
+            Sampler::Informed if self.goal_count() > 1 => self.sample_informed_union(),
+            Sampler::Informed => match self.informed_ellipse() {
+                Some(ellipse) => self.sample_ellipse(&ellipse),
+                None => self.sample_uniform(),
+            },
+        }
+    }
+
+    // Yolu üçgen eşitsizliğiyle kısaltır: her noktadan engelsiz görülebilen en uzak noktaya
+    // atlanır (bkz. `Path::shortcut_greedy`). Uç noktalar korunur; kalan ara noktalar yolun
+    // engellere yaslandığı köşelerdir
+    pub fn shortcut_path(&self, path: &[Point]) -> Vec<Point> {
+        Path::new(path.to_vec()).shortcut_greedy(self).to_vec()
+    }
+
+    // RRT*-Smart işaret noktaları: en iyi yol `shortcut_path` ile kısaltıldığında geriye kalan
+    // ara noktalar. Başlangıç ve bitiş dahil değildir; yol yoksa ya da yol düz bir çizgiye
+    // indirgenebiliyorsa boştur
+    pub fn smart_waypoints(&self) -> Vec<Point> {
+        let shortcut = self.shortcut_path(&self.best_path);
+        match shortcut.len() {
+            0..=2 => Vec::new(),
+            length => shortcut[1..length - 1].to_vec(),
+        }
+    }
+
+    // Şu anki en iyi yolu iyileştirebilecek noktaların elipsi; yol yoksa None. Yol tam hedefte
+    // bitmiyorsa kalan mesafe de elipse eklenir, böylece hedef bölgesindeki tüm iyileştirmeler
+    // elipsin içinde kalır. Çok hedefli planlamada yolun ulaştığı hedefin elipsidir (tümü için
+    // bkz. `informed_ellipses`)
+    pub fn informed_ellipse(&self) -> Option<InformedEllipse> {
+        let end = self.best_path.last()?;
+        let goal = self.goal_point(self.nearest_goal(end));
+        Some(self.ellipse_with_transverse(self.best_cost * self.cost_scale() + self.goal_distance(end), &goal))
+    }
+
+    // Çok hedefli planlamada en iyi yolu iyileştirebilecek noktalar her hedef için bir elipsin
+    // birleşimidir; başlangıca bu maliyetten uzak hedeflerin elipsi boş olduğundan atlanır.
+    // Yol yoksa boştur
+    pub fn informed_ellipses(&self) -> Vec<InformedEllipse> {
+        let Some(end) = self.best_path.last() else {
+            return Vec::new();
+        };
+        let transverse = self.best_cost * self.cost_scale() + self.goal_distance(end);
+        let start = self.nodes[0].point;
+        self.goals()
+            .iter()
+            .filter(|goal| start.distance(goal) < transverse)
+            .map(|goal| self.ellipse_with_transverse(transverse, goal))
+            .collect()
+    }
+
+    // Odakları başlangıç ve verilen hedef olan, odaklara uzaklıkları toplamı `transverse` olan elips
+    fn ellipse_with_transverse(&self, transverse: f32, goal: &Point) -> InformedEllipse {
+        let start = self.nodes[0].point;
+        let focal = start.distance(goal);
+        InformedEllipse {
+            center: start.lerp(goal, 0.5),
+            semi_major: transverse / 2.0,
+            semi_minor: (transverse * transverse - focal * focal).max(0.0).sqrt() / 2.0,
+            angle: (goal.y - start.y).atan2(goal.x - start.x),
+        }
+    }
+
+    // Ağacın dışında bulunan bir yolun maliyetini bildirir (B-RRT* köprüsü); informed
+    // örnekleme ağacın kendi yolu daha ucuz değilse bu maliyetin elipsini kullanır
+    pub(crate) fn set_shared_cost(&mut self, cost: f32) {
+        self.shared_cost = cost;
+    }
+
+    // Kendi çözümünü tutan planlayıcılar (BIT*) için: maliyeti `cost` olan ve hedef eşiğinin
+    // herhangi bir yerinde biten bir yolu iyileştirebilecek noktaların elipsinden, maliyet
+    // sonsuzsa tüm örnekleme bölgesinden tekdüze bir nokta
+    pub(crate) fn sample_informed(&mut self, cost: f32) -> Point {
+        if !cost.is_finite() {
+            return self.sample_uniform();
+        }
+        let ellipse = self.ellipse_with_transverse(cost * self.cost_scale() + self.goal_threshold, &self.goal);
+        self.sample_ellipse(&ellipse)
+    }
+
+    // Hedef elipslerinin birleşiminden tekdüze bir nokta: elips alanıyla orantılı seçilir ve
+    // birden çok elipsin içine düşen noktalar o kadar elipsten seçilebildiğinden bu sayının
+    // tersi olasılıkla kabul edilir. Bölgeye düşen bir nokta bulunamazsa tekdüze örneklemeye döner
+    fn sample_informed_union(&mut self) -> Point {
+        let ellipses = self.informed_ellipses();
+        let total: f32 = ellipses.iter().map(|ellipse| ellipse.semi_major * ellipse.semi_minor).sum();
+        if total <= 0.0 {
+            return self.sample_uniform();
+        }
+        for _ in 0..INFORMED_MAX_ATTEMPTS {
+            let mut pick = self.rng.gen::<f32>() * total;
+            let ellipse = ellipses
+                .iter()
+                .find(|ellipse| {
+                    pick -= ellipse.semi_major * ellipse.semi_minor;
+                    pick < 0.0
+                })
+                .unwrap_or(&ellipses[ellipses.len() - 1]);
+            let point = self.point_in_ellipse(ellipse);
+            let covering = ellipses.iter().filter(|ellipse| ellipse.contains(&point)).count().max(1);
+            if self.bounds.contains(&point) && self.rng.gen::<f32>() * (covering as f32) < 1.0 {
+                return point;
+            }
+        }
+        self.sample_uniform()
+    }
+
+    // Elipsin içinden, birim diskten dönüştürülerek tekdüze bir nokta seçer; örnekleme bölgesine
+    // düşen bir nokta bulunamazsa tekdüze örneklemeye döner
+    fn sample_ellipse(&mut self, ellipse: &InformedEllipse) -> Point {
+        for _ in 0..INFORMED_MAX_ATTEMPTS {
+            let point = self.point_in_ellipse(ellipse);
+            if self.bounds.contains(&point) {
+                return point;
+            }
+        }
+        self.sample_uniform()
+    }
+
+    // Elipsin içinden, birim diskten dönüştürülerek tekdüze bir nokta (örnekleme bölgesi denetlenmez)
+    fn point_in_ellipse(&mut self, ellipse: &InformedEllipse) -> Point {
+        let (sin, cos) = ellipse.angle.sin_cos();
+        // Alan olarak tekdüze olması için yarıçap karekökle ölçeklenir
+        let radius = self.rng.gen::<f32>().sqrt();
+        let theta = std::f32::consts::TAU * self.rng.gen::<f32>();
+        let x = ellipse.semi_major * radius * theta.cos();
+        let y = ellipse.semi_minor * radius * theta.sin();
+        Point {
+            x: ellipse.center.x + x * cos - y * sin,
+            y: ellipse.center.y + x * sin + y * cos,
+        }
+    }
+
+    // Örnekleme bölgesinden tekdüze rastgele bir nokta
+    fn sample_uniform(&mut self) -> Point {
+        let Bounds { min_x, max_x, min_y, max_y } = self.bounds;
+        self.random_point(min_x, max_x, min_y, max_y)
+    }
+
+    // Köprü testiyle dar geçit örneği: iki ucu da engel içinde (ikinci uç sınırlar içinde),
+    // orta noktası engelsiz bir köprü `BRIDGE_MAX_ATTEMPTS` denemede bulunamazsa None
+    fn sample_bridge(&mut self, sigma: f32) -> Option<Point> {
+        for _ in 0..BRIDGE_MAX_ATTEMPTS {
+            let first = self.sample_uniform();
+            if self.is_collision_free(&first) {
+                continue;
+            }
+            let (dx, dy) = self.gaussian_pair();
+            let second = Point { x: first.x + sigma * dx, y: first.y + sigma * dy };
+            if !self.bounds.contains(&second) || self.is_collision_free(&second) {
+                continue;
+            }
+            let midpoint = first.lerp(&second, 0.5);
+            if self.is_collision_free(&midpoint) {
+                return Some(midpoint);
+            }
+        }
+        None
+    }
+
+    // Box-Muller dönüşümüyle iki bağımsız standart normal değer
+    fn gaussian_pair(&mut self) -> (f32, f32) {
+        // ln(0) tanımsız olduğundan u1 (0, 1] aralığından seçilir
+        let u1 = 1.0 - self.rng.gen::<f32>();
+        let u2 = self.rng.gen::<f32>();
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let angle = std::f32::consts::TAU * u2;
+        (radius * angle.cos(), radius * angle.sin())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GoalBias, Obstacle, PlanConfig};
+
+    // Eksenleri farklı, orijini içermeyen bir bölgede her örnekleyicinin her örneği bölgenin
+    // içindedir; bölge dışındaki hedef ve informed elipsi de bölgeye kırpılır
+    #[test]
+    fn samples_stay_inside_asymmetric_bounds() {
+        let bounds = Bounds::new(-50.0, 250.0, 10.0, 90.0);
+        let config = PlanConfig {
+            start: Point { x: 0.0, y: 50.0 },
+            goal: Point { x: 200.0, y: 50.0 },
+            step_size: 10.0,
+            bounds,
+            obstacles: vec![Obstacle::Rect { min: Point { x: 90.0, y: 10.0 }, max: Point { x: 110.0, y: 70.0 } }],
+            max_iterations: 1500,
+            sampler: Sampler::Informed,
+            ..PlanConfig::default()
+        };
+        let mut rrt_star = RRTStar::from_config(&config, 4);
+        rrt_star.plan().expect("yol bulunmalı");
+        assert!(rrt_star.informed_ellipse().is_some());
+
+        let samplers = [
+            Sampler::Uniform,
+            Sampler::Informed,
+            Sampler::PathBiased { probability: 0.9, sigma: 40.0 },
+            Sampler::Smart { probability: 0.9, radius: 60.0 },
+            Sampler::Bridge { probability: 0.9, sigma: 30.0 },
+            Sampler::Halton,
+            Sampler::PotentialField(PotentialFieldSampler { attractive_gain: 0.5, repulsive_gain: 200.0, influence: 30.0, steps: 3, max_nudge: 80.0 }),
+            Sampler::Goal,
+        ];
+        for goal in [Point { x: 200.0, y: 50.0 }, Point { x: 400.0, y: -30.0 }] {
+            rrt_star.goal = goal;
+            rrt_star.set_goal_bias(GoalBias::Fixed(0.2));
+            for sampler in samplers {
+                rrt_star.set_sampler(sampler);
+                for _ in 0..2000 {
+                    let point = rrt_star.sample();
+                    assert!(bounds.contains(&point), "{sampler:?} sampled {point:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn halton_sequence_values_are_deterministic() {
+        let mut halton = HaltonSequence::unshifted();
+        let expected = [(1.0 / 2.0, 1.0 / 3.0), (1.0 / 4.0, 2.0 / 3.0), (3.0 / 4.0, 1.0 / 9.0), (1.0 / 8.0, 4.0 / 9.0), (5.0 / 8.0, 7.0 / 9.0), (3.0 / 8.0, 2.0 / 9.0)];
+        for (x, y) in expected {
+            let (u, v) = halton.next_point();
+            assert!((u - x).abs() < 1e-6 && (v - y).abs() < 1e-6, "({u}, {v}) != ({x}, {y})");
+        }
+
+        // Aynı tohum aynı diziyi, farklı tohum farklı bir kaydırmayı verir
+        let draw = |seed| {
+            let mut halton = HaltonSequence::new(seed);
+            (0..100).map(|_| halton.next_point()).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+        assert!(draw(7).iter().all(|&(u, v)| (0.0..1.0).contains(&u) && (0.0..1.0).contains(&v)));
+    }
+
+    // Tek engeller geçitli bir duvar olduğunda iki ucu da duvarda olan köprülerin engelsiz orta
+    // noktaları yalnızca geçidin içinde olabilir
+    #[test]
+    fn bridge_midpoints_lie_between_colliding_endpoints() {
+        let gap = 15.0;
+        let config = PlanConfig { start: Point { x: 20.0, y: 200.0 }, goal: Point { x: 380.0, y: 200.0 }, obstacles: crate::maps::narrow_gap(gap), ..PlanConfig::default() };
+        let mut rrt_star = RRTStar::from_config(&config, 16);
+        let bridges: Vec<Point> = (0..10000).filter_map(|_| rrt_star.sample_bridge(20.0)).collect();
+        assert!(bridges.len() > 20, "{} bridges", bridges.len());
+        for point in &bridges {
+            assert!(rrt_star.is_collision_free(point));
+            assert!((190.0..=210.0).contains(&point.x) && (point.y - 200.0).abs() <= gap / 2.0, "{point:?} is outside the passage");
+        }
+    }
+
+    // Aynı iterasyon bütçesinde informed örnekleme boş ve dar geçitli haritada tekdüze
+    // örneklemeden daha ucuz yollar bulur
+    #[test]
+    fn informed_sampling_lowers_fixed_budget_cost() {
+        for obstacles in [Vec::new(), crate::maps::narrow_passage()] {
+            let config = PlanConfig { start: Point { x: 20.0, y: 20.0 }, goal: Point { x: 380.0, y: 380.0 }, obstacles, max_iterations: 3000, ..PlanConfig::default() };
+            let informed = PlanConfig { sampler: Sampler::Informed, ..config.clone() };
+            let (mut uniform_total, mut informed_total) = (0.0, 0.0);
+            for seed in 0..4 {
+                uniform_total += RRTStar::from_config(&config, seed).plan().expect("harita çözülür").best_cost;
+                informed_total += RRTStar::from_config(&informed, seed).plan().expect("harita çözülür").best_cost;
+            }
+            assert!(informed_total < uniform_total * 0.99, "{informed_total} vs {uniform_total}");
+        }
+    }
+}
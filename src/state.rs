@@ -0,0 +1,60 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{HaltonSequence, RRTStar, RrtError, TreeInvariantError};
+
+impl RRTStar {
+    // Planlayıcının tüm durumunu (ağaç, engeller, maliyet bölgeleri ve ayarlar) JSON olarak
+    // dosyaya yazar. Rastgele sayı üretecinin durumu kaydedilemez; tekrarlanabilir bir devam
+    // için yükledikten sonra `set_seed` çağrılmalıdır
+    pub fn save_state(&self, path: &str) -> Result<(), RrtError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    // `save_state` ile kaydedilen bir planlayıcıyı yükler; en iyi hedef düğümü ve yol önbelleği
+    // ağaçtan yeniden hesaplanır ve ağaç tutarlılık denetiminden geçirilir
+    pub fn load_state(path: &str) -> Result<Self, RrtError> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut rrt_star: RRTStar = serde_json::from_reader(reader)?;
+
+        // Diğer yöntemler kökün varlığına ve hedef indekslerinin geçerliliğine güvenir
+        if rrt_star.nodes.is_empty() {
+            return Err(RrtError::Corrupt(TreeInvariantError::Empty));
+        }
+        let node_count = rrt_star.nodes.len();
+        let exact_goal_nodes = rrt_star.extra_goals.iter().filter_map(|(_, node)| node.as_ref());
+        if let Some(&node) = rrt_star.goal_nodes.iter().chain(&rrt_star.goal_node).chain(exact_goal_nodes).find(|&&index| index >= node_count) {
+            return Err(RrtError::Corrupt(TreeInvariantError::InvalidGoalNode { node }));
+        }
+        rrt_star.select_best_goal();
+        rrt_star.verify_tree()?;
+        Ok(rrt_star)
+    }
+
+    // Yakınsama eğrisini (`cost_history`) `iteration,best_cost` başlıklı CSV olarak yazar.
+    // Maliyet kayıtlar arasında sabit olduğundan eğri basamak olarak çizilmelidir; son satır,
+    // eğrinin sonuna kadar uzanması için şu anki iterasyondaki maliyettir (yol yoksa `inf`)
+    pub fn save_convergence_csv(&self, path: &str) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "iteration,best_cost")?;
+        for &(iteration, cost) in &self.cost_history {
+            writeln!(writer, "{iteration},{cost}")?;
+        }
+        if self.cost_history.last().is_none_or(|&(iteration, _)| iteration < self.iterations) {
+            writeln!(writer, "{},{}", self.iterations, self.best_cost)?;
+        }
+        writer.flush()
+    }
+
+    // Rastgele sayı üretecini ve Halton dizisini verilen tohumla yeniden başlatır
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.halton = HaltonSequence::new(seed);
+    }
+}
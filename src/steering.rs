@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{DoubleIntegrator, DubinsPath, Path, Point, RRTStar, ReedsSheppPath};
+
+// Ağaçtaki kenarların, yani iki durum arasındaki yerel yolların nasıl oluşturulacağı. Her
+// düğüm konumunun yanında bir yön de saklar; doğrusal yönlendirmede yön yalnızca ebeveynden
+// gelen kenarın doğrultusudur, Dubins yönlendirmesinde ise durumun bir parçasıdır
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Steering {
+    // Düğümler düz doğru parçalarıyla bağlanır
+    #[default]
+    Straight,
+    // Yerinde dönemeyen, yalnızca ileri giden bir araç: düğümler verilen en küçük dönüş
+    // yarıçapıyla en kısa Dubins yollarıyla bağlanır; kenar maliyeti yay uzunluğudur
+    Dubins { turn_radius: f32 },
+    // İleri ve geri gidebilen bir araç (park manevraları): düğümler Reeds-Shepp yollarıyla
+    // bağlanır; geri giden parçaların uzunluğu `reverse_penalty` (>= 1) ile çarpılarak
+    // ileri hareket tercih edilir
+    ReedsShepp { turn_radius: f32, reverse_penalty: f32 },
+    // Konumu ve hızı olan, ivmesi sınırlı bir nokta kütle: her kenar bir zaman adımlık ileri
+    // benzetimdir ve maliyeti geçen süredir. İki durumu tam birleştiren bir yerel yol
+    // olmadığından düz kinodinamik RRT'ye dönülür (bkz. `step_kinodynamic`); `optimize`,
+    // `try_goal_every` ve kırpma politikası bu kipte etkisizdir
+    DoubleIntegrator(DoubleIntegrator),
+}
+
+// İki düğüm arasındaki yerel yol
+enum LocalPath {
+    Straight(Point, Point),
+    Dubins(DubinsPath),
+    ReedsShepp(ReedsSheppPath),
+}
+
+impl LocalPath {
+    // Yolun uzunluğu
+    fn length(&self) -> f32 {
+        match self {
+            LocalPath::Straight(a, b) => a.distance(b),
+            LocalPath::Dubins(path) => path.length(),
+            LocalPath::ReedsShepp(path) => path.length(),
+        }
+    }
+
+    // Yolun başından `distance` uzunluk sonraki konum ve yön
+    fn sample(&self, distance: f32) -> (Point, f32) {
+        match self {
+            LocalPath::Straight(a, b) => {
+                let length = a.distance(b);
+                let t = if length > 0.0 { (distance / length).clamp(0.0, 1.0) } else { 0.0 };
+                (a.lerp(b, t), chord_heading(a, b, 0.0))
+            }
+            LocalPath::Dubins(path) => path.sample(distance),
+            LocalPath::ReedsShepp(path) => path.sample(distance),
+        }
+    }
+
+    // Yolu en fazla `spacing` uzunluklu parçalarla yaklaşan noktalar dizisi
+    fn polyline(&self, spacing: f32) -> Vec<Point> {
+        match self {
+            LocalPath::Straight(a, b) => vec![*a, *b],
+            LocalPath::Dubins(path) => path.polyline(spacing),
+            LocalPath::ReedsShepp(path) => path.polyline(spacing),
+        }
+    }
+}
+
+// from → to doğrultusu (radyan); noktalar çakışıyorsa verilen yön korunur
+pub(crate) fn chord_heading(from: &Point, to: &Point, fallback: f32) -> f32 {
+    if from == to {
+        return fallback;
+    }
+    (to.y - from.y).atan2(to.x - from.x)
+}
+
+impl RRTStar {
+    // Kenar yönlendirme biçimi
+    pub fn steering(&self) -> Steering {
+        self.steering
+    }
+
+    // İki durum arasındaki yerel yol
+    fn local_path(&self, from: &Point, from_heading: f32, to: &Point, to_heading: f32) -> LocalPath {
+        match self.steering {
+            // Kinodinamik kenarlar hızlara bağlıdır ve düğümler üzerinden izlenir (bkz.
+            // `node_edge_free`); yalnızca konumlar verildiğinde kirişe dönülür
+            Steering::Straight | Steering::DoubleIntegrator(_) => LocalPath::Straight(*from, *to),
+            Steering::Dubins { turn_radius } => {
+                LocalPath::Dubins(DubinsPath::shortest(*from, from_heading, *to, to_heading, turn_radius))
+            }
+            Steering::ReedsShepp { turn_radius, reverse_penalty } => LocalPath::ReedsShepp(ReedsSheppPath::shortest(
+                *from,
+                from_heading,
+                *to,
+                to_heading,
+                turn_radius,
+                reverse_penalty,
+            )),
+        }
+    }
+
+    // Geri giden parçaların maliyet çarpanı (yalnızca Reeds-Shepp yönlendirmesinde 1'den farklı)
+    fn reverse_penalty(&self) -> f32 {
+        match self.steering {
+            Steering::ReedsShepp { reverse_penalty, .. } => reverse_penalty,
+            _ => 1.0,
+        }
+    }
+
+    // Verilen yönle gelinen bir durumdan diğerine geçişin maliyeti: doğrusal yönlendirmede
+    // `edge_cost`, eğrisel yönlendirmelerde yay boyunca maliyet bölgesi çarpanlarıyla (ve
+    // Reeds-Shepp yollarında geri giden parçalarda geri gitme cezasıyla) ağırlıklandırılmış
+    // yol uzunluğu
+    pub(crate) fn transition_cost(&self, from: &Point, from_heading: f32, to: &Point, to_heading: f32) -> f32 {
+        // Kinodinamik kenarların hepsi bir zaman adımı sürer
+        if let Some(model) = self.double_integrator() {
+            return model.time_step;
+        }
+        let path = self.local_path(from, from_heading, to, to_heading);
+        match &path {
+            LocalPath::Straight(a, b) => self.edge_cost(a, b),
+            LocalPath::Dubins(dubins) if self.cost_regions.is_empty() => dubins.length(),
+            LocalPath::ReedsShepp(reeds_shepp) if self.cost_regions.is_empty() => reeds_shepp.cost(self.reverse_penalty()),
+            _ => {
+                let length = path.length();
+                let pieces = (length / self.cost_sample_spacing()).ceil().max(1.0) as usize;
+                let piece_length = length / pieces as f32;
+                (0..pieces)
+                    .map(|i| {
+                        let distance = (i as f32 + 0.5) * piece_length;
+                        let reverse = match &path {
+                            LocalPath::ReedsShepp(reeds_shepp) if reeds_shepp.is_reversing_at(distance) => self.reverse_penalty(),
+                            _ => 1.0,
+                        };
+                        piece_length * reverse * self.cost_multiplier(&path.sample(distance).0)
+                    })
+                    .sum()
+            }
+        }
+    }
+
+    // İki durum arasındaki yerel yol engelsiz mi; eğrisel yollarda kiriş değil, yayların
+    // kendisi kenar denetimiyle aynı aralıklarla örneklenir
+    pub(crate) fn transition_free(&self, from: &Point, from_heading: f32, to: &Point, to_heading: f32) -> bool {
+        self.collision_checks.set(self.collision_checks.get() + 1);
+        match self.local_path(from, from_heading, to, to_heading) {
+            LocalPath::Straight(a, b) => self.is_edge_collision_free(&a, &b),
+            path => path
+                .polyline(self.edge_check_spacing())
+                .windows(2)
+                .all(|piece| self.is_edge_collision_free(&piece[0], &piece[1])),
+        }
+    }
+
+    // Ebeveynden verilen noktaya gelindiğindeki yön: doğrusal yönlendirmede kiriş doğrultusu.
+    // Dubins yönlendirmesinde yönü belirtilmeyen noktalar (örneğin hedef) için de aynısı kullanılır
+    pub(crate) fn arrival_heading(&self, parent_index: usize, point: &Point) -> f32 {
+        let parent = &self.nodes[parent_index];
+        chord_heading(&parent.point, point, parent.heading)
+    }
+
+    // Düğümün ebeveyninden gelen kenarının maliyeti
+    pub(crate) fn node_edge_cost(&self, parent_index: usize, index: usize) -> f32 {
+        let (parent, node) = (&self.nodes[parent_index], &self.nodes[index]);
+        self.transition_cost(&parent.point, parent.heading, &node.point, node.heading)
+    }
+
+    // Düğümün ebeveyninden gelen kenarı engelsiz mi
+    pub(crate) fn node_edge_free(&self, parent_index: usize, index: usize) -> bool {
+        let (parent, node) = (&self.nodes[parent_index], &self.nodes[index]);
+        if let Some(model) = self.double_integrator() {
+            return self
+                .trajectory(&model, &parent.point, &parent.velocity, &node.velocity)
+                .windows(2)
+                .all(|piece| self.is_edge_collision_free(&piece[0], &piece[1]));
+        }
+        self.transition_free(&parent.point, parent.heading, &node.point, node.heading)
+    }
+
+    // Düğümden hedef noktaya doğru en fazla bir adım (bkz. `step_length`) kadar ilerler ve varılan konumla yönü
+    // döner. Doğrusal yönlendirmede `steer` ile aynıdır; eğrisel yönlendirmelerde hedefe kiriş
+    // doğrultusunda varan en kısa yol bir adım kadar yay uzunluğunda kesilir
+    pub fn steer_from(&self, from_index: usize, to: &Point) -> (Point, f32) {
+        let from = &self.nodes[from_index];
+        if let Some(model) = self.double_integrator() {
+            let (point, velocity) = model.simulate(&from.point, &from.velocity, to);
+            return (point, chord_heading(&Point::default(), &velocity, from.heading));
+        }
+        if self.steering == Steering::Straight {
+            let point = self.steer(&from.point, to);
+            return (point, chord_heading(&from.point, &point, from.heading));
+        }
+        let heading = chord_heading(&from.point, to, from.heading);
+        let path = self.local_path(&from.point, from.heading, to, heading);
+        let step = self.step_length(&from.point);
+        if path.length() <= step {
+            (*to, heading)
+        } else {
+            path.sample(step)
+        }
+    }
+
+    // Düğümün ebeveyninden gelen kenarını çizmek için noktalar dizisi (kök için tek nokta).
+    // Eğrisel kenarlar ve kinodinamik yörüngeler kenar denetimi aralıklarıyla bir çokgen
+    // çizgiye yaklaştırılır
+    pub fn edge_polyline(&self, index: usize) -> Vec<Point> {
+        let node = &self.nodes[index];
+        let Some(parent_index) = node.parent else {
+            return vec![node.point];
+        };
+        let parent = &self.nodes[parent_index];
+        if let Some(model) = self.double_integrator() {
+            return self.trajectory(&model, &parent.point, &parent.velocity, &node.velocity);
+        }
+        self.local_path(&parent.point, parent.heading, &node.point, node.heading)
+            .polyline(self.edge_check_spacing())
+    }
+
+    // En iyi yolun çizim ve mesafe hesapları için ayrıntılı hali: doğrusal yönlendirmede
+    // `best_path` ile aynıdır, eğrisel yönlendirmelerde yaylar çokgen çizgiye açılır
+    pub fn best_path_polyline(&self) -> Path {
+        if self.steering == Steering::Straight || self.best_path_nodes.len() < 2 {
+            return self.best_path.clone();
+        }
+        let mut points = vec![self.nodes[self.best_path_nodes[0]].point];
+        for &index in &self.best_path_nodes[1..] {
+            points.extend(self.edge_polyline(index).into_iter().skip(1));
+        }
+        Path::new(points)
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use crate::RRTStar;
+
+// Saklanan maliyet ile yeniden hesaplanan maliyet arasındaki izin verilen göreli fark
+// (maliyetin 1'den küçük olduğu yerlerde mutlak fark)
+pub const COST_EPSILON: f32 = 1e-3;
+
+// `verify_tree` tarafından bulunan ağaç tutarsızlıkları
+#[derive(Clone, Debug, PartialEq)]
+pub enum TreeInvariantError {
+    // Ağaçta hiç düğüm yok
+    Empty,
+    // Düğüm 0'ın bir ebeveyni var
+    RootHasParent,
+    // Kök dışındaki bir düğümün ebeveyni yok
+    ExtraRoot { node: usize },
+    // Ebeveyn indeksi ağacın dışında
+    InvalidParent { node: usize, parent: usize },
+    // Ebeveynleri izlemek köke ulaşmadan döngüye giriyor
+    Cycle { node: usize },
+    // Ebeveynin çocuk listesi ebeveyn işaretçisiyle uyuşmuyor
+    ChildListMismatch { node: usize },
+    // Hedef kümesindeki bir indeks ağacın dışında
+    InvalidGoalNode { node: usize },
+    // Saklanan maliyet, ebeveyn maliyeti artı kenar maliyetine eşit değil
+    CostMismatch { node: usize, stored: f32, expected: f32 },
+    // En iyi maliyet, hedef kümesindeki en ucuz düğümün maliyetine eşit değil
+    BestCostMismatch { stored: f32, expected: f32 },
+    // Artımlı en iyi maliyet, en iyi yolun parçalarından yeniden hesaplanan maliyetten sapmış
+    PathCostDrift { stored: f32, recomputed: f32 },
+    // Kinodinamik bir kenar ivme ya da hız sınırını aşıyor veya konumu hareket modeliyle uyuşmuyor
+    DynamicsViolated { node: usize },
+}
+
+impl fmt::Display for TreeInvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeInvariantError::Empty => write!(f, "tree has no nodes"),
+            TreeInvariantError::RootHasParent => write!(f, "root node 0 has a parent"),
+            TreeInvariantError::ExtraRoot { node } => write!(f, "node {node} has no parent"),
+            TreeInvariantError::InvalidParent { node, parent } => {
+                write!(f, "node {node} has out-of-range parent {parent}")
+            }
+            TreeInvariantError::Cycle { node } => write!(f, "following parents from node {node} never reaches the root"),
+            TreeInvariantError::ChildListMismatch { node } => {
+                write!(f, "node {node} is missing from its parent's child list")
+            }
+            TreeInvariantError::InvalidGoalNode { node } => write!(f, "goal set refers to missing node {node}"),
+            TreeInvariantError::CostMismatch { node, stored, expected } => {
+                write!(f, "node {node} stores cost {stored} but parent cost plus edge is {expected}")
+            }
+            TreeInvariantError::BestCostMismatch { stored, expected } => {
+                write!(f, "best cost is {stored} but the cheapest goal node costs {expected}")
+            }
+            TreeInvariantError::PathCostDrift { stored, recomputed } => {
+                write!(f, "best cost is {stored} but summing the best path's segments gives {recomputed}")
+            }
+            TreeInvariantError::DynamicsViolated { node } => {
+                write!(f, "edge into node {node} breaks the acceleration or speed limit of the motion model")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TreeInvariantError {}
+
+impl RRTStar {
+    // Ağacın yapısal tutarlılığını denetler: tek kök (düğüm 0), geçerli ebeveyn indeksleri,
+    // her düğümden köke döngüsüz ulaşım, çocuk listelerinin ebeveynlerle uyumu, her maliyetin
+    // ebeveyn maliyeti artı kenar maliyetine eşitliği, en iyi maliyetin hedef kümesindeki
+    // en ucuz düğümle ve en iyi yolun yeniden hesaplanan maliyetiyle eşleşmesi; çift integratör
+    // yönlendirmesinde ayrıca her kenarın ivme ve hız sınırlarına uyması ve konumların hareket
+    // modeliyle tutarlılığı. Testler ve bulanıklaştırıcılar için dışarı açıktır
+    pub fn verify_tree(&self) -> Result<(), TreeInvariantError> {
+        let nodes = &self.nodes;
+        let root = nodes.first().ok_or(TreeInvariantError::Empty)?;
+        if root.parent.is_some() {
+            return Err(TreeInvariantError::RootHasParent);
+        }
+
+        for (index, node) in nodes.iter().enumerate().skip(1) {
+            let parent = node.parent.ok_or(TreeInvariantError::ExtraRoot { node: index })?;
+            if parent >= nodes.len() {
+                return Err(TreeInvariantError::InvalidParent { node: index, parent });
+            }
+            if !nodes[parent].children.contains(&index) {
+                return Err(TreeInvariantError::ChildListMismatch { node: index });
+            }
+        }
+
+        // Döngü denetimi: 0 = ziyaret edilmedi, 1 = şu anki zincirde, 2 = köke ulaştığı biliniyor
+        let mut state = vec![0u8; nodes.len()];
+        state[0] = 2;
+        for index in 0..nodes.len() {
+            let mut chain = Vec::new();
+            let mut current = index;
+            while state[current] != 2 {
+                if state[current] == 1 {
+                    return Err(TreeInvariantError::Cycle { node: index });
+                }
+                state[current] = 1;
+                chain.push(current);
+                current = nodes[current].parent.expect("kök dışındaki düğümlerin ebeveyni yukarıda denetlendi");
+            }
+            for node_index in chain {
+                state[node_index] = 2;
+            }
+        }
+
+        for (index, node) in nodes.iter().enumerate().skip(1) {
+            let parent = node.parent.unwrap();
+            let expected = nodes[parent].cost + self.node_edge_cost(parent, index);
+            if (expected - node.cost).abs() > COST_EPSILON * expected.max(1.0) {
+                return Err(TreeInvariantError::CostMismatch { node: index, stored: node.cost, expected });
+            }
+        }
+
+        if let Some(model) = self.double_integrator() {
+            // Sabit ivmede hız büyüklüğü kenar boyunca uçlardakini aşmaz; uçları denetlemek yeter
+            let within = |value: f32, limit: f32| value <= limit * (1.0 + COST_EPSILON);
+            for (index, node) in nodes.iter().enumerate() {
+                let Some(parent) = node.parent.map(|parent| &nodes[parent]) else {
+                    continue;
+                };
+                let acceleration = model.acceleration(&parent.velocity, &node.velocity);
+                let expected = model.position_at(&parent.point, &parent.velocity, &node.velocity, model.time_step);
+                if !within(acceleration.x.hypot(acceleration.y), model.max_acceleration)
+                    || !within(node.velocity.x.hypot(node.velocity.y), model.max_speed)
+                    || expected.distance(&node.point) > COST_EPSILON * model.max_speed.max(1.0)
+                {
+                    return Err(TreeInvariantError::DynamicsViolated { node: index });
+                }
+            }
+        }
+
+        if let Some(&node) = self.goal_nodes.iter().find(|&&index| index >= nodes.len()) {
+            return Err(TreeInvariantError::InvalidGoalNode { node });
+        }
+        let expected = self
+            .goal_nodes
+            .iter()
+            .map(|&index| nodes[index].cost)
+            .fold(f32::INFINITY, f32::min);
+        if expected != self.best_cost {
+            return Err(TreeInvariantError::BestCostMismatch { stored: self.best_cost, expected });
+        }
+
+        let recomputed = self.recompute_best_cost();
+        if (recomputed - self.best_cost).abs() > COST_EPSILON * recomputed.max(1.0) {
+            return Err(TreeInvariantError::PathCostDrift { stored: self.best_cost, recomputed });
+        }
+        Ok(())
+    }
+}
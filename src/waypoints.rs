@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::{CollisionChecker, Path, PlanConfig, PlanStats, Point, RRTStar, Termination, WaypointError};
+
+// Sıralı ara noktalar üzerinden planlamanın sonucu
+#[derive(Clone, Debug)]
+pub struct WaypointPlan {
+    pub path: Path,           // Başlangıçtan hedefe birleştirilmiş yol (bacak birleşimleri tek nokta)
+    pub legs: Vec<PlanStats>, // Her bacağın özeti; bacağın kendi yolu `PlanStats::path`
+    pub total_cost: f32,      // Bacak maliyetlerinin toplamı
+}
+
+// Başlangıçtan sırayla her ara noktaya ve son olarak hedefe giden yolu, her bacağı ayrı bir
+// RRT* planlamasıyla zincirleyerek bulur. Bacaklar aynı yapılandırmayı (engeller, ızgara,
+// parametreler) kullanır; yalnızca başlangıç ve hedef değişir, durma koşulu ise her bacak için
+// `termination_per_leg` olur. Hedef eşiği pozitifse bir bacak ara noktanın kabul bölgesinde
+// bitebilir; sonraki bacak o bacağın vardığı noktadan başlar, böylece yollar kopmadan
+// birleşir. Verilirse `checker` her bacağın planlayıcısına bağlanır. i'nci bacak `seed + i`
+// tohumuyla planlanır. Bir bacak başarısız olursa kalanlar planlanmaz ve hata, başarısız
+// bacağın sırasını ve o bacağa kadar birleştirilen kısmi yolu taşır
+pub fn plan_through(
+    waypoints: &[Point],
+    config: &PlanConfig,
+    checker: Option<Arc<dyn CollisionChecker + Send + Sync>>,
+    termination_per_leg: Option<Termination>,
+    seed: u64,
+) -> Result<WaypointPlan, WaypointError> {
+    let mut points = vec![config.start];
+    let mut legs: Vec<PlanStats> = Vec::new();
+    for (leg, &goal) in waypoints.iter().chain([&config.goal]).enumerate() {
+        let start = *points.last().expect("yol en az başlangıç noktasını içerir");
+        let leg_config = PlanConfig { start, goal, termination: termination_per_leg, ..config.clone() };
+        let result = RRTStar::try_from_config(&leg_config, seed.wrapping_add(leg as u64))
+            .map_err(Into::into)
+            .and_then(|mut rrt_star| {
+                if let Some(checker) = &checker {
+                    rrt_star.set_collision_checker(Arc::clone(checker));
+                }
+                rrt_star.plan().map_err(Into::into)
+            });
+        let stats = match result {
+            Ok(stats) => stats,
+            Err(error) => return Err(WaypointError { leg, error, legs, partial: Path::new(points) }),
+        };
+        // Bacak bir önceki bacağın vardığı noktadan başladığından ilk noktası zaten yoldadır
+        points.extend(stats.path.iter().skip(1));
+        legs.push(stats);
+    }
+    let total_cost = legs.iter().map(|stats| stats.best_cost).sum();
+    Ok(WaypointPlan { path: Path::new(points), legs, total_cost })
+}